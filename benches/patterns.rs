@@ -0,0 +1,118 @@
+//! Microbenchmarks for the hot paths of guess evaluation
+//!
+//! Uses the embedded word lists so numbers reflect real solver workloads.
+//! Run with `cargo bench`.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, IteratorRandom};
+use std::hint::black_box;
+use wordle_solver::core::{Pattern, Word};
+use wordle_solver::solver::entropy::{calculate_entropy, calculate_metrics};
+use wordle_solver::solver::{EntropyStrategy, Solver};
+use wordle_solver::wordlists::{ALLOWED, ANSWERS, loader::words_from_slice};
+
+/// Fixed seed so every run measures the same inputs
+const SEED: u64 = 42;
+
+/// Candidate-set sizes: opening (all answers), mid-game and end-game
+const CANDIDATE_SIZES: [usize; 3] = [2315, 100, 10];
+
+fn bench_pattern_calculate(c: &mut Criterion) {
+    let allowed = words_from_slice(ALLOWED);
+    let answers = words_from_slice(ANSWERS);
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let pairs: Vec<(&Word, &Word)> = (0..10_000)
+        .map(|_| {
+            (
+                allowed.choose(&mut rng).unwrap(),
+                answers.choose(&mut rng).unwrap(),
+            )
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("pattern_calculate");
+    group.throughput(Throughput::Elements(pairs.len() as u64));
+    group.bench_function("random_pairs", |b| {
+        b.iter(|| {
+            for &(guess, answer) in &pairs {
+                black_box(Pattern::calculate(black_box(guess), black_box(answer)));
+            }
+        });
+    });
+    group.finish();
+}
+
+fn bench_calculate_entropy(c: &mut Criterion) {
+    let answers = words_from_slice(ANSWERS);
+    let guess = Word::new("salet").unwrap();
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    let mut group = c.benchmark_group("calculate_entropy");
+    for size in CANDIDATE_SIZES {
+        let candidates: Vec<&Word> = answers.iter().choose_multiple(&mut rng, size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &candidates,
+            |b, cands| {
+                b.iter(|| calculate_entropy(black_box(&guess), black_box(cands)));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_calculate_metrics(c: &mut Criterion) {
+    let answers = words_from_slice(ANSWERS);
+    let guess = Word::new("salet").unwrap();
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    let mut group = c.benchmark_group("calculate_metrics");
+    for size in CANDIDATE_SIZES {
+        let candidates: Vec<&Word> = answers.iter().choose_multiple(&mut rng, size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &candidates,
+            |b, cands| {
+                b.iter(|| calculate_metrics(black_box(&guess), black_box(cands)));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_filter_candidates(c: &mut Criterion) {
+    let allowed = words_from_slice(ALLOWED);
+    let answers = words_from_slice(ANSWERS);
+    let solver = Solver::new(EntropyStrategy, &allowed, &answers);
+
+    // Typical mid-game: two guesses played against a real answer
+    let answer = Word::new("robin").unwrap();
+    let history: Vec<(Word, Pattern)> = ["salet", "corni"]
+        .iter()
+        .map(|text| {
+            let guess = Word::new(*text).unwrap();
+            let pattern = Pattern::calculate(&guess, &answer);
+            (guess, pattern)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("filter_candidates");
+    group.throughput(Throughput::Elements(answers.len() as u64));
+    group.bench_function("two_guess_history", |b| {
+        b.iter(|| solver.get_candidates(black_box(&history)));
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_pattern_calculate,
+    bench_calculate_entropy,
+    bench_calculate_metrics,
+    bench_filter_candidates
+);
+criterion_main!(benches);
@@ -0,0 +1,433 @@
+//! Precomputed artifacts
+//!
+//! Results that are slow to compute, such as a solver's decision tree, can be
+//! saved to a compact binary file and loaded on later runs. Each file records
+//! what it was computed from: a hash of the guess pool and answer list, and a
+//! settings string (strategy, opener). A file computed for other lists or
+//! settings is rejected, so a stale artifact is recomputed instead of used.
+//!
+//! Layout, integers little-endian:
+//!
+//! | Field | Size |
+//! |---|---|
+//! | Magic `WSOLVART` | 8 |
+//! | Format version | 2 |
+//! | Artifact kind | 1 |
+//! | Word list hash | 8 |
+//! | Settings length, then UTF-8 settings | 2 + n |
+//! | Payload length, then payload | 8 + n |
+//! | Payload checksum (FNV-1a) | 8 |
+
+use crate::core::{Pattern, Word};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// First bytes of every artifact file
+const MAGIC: &[u8; 8] = b"WSOLVART";
+
+/// Version of the layout above; files of any other version are rejected
+pub const FORMAT_VERSION: u16 = 1;
+
+/// Largest payload read, well above any decision tree, so a corrupt length
+/// can't trigger a huge allocation
+const MAX_PAYLOAD: u64 = 1 << 30;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// 64-bit FNV-1a: stable across builds and platforms, unlike `std`'s hasher
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |h, &b| (h ^ u64::from(b)).wrapping_mul(FNV_PRIME))
+}
+
+/// Hash arbitrary bytes, such as a settings file the result depends on
+#[must_use]
+pub fn content_hash(bytes: &[u8]) -> u64 {
+    fnv1a(FNV_OFFSET, bytes)
+}
+
+/// Hash a guess pool and answer list, in order
+///
+/// Any added, removed or reordered word changes the hash. A separator byte
+/// between the lists keeps a word moving from one list to the other from
+/// going unnoticed.
+#[must_use]
+pub fn list_hash(all_words: &[Word], answers: &[Word]) -> u64 {
+    let hash = all_words
+        .iter()
+        .fold(FNV_OFFSET, |h, w| fnv1a(h, w.text().as_bytes()));
+    let hash = fnv1a(hash, b"|");
+    answers
+        .iter()
+        .fold(hash, |h, w| fnv1a(h, w.text().as_bytes()))
+}
+
+/// What an artifact was computed from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactKey {
+    /// [`list_hash`] of the word lists
+    pub lists: u64,
+    /// Everything else the result depends on, e.g. `adaptive opener=salet`
+    pub settings: String,
+}
+
+impl ArtifactKey {
+    /// Key a result computed from these word lists and `settings`
+    #[must_use]
+    pub fn new(all_words: &[Word], answers: &[Word], settings: impl Into<String>) -> Self {
+        Self {
+            lists: list_hash(all_words, answers),
+            settings: settings.into(),
+        }
+    }
+}
+
+/// A result that can be saved as an artifact
+pub trait Artifact: Sized {
+    /// Tag stored in the file, distinct per artifact type
+    const KIND: u8;
+
+    /// Append the encoded value to `out`
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Decode a value written by [`Self::encode`]
+    ///
+    /// Returns `None` if the bytes are malformed.
+    fn decode(reader: &mut ByteReader<'_>) -> Option<Self>;
+}
+
+/// Error loading an artifact
+#[derive(Debug)]
+pub enum ArtifactError {
+    /// The file could not be opened or read
+    Io(io::Error),
+    /// The file does not start with the artifact magic
+    NotAnArtifact,
+    /// Written by another version of the format
+    UnsupportedVersion(u16),
+    /// Holds another type of artifact
+    WrongKind { expected: u8, found: u8 },
+    /// Computed for different word lists
+    ListsChanged,
+    /// Computed with different settings
+    SettingsChanged { artifact: String, current: String },
+    /// The payload is truncated, fails its checksum or does not decode
+    Corrupt,
+}
+
+impl fmt::Display for ArtifactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::NotAnArtifact => write!(f, "not an artifact file"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "written in format version {version}, but this build reads {FORMAT_VERSION}"
+            ),
+            Self::WrongKind { expected, found } => {
+                write!(f, "holds artifact kind {found}, expected {expected}")
+            }
+            Self::ListsChanged => write!(f, "computed for different word lists"),
+            Self::SettingsChanged { artifact, current } => {
+                write!(f, "computed for '{artifact}', not '{current}'")
+            }
+            Self::Corrupt => write!(f, "the file is truncated or corrupt"),
+        }
+    }
+}
+
+impl std::error::Error for ArtifactError {}
+
+impl From<io::Error> for ArtifactError {
+    fn from(e: io::Error) -> Self {
+        if e.kind() == io::ErrorKind::UnexpectedEof {
+            Self::Corrupt
+        } else {
+            Self::Io(e)
+        }
+    }
+}
+
+/// Write `artifact` with its key
+///
+/// # Errors
+///
+/// Returns any error from the underlying writer, or `InvalidInput` if the
+/// settings are longer than 65,535 bytes.
+pub fn write_artifact<A: Artifact, W: Write>(
+    mut writer: W,
+    key: &ArtifactKey,
+    artifact: &A,
+) -> io::Result<()> {
+    let settings = u16::try_from(key.settings.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "settings too long"))?;
+    let mut payload = Vec::new();
+    artifact.encode(&mut payload);
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&[A::KIND])?;
+    writer.write_all(&key.lists.to_le_bytes())?;
+    writer.write_all(&settings.to_le_bytes())?;
+    writer.write_all(key.settings.as_bytes())?;
+    writer.write_all(&(payload.len() as u64).to_le_bytes())?;
+    writer.write_all(&payload)?;
+    writer.write_all(&fnv1a(FNV_OFFSET, &payload).to_le_bytes())?;
+    writer.flush()
+}
+
+/// Read an artifact, accepting it only if it was computed for `key`
+///
+/// # Errors
+///
+/// Returns an error if the reader fails, the data is not an artifact of
+/// this format version and kind, its key differs from `key`, or it is
+/// corrupt.
+pub fn read_artifact<A: Artifact, R: Read>(
+    mut reader: R,
+    key: &ArtifactKey,
+) -> Result<A, ArtifactError> {
+    let mut magic = [0; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(ArtifactError::NotAnArtifact);
+    }
+    let version = u16::from_le_bytes(read_array(&mut reader)?);
+    if version != FORMAT_VERSION {
+        return Err(ArtifactError::UnsupportedVersion(version));
+    }
+    let [kind] = read_array(&mut reader)?;
+    if kind != A::KIND {
+        return Err(ArtifactError::WrongKind {
+            expected: A::KIND,
+            found: kind,
+        });
+    }
+    if u64::from_le_bytes(read_array(&mut reader)?) != key.lists {
+        return Err(ArtifactError::ListsChanged);
+    }
+    let mut settings = vec![0; usize::from(u16::from_le_bytes(read_array(&mut reader)?))];
+    reader.read_exact(&mut settings)?;
+    let settings = String::from_utf8(settings).map_err(|_| ArtifactError::Corrupt)?;
+    if settings != key.settings {
+        return Err(ArtifactError::SettingsChanged {
+            artifact: settings,
+            current: key.settings.clone(),
+        });
+    }
+
+    let length = u64::from_le_bytes(read_array(&mut reader)?);
+    if length > MAX_PAYLOAD {
+        return Err(ArtifactError::Corrupt);
+    }
+    let mut payload = vec![0; usize::try_from(length).map_err(|_| ArtifactError::Corrupt)?];
+    reader.read_exact(&mut payload)?;
+    if u64::from_le_bytes(read_array(&mut reader)?) != fnv1a(FNV_OFFSET, &payload) {
+        return Err(ArtifactError::Corrupt);
+    }
+
+    let mut bytes = ByteReader::new(&payload);
+    let artifact = A::decode(&mut bytes).ok_or(ArtifactError::Corrupt)?;
+    if bytes.is_empty() {
+        Ok(artifact)
+    } else {
+        Err(ArtifactError::Corrupt)
+    }
+}
+
+/// Save `artifact` to `path`, replacing any file there
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn save<A: Artifact>(path: &Path, key: &ArtifactKey, artifact: &A) -> io::Result<()> {
+    write_artifact(BufWriter::new(File::create(path)?), key, artifact)
+}
+
+/// Load the artifact at `path` if it was computed for `key`
+///
+/// # Errors
+///
+/// See [`read_artifact`]; a missing file is an `Io` error of kind `NotFound`.
+pub fn load<A: Artifact>(path: &Path, key: &ArtifactKey) -> Result<A, ArtifactError> {
+    read_artifact(BufReader::new(File::open(path)?), key)
+}
+
+fn read_array<const N: usize, R: Read>(reader: &mut R) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Cursor over an artifact payload for [`Artifact::decode`]
+///
+/// Every read returns `None` once the bytes run out, so decoding a
+/// truncated payload fails instead of panicking.
+#[derive(Debug)]
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    /// Read `bytes` from the start
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Whether every byte has been read
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Take the next `n` bytes
+    pub fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if n > self.bytes.len() {
+            return None;
+        }
+        let (head, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Some(head)
+    }
+
+    /// Take one byte
+    pub fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    /// Take a little-endian `u16`
+    pub fn u16(&mut self) -> Option<u16> {
+        self.take(2)?.try_into().ok().map(u16::from_le_bytes)
+    }
+
+    /// Take a little-endian `u32`
+    pub fn u32(&mut self) -> Option<u32> {
+        self.take(4)?.try_into().ok().map(u32::from_le_bytes)
+    }
+
+    /// A word written with [`put_word`]
+    pub fn word(&mut self) -> Option<Word> {
+        Word::from_bytes(self.take(5)?.try_into().ok()?).ok()
+    }
+
+    /// A pattern written with [`put_pattern`]
+    pub fn pattern(&mut self) -> Option<Pattern> {
+        self.u8().filter(|&v| v < 243).map(Pattern::new)
+    }
+}
+
+/// Append a word's five letters, given its text
+pub fn put_word(out: &mut Vec<u8>, text: &str) {
+    debug_assert_eq!(text.len(), 5, "not a word: {text}");
+    out.extend_from_slice(text.as_bytes());
+}
+
+/// Append a pattern as one byte
+pub fn put_pattern(out: &mut Vec<u8>, pattern: Pattern) {
+    out.push(pattern.value());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::loader::words_from_slice;
+
+    /// A list of words, as the simplest artifact
+    #[derive(Debug, PartialEq)]
+    struct Words(Vec<Word>);
+
+    impl Artifact for Words {
+        const KIND: u8 = 200;
+
+        fn encode(&self, out: &mut Vec<u8>) {
+            out.extend_from_slice(&(self.0.len() as u32).to_le_bytes());
+            for word in &self.0 {
+                put_word(out, word.text());
+            }
+        }
+
+        fn decode(reader: &mut ByteReader<'_>) -> Option<Self> {
+            let n = reader.u32()?;
+            (0..n)
+                .map(|_| reader.word())
+                .collect::<Option<_>>()
+                .map(Self)
+        }
+    }
+
+    fn setup() -> (Vec<Word>, Vec<Word>, ArtifactKey, Vec<u8>) {
+        let all = words_from_slice(&["salet", "crane", "irate"]);
+        let answers = words_from_slice(&["crane", "irate"]);
+        let key = ArtifactKey::new(&all, &answers, "entropy opener=salet");
+        let mut bytes = Vec::new();
+        write_artifact(&mut bytes, &key, &Words(answers.clone())).unwrap();
+        (all, answers, key, bytes)
+    }
+
+    #[test]
+    fn round_trips() {
+        let (_, answers, key, bytes) = setup();
+        let loaded: Words = read_artifact(bytes.as_slice(), &key).unwrap();
+        assert_eq!(loaded, Words(answers));
+    }
+
+    #[test]
+    fn list_hash_sees_every_change() {
+        let (all, answers, ..) = setup();
+        let hash = list_hash(&all, &answers);
+        assert_eq!(hash, list_hash(&all, &answers));
+
+        let reordered = words_from_slice(&["crane", "salet", "irate"]);
+        assert_ne!(hash, list_hash(&reordered, &answers));
+        assert_ne!(hash, list_hash(&all, &answers[..1]));
+        // The same words, split differently between the lists
+        assert_ne!(
+            hash,
+            list_hash(&all[..2], &words_from_slice(&["irate", "crane", "irate"]))
+        );
+    }
+
+    #[test]
+    fn rejects_artifacts_for_other_lists_or_settings() {
+        let (all, answers, key, bytes) = setup();
+
+        let other_lists = ArtifactKey::new(&all[..2], &answers, key.settings.clone());
+        let result = read_artifact::<Words, _>(bytes.as_slice(), &other_lists);
+        assert!(matches!(result, Err(ArtifactError::ListsChanged)));
+
+        let other_settings = ArtifactKey::new(&all, &answers, "minimax opener=salet");
+        let error = read_artifact::<Words, _>(bytes.as_slice(), &other_settings).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "computed for 'entropy opener=salet', not 'minimax opener=salet'"
+        );
+    }
+
+    #[test]
+    fn rejects_damaged_files() {
+        let (_, _, key, bytes) = setup();
+
+        let mut flipped = bytes.clone();
+        let payload_byte = flipped.len() - 9;
+        flipped[payload_byte] ^= 1;
+        let result = read_artifact::<Words, _>(flipped.as_slice(), &key);
+        assert!(matches!(result, Err(ArtifactError::Corrupt)));
+
+        let truncated = &bytes[..bytes.len() - 3];
+        let result = read_artifact::<Words, _>(truncated, &key);
+        assert!(matches!(result, Err(ArtifactError::Corrupt)));
+
+        let result = read_artifact::<Words, _>(&b"not an artifact"[..], &key);
+        assert!(matches!(result, Err(ArtifactError::NotAnArtifact)));
+
+        let mut newer = bytes;
+        newer[8] = 2;
+        let result = read_artifact::<Words, _>(newer.as_slice(), &key);
+        assert!(matches!(result, Err(ArtifactError::UnsupportedVersion(2))));
+    }
+}
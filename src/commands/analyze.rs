@@ -1,9 +1,54 @@
 //! Word analysis command
 //!
-//! Analyzes the entropy and information content of a specific word.
+//! Analyzes the entropy and information content of a specific word, or of
+//! every word in the guess pool as an opener.
 
-use crate::core::Word;
-use crate::solver::entropy::calculate_entropy;
+use crate::core::{Constraints, FxHashMap, Pattern, Word};
+use crate::output::formatters::progress_bar;
+use crate::solver::entropy::{calculate_entropy, select_best_guess, shannon_entropy};
+use rayon::prelude::*;
+use std::io::{self, Write};
+
+/// The words an analyzed guess is scored against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CandidateSet {
+    /// The answer list, as the solver plays
+    #[default]
+    Answers,
+    /// Every allowed word, for a score that assumes nothing about which
+    /// words can be answers
+    Allowed,
+}
+
+impl CandidateSet {
+    /// Parse a `--against` value: `answers` or `all`
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "answers" => Some(Self::Answers),
+            "all" => Some(Self::Allowed),
+            _ => None,
+        }
+    }
+
+    /// What the candidates are, for output like "against 2315 possible answers"
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Answers => "possible answers",
+            Self::Allowed => "allowed words",
+        }
+    }
+
+    /// This set's words, given the guess pool and the answer list
+    #[must_use]
+    pub const fn pick<'a>(self, all_words: &'a [Word], answers: &'a [Word]) -> &'a [Word] {
+        match self {
+            Self::Answers => answers,
+            Self::Allowed => all_words,
+        }
+    }
+}
 
 /// Result of analyzing a word
 pub struct AnalysisResult {
@@ -11,20 +56,36 @@ pub struct AnalysisResult {
     pub entropy: f64,
     pub expected_reduction: f64,
     pub expected_remaining: f64,
+    /// Candidates consistent with the prior clues (all candidates if none)
     pub total_candidates: usize,
+    /// Which words were the candidates
+    pub against: CandidateSet,
+    /// Number of prior (guess, pattern) clues applied before scoring
+    pub clues: usize,
+    /// Entropy as a fraction of the maximum available, log₂(`total_candidates`)
+    ///
+    /// Defined as 1.0 when at most one candidate remains (nothing left to learn).
+    pub efficiency: f64,
 }
 
 /// Analyze the entropy of a word against a set of candidates
 ///
+/// The candidates are `answers`, or every word in `all_words` when `against`
+/// is [`CandidateSet::Allowed`]. They are first narrowed to those consistent
+/// with `history`, so the word is scored in the context of a partial game.
+///
 /// # Errors
 ///
 /// Returns an error if:
 /// - The word is invalid (not 5 letters or contains non-ASCII)
 /// - The word is not in the provided word list
+/// - No candidate is consistent with the history
 pub fn analyze_word(
     word: &str,
     all_words: &[Word],
-    candidates: &[Word],
+    answers: &[Word],
+    history: &[(Word, Pattern)],
+    against: CandidateSet,
 ) -> Result<AnalysisResult, String> {
     let word_obj = Word::new(word).map_err(|e| format!("Invalid word: {e}"))?;
 
@@ -33,12 +94,25 @@ pub fn analyze_word(
         return Err(format!("Word '{word}' not in word list"));
     }
 
-    let candidate_refs: Vec<&Word> = candidates.iter().collect();
+    let constraints = Constraints::new(history.to_vec());
+    let candidate_refs: Vec<&Word> = against
+        .pick(all_words, answers)
+        .iter()
+        .filter(|c| constraints.allows(c))
+        .collect();
+    if candidate_refs.is_empty() {
+        return Err("No candidates match the given clues".to_string());
+    }
     let entropy = calculate_entropy(&word_obj, &candidate_refs);
 
-    let total_candidates = candidates.len();
+    let total_candidates = candidate_refs.len();
     let expected_reduction = entropy.exp2();
     let expected_remaining = total_candidates as f64 / expected_reduction;
+    let efficiency = if total_candidates <= 1 {
+        1.0
+    } else {
+        entropy / (total_candidates as f64).log2()
+    };
 
     Ok(AnalysisResult {
         word: word.to_string(),
@@ -46,21 +120,278 @@ pub fn analyze_word(
         expected_reduction,
         expected_remaining,
         total_candidates,
+        against,
+        clues: history.len(),
+        efficiency,
+    })
+}
+
+/// How a played guess compares to the best available guess, as the NYT Wordle bot does
+#[derive(Debug, Clone)]
+pub struct SkillScore {
+    pub word: String,
+    pub entropy: f64,
+    /// Highest-entropy guess in the pool at this state
+    pub best: String,
+    pub best_entropy: f64,
+    /// Played entropy as a percentage of the best, 0-100
+    pub skill: f64,
+    /// False if the played word is not in the guess pool; it is scored anyway
+    pub in_word_list: bool,
+    /// Candidates consistent with the prior clues
+    pub total_candidates: usize,
+}
+
+/// Score a played guess against the best guess available after `history`
+///
+/// The skill is the played guess's entropy as a percentage of the highest
+/// entropy over `all_words`, capped at 100. When no guess can gain
+/// information (one candidate left) every guess scores 100. A word outside
+/// the pool is still scored, with `in_word_list` cleared.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The word is invalid (not 5 letters or contains non-ASCII)
+/// - No candidate is consistent with the history
+/// - The guess pool is empty
+pub fn skill_score(
+    word: &str,
+    all_words: &[Word],
+    candidates: &[Word],
+    history: &[(Word, Pattern)],
+) -> Result<SkillScore, String> {
+    let word_obj = Word::new(word).map_err(|e| format!("Invalid word: {e}"))?;
+
+    let constraints = Constraints::new(history.to_vec());
+    let candidate_refs: Vec<&Word> = candidates
+        .iter()
+        .filter(|c| constraints.allows(c))
+        .collect();
+    if candidate_refs.is_empty() {
+        return Err("No candidates match the given clues".to_string());
+    }
+
+    let pool: Vec<&Word> = all_words.iter().collect();
+    let (best, best_entropy) =
+        select_best_guess(&pool, &candidate_refs).ok_or("The word list is empty")?;
+    let entropy = calculate_entropy(&word_obj, &candidate_refs);
+    let skill = if best_entropy > 0.0 {
+        (100.0 * entropy / best_entropy).min(100.0)
+    } else {
+        100.0
+    };
+
+    Ok(SkillScore {
+        word: word_obj.text().to_string(),
+        entropy,
+        best: best.text().to_string(),
+        best_entropy,
+        skill,
+        in_word_list: all_words.contains(&word_obj),
+        total_candidates: candidate_refs.len(),
     })
 }
 
+/// How much of a guess's entropy one position's feedback carries
+#[derive(Debug, Clone)]
+pub struct PositionContribution {
+    /// 0-based position in the guess
+    pub position: usize,
+    /// Letter the guess plays there, lowercase
+    pub letter: char,
+    /// Entropy lost when this position's color is hidden
+    pub bits: f64,
+}
+
+/// A guess's entropy broken down by position
+#[derive(Debug, Clone)]
+pub struct EntropyBreakdown {
+    /// The guess being broken down, lowercase
+    pub word: String,
+    /// Entropy (bits) of the full feedback over the candidates
+    pub entropy: f64,
+    /// Candidates consistent with the prior clues
+    pub total_candidates: usize,
+    /// One entry per position, most informative first
+    pub positions: Vec<PositionContribution>,
+}
+
+/// Break a guess's entropy down by which position's feedback carries it
+///
+/// Each position's contribution is the full partition entropy minus the
+/// entropy of the partition with that position's color hidden, i.e. the
+/// information only that tile provides. Tiles can be redundant with or
+/// depend on each other, so the contributions need not sum to the total.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The word is invalid (not 5 letters or contains non-ASCII)
+/// - No candidate is consistent with the history
+pub fn entropy_breakdown(
+    word: &str,
+    candidates: &[Word],
+    history: &[(Word, Pattern)],
+) -> Result<EntropyBreakdown, String> {
+    let word_obj = Word::new(word).map_err(|e| format!("Invalid word: {e}"))?;
+
+    let constraints = Constraints::new(history.to_vec());
+    let candidate_refs: Vec<&Word> = candidates
+        .iter()
+        .filter(|c| constraints.allows(c))
+        .collect();
+    if candidate_refs.is_empty() {
+        return Err("No candidates match the given clues".to_string());
+    }
+
+    let patterns: Vec<Pattern> = candidate_refs
+        .iter()
+        .map(|c| Pattern::calculate(&word_obj, c))
+        .collect();
+    let entropy = calculate_entropy(&word_obj, &candidate_refs);
+
+    let mut positions: Vec<PositionContribution> = (0..5)
+        .map(|position| {
+            let mut masked: FxHashMap<Pattern, usize> = FxHashMap::default();
+            for pattern in &patterns {
+                *masked.entry(pattern.masked(position)).or_insert(0) += 1;
+            }
+            PositionContribution {
+                position,
+                letter: char::from(word_obj.char_at(position)),
+                bits: entropy - shannon_entropy(&masked),
+            }
+        })
+        .collect();
+    positions.sort_by(|a, b| b.bits.total_cmp(&a.bits));
+
+    Ok(EntropyBreakdown {
+        word: word_obj.text().to_string(),
+        entropy,
+        total_candidates: candidate_refs.len(),
+        positions,
+    })
+}
+
+/// A guess pool word's entropy as an opener against the full answer set
+#[derive(Debug, Clone)]
+pub struct OpenerEntropy {
+    pub word: String,
+    pub entropy: f64,
+}
+
+/// Score every word in `guess_pool` as an opener against all `answers`
+///
+/// Runs in parallel with a progress bar. Results are sorted by entropy,
+/// highest first, with ties broken alphabetically so output is stable.
+#[must_use]
+pub fn rank_openers(guess_pool: &[Word], answers: &[Word]) -> Vec<OpenerEntropy> {
+    let answer_refs: Vec<&Word> = answers.iter().collect();
+
+    let pb = progress_bar(guess_pool.len(), "Scoring openers");
+
+    let mut openers: Vec<OpenerEntropy> = guess_pool
+        .par_iter()
+        .map(|word| {
+            let entropy = calculate_entropy(word, &answer_refs);
+            pb.inc(1);
+            OpenerEntropy {
+                word: word.text().to_string(),
+                entropy,
+            }
+        })
+        .collect();
+    pb.finish_and_clear();
+
+    openers.sort_by(|a, b| {
+        b.entropy
+            .total_cmp(&a.entropy)
+            .then_with(|| a.word.cmp(&b.word))
+    });
+    openers
+}
+
+/// Percentiles reported by `--percentiles`, highest first
+pub const OPENER_PERCENTILES: [f64; 5] = [100.0, 99.0, 90.0, 50.0, 0.0];
+
+/// The opener at one percentile of an entropy ranking
+#[derive(Debug, Clone)]
+pub struct OpenerPercentile {
+    pub percentile: f64,
+    /// 1-based position in the ranking, best first
+    pub rank: usize,
+    pub opener: OpenerEntropy,
+}
+
+/// Pick the openers at each of `percentiles` from a ranking
+///
+/// `openers` must be sorted best first, as [`rank_openers`] returns them.
+/// Uses the nearest-rank method: the opener at percentile p is the one with
+/// at least p% of openers at or below its entropy, so 100 is the best, 50
+/// the median and 0 the worst. Returns nothing for an empty ranking.
+#[must_use]
+pub fn opener_percentiles(openers: &[OpenerEntropy], percentiles: &[f64]) -> Vec<OpenerPercentile> {
+    if openers.is_empty() {
+        return Vec::new();
+    }
+    let n = openers.len();
+
+    percentiles
+        .iter()
+        .map(|&percentile| {
+            // Position counting from the worst opener, 1-based
+            let from_worst = ((percentile / 100.0 * n as f64).ceil() as usize).clamp(1, n);
+            let rank = n + 1 - from_worst;
+            OpenerPercentile {
+                percentile,
+                rank,
+                opener: openers[rank - 1].clone(),
+            }
+        })
+        .collect()
+}
+
+/// Find `word` in a ranking, with the percentile it sits at
+///
+/// The percentile is the share of openers at or below its position, so
+/// [`opener_percentiles`] at that percentile picks the same word.
+#[must_use]
+pub fn opener_rank(openers: &[OpenerEntropy], word: &str) -> Option<OpenerPercentile> {
+    let index = openers.iter().position(|o| o.word == word)?;
+    let n = openers.len();
+    Some(OpenerPercentile {
+        percentile: (n - index) as f64 / n as f64 * 100.0,
+        rank: index + 1,
+        opener: openers[index].clone(),
+    })
+}
+
+/// Write ranked openers as CSV with a `rank,word,entropy` header
+///
+/// # Errors
+///
+/// Returns any error from the underlying writer.
+pub fn write_openers_csv<W: Write>(mut writer: W, openers: &[OpenerEntropy]) -> io::Result<()> {
+    writeln!(writer, "rank,word,entropy")?;
+    for (i, opener) in openers.iter().enumerate() {
+        writeln!(writer, "{},{},{:.6}", i + 1, opener.word, opener.entropy)?;
+    }
+    writer.flush()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::wordlists::ANSWERS;
     use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
 
     #[test]
     fn analyze_valid_word() {
         let words = words_from_slice(&ANSWERS[..100]);
 
         // Use a word we know is in the first 100
-        let result = analyze_word("aback", &words, &words).unwrap();
+        let result = analyze_word("aback", &words, &words, &[], CandidateSet::Answers).unwrap();
 
         assert_eq!(result.word, "aback");
         assert!(result.entropy > 0.0);
@@ -72,7 +403,7 @@ mod tests {
     fn analyze_invalid_word() {
         let words = words_from_slice(&ANSWERS[..100]);
 
-        let result = analyze_word("zzzzz", &words, &words);
+        let result = analyze_word("zzzzz", &words, &words, &[], CandidateSet::Answers);
         assert!(result.is_err());
     }
 
@@ -80,7 +411,7 @@ mod tests {
     fn entropy_properties() {
         let words = words_from_slice(&ANSWERS[..100]);
 
-        let result = analyze_word("aback", &words, &words).unwrap();
+        let result = analyze_word("aback", &words, &words, &[], CandidateSet::Answers).unwrap();
 
         // Entropy should be bounded
         assert!(result.entropy >= 0.0);
@@ -90,4 +421,198 @@ mod tests {
         assert!(result.expected_remaining >= 0.0);
         assert!(result.expected_remaining <= words.len() as f64);
     }
+
+    #[test]
+    fn efficiency_bounded() {
+        let words = words_from_slice(&ANSWERS[..100]);
+
+        let result = analyze_word("aback", &words, &words, &[], CandidateSet::Answers).unwrap();
+        let expected = result.entropy / (words.len() as f64).log2();
+
+        assert!((result.efficiency - expected).abs() < 1e-9);
+        assert!((0.0..=1.0).contains(&result.efficiency));
+    }
+
+    #[test]
+    fn efficiency_single_candidate() {
+        let words = words_from_slice(&ANSWERS[..100]);
+        let candidates = words_from_slice(&ANSWERS[..1]);
+
+        let result =
+            analyze_word("aback", &words, &candidates, &[], CandidateSet::Answers).unwrap();
+        assert!((result.efficiency - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn analyze_with_history_scores_remaining_candidates() {
+        let words = words_from_slice(&ANSWERS[..100]);
+        let guess = Word::new("aback").unwrap();
+        let answer = &words[50];
+        let history = [(guess.clone(), Pattern::calculate(&guess, answer))];
+
+        let constraints = Constraints::new(history.to_vec());
+        let remaining: Vec<Word> = words
+            .iter()
+            .filter(|w| constraints.allows(w))
+            .cloned()
+            .collect();
+
+        let result =
+            analyze_word("abide", &words, &words, &history, CandidateSet::Answers).unwrap();
+        let direct = analyze_word("abide", &words, &remaining, &[], CandidateSet::Answers).unwrap();
+
+        assert_eq!(result.clues, 1);
+        assert_eq!(result.total_candidates, remaining.len());
+        assert!(result.total_candidates < words.len());
+        assert!((result.entropy - direct.entropy).abs() < 1e-9);
+    }
+
+    #[test]
+    fn analyze_against_allowed_words() {
+        let all_words = words_from_slice(&ALLOWED[..1000]);
+        let answers = words_from_slice(&ANSWERS[..100]);
+
+        let result =
+            analyze_word("aahed", &all_words, &answers, &[], CandidateSet::Allowed).unwrap();
+        let direct =
+            analyze_word("aahed", &all_words, &all_words, &[], CandidateSet::Answers).unwrap();
+        assert_eq!(result.against, CandidateSet::Allowed);
+        assert_eq!(result.total_candidates, all_words.len());
+        assert!((result.entropy - direct.entropy).abs() < 1e-12);
+
+        let answers_only =
+            analyze_word("aahed", &all_words, &answers, &[], CandidateSet::Answers).unwrap();
+        assert_eq!(answers_only.total_candidates, answers.len());
+
+        assert_eq!(CandidateSet::from_name("all"), Some(CandidateSet::Allowed));
+        assert_eq!(
+            CandidateSet::from_name("answers"),
+            Some(CandidateSet::Answers)
+        );
+        assert_eq!(CandidateSet::from_name("everything"), None);
+    }
+
+    #[test]
+    fn analyze_with_contradictory_history() {
+        let words = words_from_slice(&ANSWERS[..100]);
+        let history = [(Word::new("zzzzz").unwrap(), Pattern::PERFECT)];
+
+        assert!(analyze_word("aback", &words, &words, &history, CandidateSet::Answers).is_err());
+    }
+
+    #[test]
+    fn skill_score_compares_to_best_guess() {
+        let words = words_from_slice(&ANSWERS[..100]);
+        let pool: Vec<&Word> = words.iter().collect();
+        let (best, _) = select_best_guess(&pool, &pool).unwrap();
+
+        let top = skill_score(best.text(), &words, &words, &[]).unwrap();
+        assert!((top.skill - 100.0).abs() < 1e-9);
+        assert_eq!(top.best, best.text());
+        assert!(top.in_word_list);
+
+        let weak = skill_score("aback", &words, &words, &[]).unwrap();
+        assert!(weak.skill > 0.0 && weak.skill < 100.0);
+        assert!((weak.skill - 100.0 * weak.entropy / weak.best_entropy).abs() < 1e-9);
+
+        // Not in the list: scored anyway, but flagged
+        let outside = skill_score("zzzzz", &words, &words, &[]).unwrap();
+        assert!(!outside.in_word_list);
+        assert!(outside.skill < weak.skill);
+
+        let history = [(Word::new("zzzzz").unwrap(), Pattern::PERFECT)];
+        assert!(skill_score("aback", &words, &words, &history).is_err());
+    }
+
+    #[test]
+    fn entropy_breakdown_by_position() {
+        let words = words_from_slice(&ANSWERS[..100]);
+        let breakdown = entropy_breakdown("crane", &words, &[]).unwrap();
+
+        let pool: Vec<&Word> = words.iter().collect();
+        let crane = Word::new("crane").unwrap();
+        assert!((breakdown.entropy - calculate_entropy(&crane, &pool)).abs() < 1e-9);
+        let mut positions: Vec<usize> = breakdown.positions.iter().map(|p| p.position).collect();
+        positions.sort_unstable();
+        assert_eq!(positions, [0, 1, 2, 3, 4]);
+        for pair in breakdown.positions.windows(2) {
+            assert!(pair[0].bits >= pair[1].bits);
+        }
+        for contribution in &breakdown.positions {
+            assert_eq!(
+                contribution.letter,
+                "crane".as_bytes()[contribution.position] as char
+            );
+            assert!(contribution.bits >= -1e-9 && contribution.bits <= breakdown.entropy + 1e-9);
+        }
+
+        // Every answer starts with A: the first tile is always green and says nothing
+        let a_words = words_from_slice(&ANSWERS[..20]);
+        let first = entropy_breakdown("abbey", &a_words, &[]).unwrap();
+        let position_0 = first.positions.iter().find(|p| p.position == 0).unwrap();
+        assert!(position_0.bits.abs() < 1e-9);
+    }
+
+    #[test]
+    fn rank_openers_sorted_by_entropy() {
+        let answers = words_from_slice(&ANSWERS[..50]);
+        let pool = words_from_slice(&["salet", "aback", "fuzzy", "crane"]);
+
+        let openers = rank_openers(&pool, &answers);
+
+        assert_eq!(openers.len(), pool.len());
+        for pair in openers.windows(2) {
+            assert!(pair[0].entropy >= pair[1].entropy);
+        }
+        let salet = openers.iter().find(|o| o.word == "salet").unwrap();
+        let answer_refs: Vec<&Word> = answers.iter().collect();
+        let expected = calculate_entropy(&Word::new("salet").unwrap(), &answer_refs);
+        assert!((salet.entropy - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn openers_csv_format() {
+        let openers = [
+            OpenerEntropy {
+                word: "salet".to_string(),
+                entropy: 5.835_2,
+            },
+            OpenerEntropy {
+                word: "fuzzy".to_string(),
+                entropy: 1.5,
+            },
+        ];
+
+        let mut out = Vec::new();
+        write_openers_csv(&mut out, &openers).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "rank,word,entropy\n1,salet,5.835200\n2,fuzzy,1.500000\n"
+        );
+    }
+
+    #[test]
+    fn percentiles_pick_nearest_rank() {
+        let openers: Vec<OpenerEntropy> = (0..10)
+            .map(|i| OpenerEntropy {
+                word: format!("w{i}"),
+                entropy: f64::from(10 - i),
+            })
+            .collect();
+
+        let picked = opener_percentiles(&openers, &OPENER_PERCENTILES);
+        let ranks: Vec<usize> = picked.iter().map(|p| p.rank).collect();
+        assert_eq!(ranks, [1, 1, 2, 6, 10]);
+        assert_eq!(picked[0].opener.word, "w0");
+        assert_eq!(picked[4].opener.word, "w9");
+
+        assert!(opener_percentiles(&[], &OPENER_PERCENTILES).is_empty());
+
+        let w3 = opener_rank(&openers, "w3").unwrap();
+        assert_eq!(w3.rank, 4);
+        assert!((w3.percentile - 70.0).abs() < 1e-9);
+        assert_eq!(opener_percentiles(&openers, &[w3.percentile])[0].rank, 4);
+        assert!(opener_rank(&openers, "salet").is_none());
+    }
 }
@@ -2,8 +2,11 @@
 //!
 //! Tests solver performance across multiple words.
 
-use crate::core::{Pattern, Word};
-use crate::solver::{Solver, Strategy};
+use crate::core::Word;
+use crate::solver::{AdaptiveStrategy, MAX_GUESSES, Solver, Strategy, simulate_game};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
@@ -14,9 +17,101 @@ pub struct BenchmarkResult {
     pub average_guesses: f64,
     pub min_guesses: usize,
     pub max_guesses: usize,
+    /// Solved words per guess count
     pub distribution: HashMap<usize, usize>,
+    /// Words not solved within the guess limit
+    pub failures: usize,
     pub duration: Duration,
     pub words_per_second: f64,
+    /// Guess selection time per turn (index 0 = turn 1)
+    pub turn_timings: Vec<TurnTiming>,
+    /// Run-to-run timing spread, present when run with multiple iterations
+    pub timing: Option<TimingStats>,
+}
+
+impl BenchmarkResult {
+    /// Total time spent selecting first guesses
+    #[must_use]
+    pub fn first_guess_time(&self) -> Duration {
+        self.turn_timings
+            .first()
+            .map_or(Duration::ZERO, |timing| timing.total)
+    }
+
+    /// Total time spent selecting guesses after the first
+    #[must_use]
+    pub fn subsequent_time(&self) -> Duration {
+        self.turn_timings
+            .iter()
+            .skip(1)
+            .map(|timing| timing.total)
+            .sum()
+    }
+}
+
+/// Wall-clock timing across repeated benchmark runs
+#[derive(Debug, Clone, Copy)]
+pub struct TimingStats {
+    pub iterations: usize,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl TimingStats {
+    /// Compute mean and (population) standard deviation of run durations
+    ///
+    /// Returns `None` if `durations` is empty.
+    #[must_use]
+    pub fn from_durations(durations: &[Duration]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+
+        let n = durations.len() as f64;
+        let mean = durations.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = durations
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Some(Self {
+            iterations: durations.len(),
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Accumulated guess selection time for one turn number
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TurnTiming {
+    pub total: Duration,
+    pub guesses: usize,
+}
+
+impl TurnTiming {
+    /// Average selection time per guess at this turn
+    #[must_use]
+    pub fn average(&self) -> Duration {
+        u32::try_from(self.guesses)
+            .ok()
+            .and_then(|n| self.total.checked_div(n))
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Randomly sample `count` words from the answer list
+///
+/// The same seed always produces the same sample. If `count` exceeds the
+/// number of answers, every answer is returned (in shuffled order).
+#[must_use]
+pub fn sample_words(answer_words: &[Word], count: usize, seed: u64) -> Vec<Word> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    answer_words
+        .choose_multiple(&mut rng, count)
+        .cloned()
+        .collect()
 }
 
 /// Run benchmark on a set of target words
@@ -33,37 +128,29 @@ pub fn run_benchmark<S: Strategy>(
     let mut min_guesses = usize::MAX;
     let mut max_guesses = 0;
     let mut distribution: HashMap<usize, usize> = HashMap::new();
+    let mut failures = 0;
+    let mut turn_timings: Vec<TurnTiming> = Vec::new();
 
     for target in target_words {
-        let mut history: Vec<(Word, Pattern)> = Vec::new();
-        let mut guesses = 0;
-
-        loop {
-            guesses += 1;
-
-            let guess = if let (1, Some(forced)) = (guesses, forced_first) {
-                // Use forced first word on first guess
-                forced
-            } else {
-                // Otherwise use solver
-                match solver.next_guess(&history) {
-                    Some(g) => g,
-                    None => break,
-                }
-            };
-
-            let pattern = Pattern::calculate(guess, target);
-            history.push((guess.clone(), pattern));
+        let record = simulate_game(solver, target, MAX_GUESSES, forced_first);
 
-            if pattern.is_perfect() || guesses >= 6 {
-                break;
-            }
+        if turn_timings.len() < record.selection_times.len() {
+            turn_timings.resize(record.selection_times.len(), TurnTiming::default());
+        }
+        for (timing, &elapsed) in turn_timings.iter_mut().zip(&record.selection_times) {
+            timing.total += elapsed;
+            timing.guesses += 1;
         }
 
+        let guesses = record.num_guesses();
         total_guesses += guesses;
         min_guesses = min_guesses.min(guesses);
         max_guesses = max_guesses.max(guesses);
-        *distribution.entry(guesses).or_insert(0) += 1;
+        if record.success {
+            *distribution.entry(guesses).or_insert(0) += 1;
+        } else {
+            failures += 1;
+        }
     }
 
     let duration = start.elapsed();
@@ -76,11 +163,98 @@ pub fn run_benchmark<S: Strategy>(
         min_guesses,
         max_guesses,
         distribution,
+        failures,
         duration,
         words_per_second: total_words as f64 / duration.as_secs_f64(),
+        turn_timings,
+        timing: None,
     }
 }
 
+/// Run the benchmark `iterations` times after one untimed warmup run
+///
+/// Guess-count statistics are deterministic for non-random strategies, so they
+/// come from the first timed run. `duration`, `words_per_second` and the
+/// per-turn timings use the mean across runs, and `timing` reports the
+/// spread for regression tracking.
+///
+/// # Panics
+///
+/// Panics if `iterations` is 0.
+pub fn run_benchmark_iterations<S: Strategy>(
+    solver: &Solver<S>,
+    target_words: &[Word],
+    forced_first: Option<&Word>,
+    iterations: usize,
+) -> BenchmarkResult {
+    assert!(iterations > 0, "iterations must be at least 1");
+
+    // Warmup: populate caches before timing
+    let _ = run_benchmark(solver, target_words, forced_first);
+
+    let mut result = run_benchmark(solver, target_words, forced_first);
+    let mut durations = vec![result.duration];
+    for _ in 1..iterations {
+        let run = run_benchmark(solver, target_words, forced_first);
+        durations.push(run.duration);
+        add_turn_timings(&mut result.turn_timings, &run.turn_timings);
+    }
+    let runs = u32::try_from(iterations).unwrap_or(u32::MAX);
+    for timing in &mut result.turn_timings {
+        timing.total /= runs;
+        timing.guesses /= iterations;
+    }
+
+    let timing = TimingStats::from_durations(&durations).expect("at least one iteration");
+    result.duration = timing.mean;
+    result.words_per_second = result.total_words as f64 / timing.mean.as_secs_f64();
+    result.timing = Some(timing);
+    result
+}
+
+/// Add another run's per-turn timings to `totals`, turn by turn
+fn add_turn_timings(totals: &mut Vec<TurnTiming>, run: &[TurnTiming]) {
+    if totals.len() < run.len() {
+        totals.resize(run.len(), TurnTiming::default());
+    }
+    for (total, timing) in totals.iter_mut().zip(run) {
+        total.total += timing.total;
+        total.guesses += timing.guesses;
+    }
+}
+
+/// Benchmark result for one beam width
+pub struct BeamRun {
+    /// Guesses kept for the entropy pass; `None` scores every guess
+    pub width: Option<usize>,
+    pub result: BenchmarkResult,
+}
+
+/// Benchmark the adaptive strategy without a beam and at each of `widths`
+///
+/// Every run plays the same words with the same opener, so differences in
+/// average guesses and time come from the beam alone. The unbeamed run comes
+/// first as the baseline.
+#[must_use]
+pub fn compare_beam_widths(
+    solver: &Solver<AdaptiveStrategy>,
+    widths: &[usize],
+    target_words: &[Word],
+    forced_first: Option<&Word>,
+) -> Vec<BeamRun> {
+    std::iter::once(None)
+        .chain(widths.iter().copied().map(Some))
+        .map(|width| {
+            let strategy = AdaptiveStrategy {
+                beam_width: width,
+                ..solver.strategy().clone()
+            };
+            let result = run_benchmark(&solver.with_strategy(strategy), target_words, forced_first);
+            BeamRun { width, result }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,7 +286,7 @@ mod tests {
         let result = run_benchmark(&solver, &answer_words, None);
 
         let distribution_sum: usize = result.distribution.values().sum();
-        assert_eq!(distribution_sum, result.total_words);
+        assert_eq!(distribution_sum + result.failures, result.total_words);
     }
 
     #[test]
@@ -129,6 +303,116 @@ mod tests {
         assert!(result.average_guesses >= 1.0);
     }
 
+    #[test]
+    fn beam_comparison_starts_with_full_width() {
+        let all_words = words_from_slice(&ALLOWED[..300]);
+        let answer_words = words_from_slice(&ANSWERS[..150]);
+        let solver = Solver::new(
+            AdaptiveStrategy::default().with_random_seed(0),
+            &all_words,
+            &answer_words,
+        );
+
+        let runs = compare_beam_widths(&solver, &[300, 20], &answer_words[..10], None);
+
+        let widths: Vec<_> = runs.iter().map(|run| run.width).collect();
+        assert_eq!(widths, [None, Some(300), Some(20)]);
+        // A beam covering the whole pool changes nothing
+        assert_eq!(runs[0].result.total_guesses, runs[1].result.total_guesses);
+        for run in &runs {
+            assert_eq!(run.result.total_words, 10);
+        }
+    }
+
+    #[test]
+    fn sample_words_reproducible_with_seed() {
+        let answer_words = words_from_slice(ANSWERS);
+
+        let sample1 = sample_words(&answer_words, 20, 42);
+        let sample2 = sample_words(&answer_words, 20, 42);
+        let other = sample_words(&answer_words, 20, 43);
+
+        assert_eq!(sample1.len(), 20);
+        assert_eq!(sample1, sample2);
+        assert_ne!(sample1, other);
+
+        // Not just the alphabetical prefix
+        assert_ne!(sample1, answer_words[..20].to_vec());
+    }
+
+    #[test]
+    fn sample_words_caps_at_list_size() {
+        let answer_words = words_from_slice(&ANSWERS[..5]);
+        let sample = sample_words(&answer_words, 50, 7);
+
+        assert_eq!(sample.len(), 5);
+        assert!(answer_words.iter().all(|w| sample.contains(w)));
+    }
+
+    #[test]
+    fn benchmark_turn_timings_consistent() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..10]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let result = run_benchmark(&solver, &answer_words, None);
+
+        // Every word makes a first guess; turn counts match the distribution
+        assert_eq!(result.turn_timings.len(), result.max_guesses);
+        assert_eq!(result.turn_timings[0].guesses, result.total_words);
+        let timed_guesses: usize = result.turn_timings.iter().map(|t| t.guesses).sum();
+        assert_eq!(timed_guesses, result.total_guesses);
+
+        let split = result.first_guess_time() + result.subsequent_time();
+        assert!(split <= result.duration);
+    }
+
+    #[test]
+    fn benchmark_iterations_reports_timing() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..5]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let single = run_benchmark(&solver, &answer_words, None);
+        let repeated = run_benchmark_iterations(&solver, &answer_words, None, 3);
+
+        assert!(single.timing.is_none());
+        let timing = repeated.timing.unwrap();
+        assert_eq!(timing.iterations, 3);
+        assert_eq!(repeated.duration, timing.mean);
+        assert_eq!(repeated.total_guesses, single.total_guesses);
+        // Turn timings are per-run means, so guess counts match one run
+        let guesses =
+            |r: &BenchmarkResult| r.turn_timings.iter().map(|t| t.guesses).collect::<Vec<_>>();
+        assert_eq!(guesses(&repeated), guesses(&single));
+    }
+
+    #[test]
+    fn timing_stats_mean_and_std_dev() {
+        let durations = [
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+            Duration::from_millis(30),
+        ];
+        let stats = TimingStats::from_durations(&durations).unwrap();
+
+        assert_eq!(stats.iterations, 3);
+        assert!((stats.mean.as_secs_f64() - 0.020).abs() < 1e-9);
+        // sqrt(((10² + 0² + 10²) / 3)) ms ≈ 8.165ms
+        assert!((stats.std_dev.as_secs_f64() - 0.008_165).abs() < 1e-6);
+        assert!(TimingStats::from_durations(&[]).is_none());
+    }
+
+    #[test]
+    fn turn_timing_average() {
+        let timing = TurnTiming {
+            total: Duration::from_millis(30),
+            guesses: 3,
+        };
+        assert_eq!(timing.average(), Duration::from_millis(10));
+        assert_eq!(TurnTiming::default().average(), Duration::ZERO);
+    }
+
     #[test]
     fn benchmark_empty_word_list() {
         let all_words = words_from_slice(&ALLOWED[..100]);
@@ -0,0 +1,268 @@
+//! Post-game coach command
+//!
+//! Reviews a finished (or abandoned) game turn by turn: at each state the
+//! played guess is scored against the best guess available, over the
+//! answers still consistent with the feedback so far. Guesses need not be
+//! possible answers, or even in the word list, to be graded.
+
+use crate::core::{Constraints, Pattern, Word};
+use crate::output::formatters::word_label;
+
+use super::analyze::{SkillScore, skill_score};
+
+/// How a turn's skill score reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grade {
+    /// As good as the best guess (skill rounds to 100)
+    Best,
+    /// Skill 90 or more
+    Great,
+    /// Skill 75 or more
+    Good,
+    /// Skill 50 or more
+    Fair,
+    /// Under half the information the best guess would have gained
+    Poor,
+}
+
+impl Grade {
+    /// Grade a 0-100 skill score
+    #[must_use]
+    pub fn from_skill(skill: f64) -> Self {
+        if skill >= 99.5 {
+            Self::Best
+        } else if skill >= 90.0 {
+            Self::Great
+        } else if skill >= 75.0 {
+            Self::Good
+        } else if skill >= 50.0 {
+            Self::Fair
+        } else {
+            Self::Poor
+        }
+    }
+
+    /// Lowercase name for output
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Best => "best",
+            Self::Great => "great",
+            Self::Good => "good",
+            Self::Fair => "fair",
+            Self::Poor => "poor",
+        }
+    }
+}
+
+/// One played guess, graded at the state it was played in
+#[derive(Debug, Clone)]
+pub struct CoachTurn {
+    /// The guess against the best available, over the candidates at this turn
+    pub score: SkillScore,
+    /// Feedback the guess got
+    pub pattern: Pattern,
+    /// Whether the guess was still a possible answer
+    pub could_win: bool,
+    /// Candidates left after the feedback
+    pub remaining: usize,
+}
+
+impl CoachTurn {
+    /// Bits the best guess would have gained over the played one
+    #[must_use]
+    pub fn bits_lost(&self) -> f64 {
+        (self.score.best_entropy - self.score.entropy).max(0.0)
+    }
+
+    /// The turn's skill score as a grade
+    #[must_use]
+    pub fn grade(&self) -> Grade {
+        Grade::from_skill(self.score.skill)
+    }
+}
+
+/// A game reviewed turn by turn
+#[derive(Debug, Clone)]
+pub struct CoachReport {
+    pub turns: Vec<CoachTurn>,
+    /// Whether the last guess got all greens
+    pub solved: bool,
+}
+
+impl CoachReport {
+    /// Overall skill: the mean of the turns' skill scores, 0-100
+    #[must_use]
+    pub fn skill(&self) -> f64 {
+        if self.turns.is_empty() {
+            return 0.0;
+        }
+        self.turns.iter().map(|t| t.score.skill).sum::<f64>() / self.turns.len() as f64
+    }
+
+    /// Bits left on the table over the whole game
+    #[must_use]
+    pub fn bits_lost(&self) -> f64 {
+        self.turns.iter().map(CoachTurn::bits_lost).sum()
+    }
+}
+
+/// Grade every guess of a played game
+///
+/// Each guess is scored with [`skill_score`] against the answers consistent
+/// with the feedback before it, with the best guess searched over
+/// `all_words`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The game has no guesses
+/// - A guess follows an all-green feedback
+/// - No answer fits the feedback after some guess
+/// - The guess pool is empty
+pub fn coach(
+    all_words: &[Word],
+    answers: &[Word],
+    game: &[(Word, Pattern)],
+) -> Result<CoachReport, String> {
+    if game.is_empty() {
+        return Err("No guesses to review".to_string());
+    }
+    if let Some(i) = game[..game.len() - 1]
+        .iter()
+        .position(|(_, pattern)| *pattern == Pattern::PERFECT)
+    {
+        return Err(format!(
+            "Guess {} ({}) already solved the game, but more guesses follow",
+            i + 1,
+            word_label(&game[i].0)
+        ));
+    }
+
+    // Check the feedback first: grading is far slower than filtering
+    let mut remaining = Vec::with_capacity(game.len());
+    for i in 1..=game.len() {
+        let constraints = Constraints::new(game[..i].to_vec());
+        let left = answers.iter().filter(|a| constraints.allows(a)).count();
+        if left == 0 {
+            return Err(format!(
+                "No answers fit the feedback after guess {i} ({})",
+                word_label(&game[i - 1].0)
+            ));
+        }
+        remaining.push(left);
+    }
+
+    let turns = game
+        .iter()
+        .zip(remaining)
+        .enumerate()
+        .map(|(i, ((guess, pattern), remaining))| {
+            let history = &game[..i];
+            let score = skill_score(guess.text(), all_words, answers, history)?;
+            let constraints = Constraints::new(history.to_vec());
+            Ok(CoachTurn {
+                score,
+                pattern: *pattern,
+                could_win: answers.contains(guess) && constraints.allows(guess),
+                remaining,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(CoachReport {
+        solved: game[game.len() - 1].1 == Pattern::PERFECT,
+        turns,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::entropy::select_best_guess;
+    use crate::wordlists::ANSWERS;
+    use crate::wordlists::loader::words_from_slice;
+
+    /// Play `guesses` against `answer`, recording the real feedback
+    fn play(guesses: &[&str], answer: &str) -> Vec<(Word, Pattern)> {
+        let answer = Word::new(answer).unwrap();
+        guesses
+            .iter()
+            .map(|g| {
+                let guess = Word::new(*g).unwrap();
+                let pattern = Pattern::calculate(&guess, &answer);
+                (guess, pattern)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn grades_every_turn_against_the_true_candidates() {
+        let words = words_from_slice(ANSWERS);
+        let refs: Vec<&Word> = words.iter().collect();
+        let (opener, _) = select_best_guess(&refs, &refs).unwrap();
+
+        // The best opener, a guess outside the word list, then the answer
+        let game = play(&[opener.text(), "zzzzz", "abuse"], "abuse");
+        let report = coach(&words, &words, &game).unwrap();
+
+        assert!(report.solved);
+        assert_eq!(report.turns.len(), 3);
+        assert_eq!(report.turns[0].score.total_candidates, ANSWERS.len());
+        for pair in report.turns.windows(2) {
+            assert_eq!(pair[1].score.total_candidates, pair[0].remaining);
+        }
+        assert_eq!(report.turns[2].remaining, 1);
+
+        let first = &report.turns[0];
+        assert_eq!(first.grade(), Grade::Best);
+        assert!(first.bits_lost().abs() < 1e-9);
+
+        let outside = &report.turns[1];
+        assert!(!outside.score.in_word_list && !outside.could_win);
+        assert_ne!(outside.grade(), Grade::Best);
+        assert!(outside.bits_lost() > 0.0);
+
+        assert!(report.turns[2].could_win);
+        let mean = report.turns.iter().map(|t| t.score.skill).sum::<f64>() / 3.0;
+        assert!((report.skill() - mean).abs() < 1e-9);
+        assert!(
+            (report.bits_lost() - outside.bits_lost() - report.turns[2].bits_lost()).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn unfinished_games_are_reviewed() {
+        let words = words_from_slice(&ANSWERS[..300]);
+        let game = play(&["crane", "moist"], ANSWERS[0]);
+        let report = coach(&words, &words, &game).unwrap();
+        assert!(!report.solved);
+        assert_eq!(report.turns.len(), 2);
+    }
+
+    #[test]
+    fn rejects_impossible_games() {
+        let words = words_from_slice(&ANSWERS[..300]);
+        assert!(coach(&words, &words, &[]).is_err());
+
+        let salet = Word::new("salet").unwrap();
+        let impossible = vec![(salet.clone(), Pattern::from_str("GGGGY").unwrap())];
+        let error = coach(&words, &words, &impossible).unwrap_err();
+        assert!(error.contains("after guess 1 (SALET)"), "{error}");
+
+        let mut after_win = play(&["crane"], "crane");
+        after_win.extend(play(&["salet"], "crane"));
+        let error = coach(&words, &words, &after_win).unwrap_err();
+        assert!(error.contains("already solved"), "{error}");
+    }
+
+    #[test]
+    fn grades_from_skill() {
+        assert_eq!(Grade::from_skill(100.0), Grade::Best);
+        assert_eq!(Grade::from_skill(99.6), Grade::Best);
+        assert_eq!(Grade::from_skill(95.0), Grade::Great);
+        assert_eq!(Grade::from_skill(80.0), Grade::Good);
+        assert_eq!(Grade::from_skill(50.0), Grade::Fair);
+        assert_eq!(Grade::from_skill(0.0), Grade::Poor);
+    }
+}
@@ -0,0 +1,152 @@
+//! Strategy diff command
+//!
+//! Plays two strategies against the same answer and reports where their
+//! guess sequences part ways.
+
+use crate::core::Word;
+use crate::output::formatters::progress_bar;
+use crate::solver::{GameRecord, MAX_GUESSES, Solver, Strategy, simulate_game};
+
+/// The first turn at which two strategies chose different guesses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Divergence {
+    /// 1-based turn number
+    pub turn: usize,
+    /// Candidates remaining before that turn (identical for both, since the
+    /// earlier guesses matched)
+    pub candidates: usize,
+}
+
+/// Side-by-side games of two strategies on one answer
+#[derive(Debug, Clone)]
+pub struct StrategyDiff {
+    pub answer: String,
+    pub first: GameRecord,
+    pub second: GameRecord,
+    /// `None` if both strategies made exactly the same guesses
+    pub divergence: Option<Divergence>,
+}
+
+impl StrategyDiff {
+    /// Guesses used by the second strategy minus the first
+    ///
+    /// `None` if either strategy failed: a failed game stops at the guess
+    /// limit, so its count says nothing about how close it came.
+    #[must_use]
+    pub fn guess_difference(&self) -> Option<isize> {
+        (self.first.success && self.second.success).then(|| {
+            self.second.num_guesses().cast_signed() - self.first.num_guesses().cast_signed()
+        })
+    }
+}
+
+/// Play both strategies against `answer` and locate the first differing guess
+///
+/// Divergence is detected on guess text, so two games that differ mid-way
+/// but finish in the same number of guesses still count as diverged.
+pub fn diff_strategies<A: Strategy, B: Strategy>(
+    first: &Solver<A>,
+    second: &Solver<B>,
+    answer: &Word,
+) -> StrategyDiff {
+    let first_record = simulate_game(first, answer, MAX_GUESSES, None);
+    let second_record = simulate_game(second, answer, MAX_GUESSES, None);
+
+    let shared = first_record
+        .guesses()
+        .zip(second_record.guesses())
+        .take_while(|(a, b)| a.text() == b.text())
+        .count();
+    let diverged = shared < first_record.num_guesses().max(second_record.num_guesses());
+
+    let divergence = diverged.then(|| Divergence {
+        turn: shared + 1,
+        candidates: first.count_candidates(&first_record.history[..shared]),
+    });
+
+    StrategyDiff {
+        answer: answer.text().to_string(),
+        first: first_record,
+        second: second_record,
+        divergence,
+    }
+}
+
+/// Diff two strategies on every answer, keeping only those that diverge
+pub fn diff_strategies_all<A: Strategy, B: Strategy>(
+    first: &Solver<A>,
+    second: &Solver<B>,
+    answers: &[Word],
+) -> Vec<StrategyDiff> {
+    let pb = progress_bar(answers.len(), "");
+
+    let mut diffs = Vec::new();
+    for answer in answers {
+        let diff = diff_strategies(first, second, answer);
+        if diff.divergence.is_some() {
+            diffs.push(diff);
+            pb.set_message(format!("{} differ", diffs.len()));
+        }
+        pb.inc(1);
+    }
+    pb.finish_and_clear();
+
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{EntropyStrategy, MinimaxStrategy};
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn identical_strategies_do_not_diverge() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+        let first = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let second = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        let diff = diff_strategies(&first, &second, &answer_words[10]);
+
+        assert_eq!(diff.divergence, None);
+        assert_eq!(diff.guess_difference(), Some(0));
+        assert!(diff_strategies_all(&first, &second, &answer_words).is_empty());
+    }
+
+    #[test]
+    fn divergence_marks_first_differing_guess() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+        let first = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let second = Solver::new(MinimaxStrategy, &all_words, &answer_words);
+
+        for diff in diff_strategies_all(&first, &second, &answer_words) {
+            let divergence = diff.divergence.unwrap();
+            let turn = divergence.turn - 1;
+
+            let a: Vec<&str> = diff.first.guesses().map(Word::text).collect();
+            let b: Vec<&str> = diff.second.guesses().map(Word::text).collect();
+            assert_eq!(a[..turn], b[..turn]);
+            assert_ne!(a.get(turn), b.get(turn));
+            assert_eq!(
+                divergence.candidates,
+                first.count_candidates(&diff.first.history[..turn])
+            );
+        }
+    }
+
+    #[test]
+    fn failed_games_have_no_guess_difference() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+        let first = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let second = Solver::new(MinimaxStrategy, &all_words, &answer_words);
+
+        let mut diff = diff_strategies(&first, &second, &answer_words[0]);
+        assert!(diff.guess_difference().is_some());
+        diff.second.success = false;
+        assert_eq!(diff.guess_difference(), None);
+    }
+}
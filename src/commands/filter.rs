@@ -0,0 +1,82 @@
+//! Candidate filter command
+//!
+//! Lists the answers consistent with feedback and with per-position letter
+//! classes, such as "the first letter is a vowel".
+
+use crate::core::{Constraints, LetterClass, Word};
+
+/// Parse a position restriction like `1=vowel`, `5=consonant` or `3=rst`
+///
+/// Positions count from 1 as players do; the returned position is 0-based.
+///
+/// # Errors
+///
+/// Returns an error if the position is not 1-5 or the class is not
+/// `vowel`, `consonant` or a set of letters.
+pub fn parse_position_class(spec: &str) -> Result<(usize, LetterClass), String> {
+    let (position, class) = spec
+        .split_once('=')
+        .ok_or_else(|| format!("Expected POSITION=CLASS like 1=vowel, got '{spec}'"))?;
+    let position = position
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|p| (1..=5).contains(p))
+        .ok_or_else(|| format!("Position must be 1-5, got '{position}'"))?;
+    let class = LetterClass::parse(class).ok_or_else(|| {
+        format!("Class must be vowel, consonant or letters like rst, got '{class}'")
+    })?;
+    Ok((position - 1, class))
+}
+
+/// Answers the constraints allow, in answer list order
+#[must_use]
+pub fn filter_answers<'a>(answers: &'a [Word], constraints: &Constraints) -> Vec<&'a Word> {
+    answers.iter().filter(|a| constraints.allows(a)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Pattern;
+    use crate::wordlists::ANSWERS;
+    use crate::wordlists::loader::words_from_slice;
+
+    #[test]
+    fn parses_position_classes() {
+        assert_eq!(
+            parse_position_class("1=vowel"),
+            Ok((0, LetterClass::VOWELS))
+        );
+        assert_eq!(
+            parse_position_class(" 5 = Consonant "),
+            Ok((4, LetterClass::CONSONANTS))
+        );
+        assert_eq!(
+            parse_position_class("3=rst"),
+            Ok((2, LetterClass::from_letters(b"rst")))
+        );
+        for bad in ["vowel", "0=vowel", "6=vowel", "x=vowel", "2=", "2=r5"] {
+            assert!(parse_position_class(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn classes_narrow_the_feedback_candidates() {
+        let answers = words_from_slice(ANSWERS);
+        // An E somewhere but not last
+        let guess = Word::new("crane").unwrap();
+        let mut constraints = Constraints::new(vec![(guess, Pattern::from_str("----Y").unwrap())]);
+        let by_feedback = filter_answers(&answers, &constraints);
+
+        constraints.restrict(0, LetterClass::CONSONANTS);
+        let narrowed = filter_answers(&answers, &constraints);
+
+        assert!(!narrowed.is_empty() && narrowed.len() < by_feedback.len());
+        for word in &narrowed {
+            assert!(by_feedback.contains(word));
+            assert!(!LetterClass::VOWELS.contains(word.char_at(0)));
+            assert!(word.has_letter(b'e'));
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! First guess analysis command
+//!
+//! Shows how much work an opener leaves: for every answer, how many
+//! candidates remain after the opener's feedback. Two openers with similar
+//! entropy can leave quite different tails, which is what drives the
+//! average guess count.
+
+use crate::core::{FxHashMap, Pattern, Word};
+use crate::solver::{Solver, Strategy};
+
+/// Upper bounds of the histogram buckets; the last bucket is open-ended
+const BUCKET_BOUNDS: [usize; 8] = [1, 2, 5, 10, 20, 50, 100, 200];
+
+/// Answers whose remaining candidate count falls in `low..=high`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemainingBucket {
+    pub low: usize,
+    /// `None` for the open-ended last bucket
+    pub high: Option<usize>,
+    pub answers: usize,
+}
+
+/// Candidates an opener leaves across all answers
+#[derive(Debug, Clone)]
+pub struct FirstGuessAnalysis {
+    pub opener: String,
+    /// Candidates left after the opener, one entry per answer, smallest first
+    pub remaining: Vec<usize>,
+}
+
+impl FirstGuessAnalysis {
+    /// Answers the opener was played against
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.remaining.len()
+    }
+
+    /// Expected candidates left when the answer is drawn uniformly
+    #[must_use]
+    pub fn mean(&self) -> f64 {
+        if self.remaining.is_empty() {
+            return 0.0;
+        }
+        self.remaining.iter().sum::<usize>() as f64 / self.remaining.len() as f64
+    }
+
+    /// Median candidates left; the mean of the middle two for an even count
+    #[must_use]
+    pub fn median(&self) -> f64 {
+        let n = self.remaining.len();
+        match n {
+            0 => 0.0,
+            _ if n % 2 == 1 => self.remaining[n / 2] as f64,
+            _ => (self.remaining[n / 2 - 1] + self.remaining[n / 2]) as f64 / 2.0,
+        }
+    }
+
+    /// Most candidates left, which is the opener's largest partition
+    #[must_use]
+    pub fn max(&self) -> usize {
+        self.remaining.last().copied().unwrap_or(0)
+    }
+
+    /// Answers per remaining-candidate range, smallest range first
+    ///
+    /// Every bucket is listed, including empty ones, so histograms for
+    /// different openers line up.
+    #[must_use]
+    pub fn histogram(&self) -> Vec<RemainingBucket> {
+        let mut low = 1;
+        let mut buckets: Vec<RemainingBucket> = BUCKET_BOUNDS
+            .iter()
+            .map(|&high| {
+                let bucket = RemainingBucket {
+                    low,
+                    high: Some(high),
+                    answers: 0,
+                };
+                low = high + 1;
+                bucket
+            })
+            .collect();
+        buckets.push(RemainingBucket {
+            low,
+            high: None,
+            answers: 0,
+        });
+
+        for &count in &self.remaining {
+            let i = BUCKET_BOUNDS
+                .iter()
+                .position(|&high| count <= high)
+                .unwrap_or(BUCKET_BOUNDS.len());
+            buckets[i].answers += 1;
+        }
+        buckets
+    }
+}
+
+/// Count the candidates left after `opener` for every answer
+///
+/// `opener` defaults to the solver's opener. Answers giving the same
+/// feedback leave the same candidates, so each pattern is filtered once.
+///
+/// # Errors
+///
+/// Returns an error if there is no opener or no answers.
+pub fn first_guess_analysis<S: Strategy>(
+    solver: &Solver<S>,
+    opener: Option<&Word>,
+) -> Result<FirstGuessAnalysis, String> {
+    let opener = opener
+        .or_else(|| solver.first_guess())
+        .ok_or("No opener available")?;
+    let answers = solver.answer_words();
+    if answers.is_empty() {
+        return Err("No answers to analyze".to_string());
+    }
+
+    let mut by_pattern: FxHashMap<Pattern, usize> = FxHashMap::default();
+    let mut remaining: Vec<usize> = answers
+        .iter()
+        .map(|answer| {
+            let pattern = Pattern::calculate(opener, answer);
+            *by_pattern
+                .entry(pattern)
+                .or_insert_with(|| solver.count_candidates(&[(opener.clone(), pattern)]))
+        })
+        .collect();
+    remaining.sort_unstable();
+
+    Ok(FirstGuessAnalysis {
+        opener: opener.text().to_string(),
+        remaining,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::EntropyStrategy;
+    use crate::solver::entropy::calculate_metrics;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn remaining_counts_match_the_partition() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(&ANSWERS[..400]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let opener = Word::new("salet").unwrap();
+
+        let analysis = first_guess_analysis(&solver, Some(&opener)).unwrap();
+        assert_eq!(analysis.opener, "salet");
+        assert_eq!(analysis.total(), 400);
+
+        let answer_refs: Vec<&Word> = answer_words.iter().collect();
+        let metrics = calculate_metrics(&opener, &answer_refs);
+        assert_eq!(analysis.max(), metrics.max_partition);
+        assert!((analysis.mean() - metrics.expected_remaining).abs() < 1e-9);
+        assert!(analysis.median() <= analysis.max() as f64);
+
+        let histogram = analysis.histogram();
+        assert_eq!(histogram.len(), BUCKET_BOUNDS.len() + 1);
+        assert_eq!(histogram.iter().map(|b| b.answers).sum::<usize>(), 400);
+    }
+
+    #[test]
+    fn summary_statistics() {
+        let analysis = FirstGuessAnalysis {
+            opener: "salet".to_string(),
+            remaining: vec![1, 2, 2, 7, 7, 7, 7, 250],
+        };
+        assert!((analysis.mean() - 35.375).abs() < 1e-12);
+        assert!((analysis.median() - 7.0).abs() < 1e-12);
+        assert_eq!(analysis.max(), 250);
+
+        let counts: Vec<usize> = analysis.histogram().iter().map(|b| b.answers).collect();
+        assert_eq!(counts, vec![1, 2, 0, 4, 0, 0, 0, 0, 1]);
+        let last = analysis.histogram()[BUCKET_BOUNDS.len()];
+        assert_eq!((last.low, last.high), (201, None));
+    }
+}
@@ -0,0 +1,273 @@
+//! Hard mode opening command
+//!
+//! Plans a fixed opening line for hard mode, where every guess must reuse
+//! the letters revealed so far. The line follows the most likely feedback,
+//! then every answer is played out to see how often the line stays legal and
+//! what it averages.
+
+use crate::core::{Constraints, Pattern, Word};
+use crate::output::formatters::progress_bar;
+use crate::solver::{MAX_GUESSES, Solver, Strategy};
+use indicatif::ProgressBar;
+
+/// Guesses in a planned opening line, including the first word
+pub const HARD_OPENING_LENGTH: usize = 3;
+
+/// One guess of a planned opening line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningStep {
+    pub guess: String,
+    /// Most likely feedback, which the next guess was planned for
+    pub assumed: Pattern,
+    /// Answers left after the assumed feedback
+    pub remaining: usize,
+}
+
+/// A hard mode opening line and how it performs over the answers
+#[derive(Debug, Clone)]
+pub struct HardOpening {
+    /// Planned guesses in order, starting with the first word
+    pub line: Vec<OpeningStep>,
+    pub total_answers: usize,
+    /// Answers for which hard mode never ruled out a planned guess
+    pub followed: usize,
+    /// Answers solved within `MAX_GUESSES`
+    pub solved: usize,
+    /// Average guesses over solved answers
+    pub average: f64,
+}
+
+/// Plan a hard mode opening line from `first` and play it on every answer
+///
+/// Each later guess of the line is the strategy's pick among hard mode legal
+/// guesses for the answers left after the most likely feedback so far, so
+/// the line is always legal along that path. On any other feedback a planned
+/// guess may be illegal; from there the strategy picks the best legal guess
+/// instead. The line is also dropped once only one answer remains. Seed
+/// strategies with random choices first for a stable result.
+///
+/// `first` defaults to the solver's opener.
+///
+/// # Errors
+///
+/// Returns an error if there is no first guess or no answers.
+pub fn hard_opening<S: Strategy>(
+    solver: &Solver<S>,
+    first: Option<&Word>,
+) -> Result<HardOpening, String> {
+    let first = first
+        .or_else(|| solver.first_guess())
+        .ok_or("No first guess available")?;
+    let answers = solver.get_candidates(&[]);
+    if answers.is_empty() {
+        return Err("No answers to plan for".to_string());
+    }
+
+    let (guesses, line) = plan_line(solver, first, answers.clone());
+
+    let pb = progress_bar(answers.len(), "");
+
+    let mut walk = LineWalk {
+        solver,
+        line: &guesses,
+        pb: &pb,
+        diverted: 0,
+        solved: 0,
+        total_guesses: 0,
+    };
+    walk.visit(&mut Vec::new(), answers.clone(), true);
+    pb.finish_and_clear();
+
+    Ok(HardOpening {
+        line,
+        total_answers: answers.len(),
+        followed: answers.len() - walk.diverted,
+        solved: walk.solved,
+        average: if walk.solved == 0 {
+            0.0
+        } else {
+            walk.total_guesses as f64 / walk.solved as f64
+        },
+    })
+}
+
+/// Follow the most likely feedback from `first`, picking each next guess
+///
+/// A win is never assumed: the all-green group is only followed when it is
+/// the only one, which ends the line. Returns the planned guesses alongside their report steps.
+fn plan_line<S: Strategy>(
+    solver: &Solver<S>,
+    first: &Word,
+    mut candidates: Vec<&Word>,
+) -> (Vec<Word>, Vec<OpeningStep>) {
+    let mut history: Vec<(Word, Pattern)> = Vec::new();
+    let mut line = Vec::new();
+    let mut guess = Some(first.clone());
+
+    while let Some(current) = guess {
+        // The all-green group holds at most one answer, so it is never
+        // larger than the second-largest group unless it is the only one
+        let groups = Pattern::largest_groups(&current, &candidates, 2);
+        let (pattern, _) = groups
+            .iter()
+            .copied()
+            .find(|(pattern, _)| !pattern.is_perfect())
+            .unwrap_or(groups[0]);
+        candidates.retain(|c| Pattern::calculate(&current, c) == pattern);
+        line.push(OpeningStep {
+            guess: current.text().to_string(),
+            assumed: pattern,
+            remaining: candidates.len(),
+        });
+        history.push((current, pattern));
+
+        guess = if line.len() < HARD_OPENING_LENGTH && candidates.len() > 1 {
+            best_legal(solver, &history, &candidates)
+        } else {
+            None
+        };
+    }
+
+    let guesses = history.into_iter().map(|(guess, _)| guess).collect();
+    (guesses, line)
+}
+
+/// The strategy's pick among guesses hard mode allows after `history`
+fn best_legal<S: Strategy>(
+    solver: &Solver<S>,
+    history: &[(Word, Pattern)],
+    candidates: &[&Word],
+) -> Option<Word> {
+    if let [only] = candidates {
+        return Some((*only).clone());
+    }
+
+    let constraints = Constraints::new(history.to_vec());
+    let pool: Vec<&Word> = solver
+        .all_words()
+        .iter()
+        .filter(|w| constraints.hard_mode_allows(w))
+        .collect();
+
+    solver.strategy().select_among(&pool, candidates).cloned()
+}
+
+/// State for playing the line on every answer at once
+///
+/// Answers with the same feedback so far share a node, so each game state is
+/// solved once.
+struct LineWalk<'s, 'w, S: Strategy> {
+    solver: &'s Solver<'w, S>,
+    line: &'s [Word],
+    pb: &'s ProgressBar,
+    /// Answers that reached an illegal planned guess
+    diverted: usize,
+    solved: usize,
+    total_guesses: usize,
+}
+
+impl<S: Strategy> LineWalk<'_, '_, S> {
+    /// Visit the state reached by `history`, where `candidates` remain
+    fn visit(&mut self, history: &mut Vec<(Word, Pattern)>, candidates: Vec<&Word>, on_line: bool) {
+        let planned = self
+            .line
+            .get(history.len())
+            .filter(|_| on_line && (history.is_empty() || candidates.len() > 1));
+        let legal = planned.filter(|g| Constraints::new(history.clone()).hard_mode_allows(g));
+        if planned.is_some() && legal.is_none() {
+            self.diverted += candidates.len();
+        }
+        let on_line = legal.is_some();
+
+        let guess = match legal {
+            Some(guess) => Some(guess.clone()),
+            None if history.len() < MAX_GUESSES => best_legal(self.solver, history, &candidates),
+            None => None,
+        };
+        let Some(guess) = guess else {
+            self.pb.inc(candidates.len() as u64);
+            return;
+        };
+
+        let mut groups: Vec<(Pattern, Vec<&Word>)> = Vec::new();
+        for answer in candidates {
+            let pattern = Pattern::calculate(&guess, answer);
+            if pattern.is_perfect() {
+                self.solved += 1;
+                self.total_guesses += history.len() + 1;
+                self.pb.inc(1);
+            } else if let Some((_, group)) = groups.iter_mut().find(|(p, _)| *p == pattern) {
+                group.push(answer);
+            } else {
+                groups.push((pattern, vec![answer]));
+            }
+        }
+
+        for (pattern, group) in groups {
+            history.push((guess.clone(), pattern));
+            self.visit(history, group, on_line);
+            history.pop();
+        }
+        self.pb
+            .set_message(format!("{} off the line", self.diverted));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::EntropyStrategy;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn line_is_legal_along_the_planned_path() {
+        let all_words = words_from_slice(&ALLOWED[..300]);
+        let answer_words = words_from_slice(&ANSWERS[..80]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let first = &all_words[11];
+
+        let report = hard_opening(&solver, Some(first)).unwrap();
+
+        assert_eq!(report.line[0].guess, first.text());
+        assert!(report.line.len() <= HARD_OPENING_LENGTH);
+        let mut constraints = Constraints::default();
+        for step in &report.line {
+            let guess = Word::new(step.guess.as_str()).unwrap();
+            assert!(constraints.hard_mode_allows(&guess), "{}", step.guess);
+            constraints.push(guess, step.assumed);
+        }
+        for pair in report.line.windows(2) {
+            assert!(pair[1].remaining <= pair[0].remaining);
+        }
+
+        assert_eq!(report.total_answers, answer_words.len());
+        assert!(report.followed <= report.total_answers);
+        assert!(report.solved <= report.total_answers);
+        assert!(report.average >= 1.0 && report.average <= MAX_GUESSES as f64);
+    }
+
+    #[test]
+    fn line_never_assumes_a_win() {
+        let all_words = words_from_slice(&["crane", "crate", "slate", "pious"]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &all_words);
+
+        // Every answer gives CRANE different feedback, CRANE's own all-green
+        // included, so any one of them could be picked as most likely
+        let report = hard_opening(&solver, Some(&all_words[0])).unwrap();
+        assert!(!report.line[0].assumed.is_perfect());
+        assert_eq!(report.line[0].remaining, 1);
+    }
+
+    #[test]
+    fn defaults_to_the_solver_opener() {
+        let all_words = words_from_slice(&ALLOWED[..200]);
+        let answer_words = words_from_slice(&ANSWERS[..40]);
+        let opener = all_words[3].clone();
+        let solver =
+            Solver::new(EntropyStrategy, &all_words, &answer_words).with_opener(Some(&opener));
+
+        let report = hard_opening(&solver, None).unwrap();
+        assert_eq!(report.line[0].guess, opener.text());
+    }
+}
@@ -0,0 +1,350 @@
+//! Spoiler-free hint command
+//!
+//! Gives progressively stronger hints about a hidden answer without naming it.
+
+use crate::core::{Constraints, LetterClue, Pattern, Word};
+use crate::output::formatters::{count_word, word_label};
+use crate::solver::{Solver, Strategy};
+
+/// Produce a hint for the hidden `answer` given the guesses made so far
+///
+/// Levels:
+/// - 1: One letter of the answer not yet revealed by green/yellow feedback,
+///   or an extra copy of a letter whose full count isn't known yet
+/// - 2: A good next guess (never the answer itself)
+/// - 3+: How many candidate words remain
+///
+/// Hints are deterministic for the same state, given a deterministic
+/// strategy (seed any random choices). Level 1 picks the alphabetically
+/// first unrevealed letter, so it leaks exactly one letter (or one extra
+/// occurrence of a letter).
+///
+/// # Errors
+///
+/// Returns an error if `level` is 0.
+pub fn hint<S: Strategy>(
+    solver: &Solver<S>,
+    history: &[(Word, Pattern)],
+    answer: &Word,
+    level: u8,
+) -> Result<String, String> {
+    match level {
+        0 => Err("Hint level must be at least 1".to_string()),
+        1 => Ok(letter_hint(history, answer)),
+        2 => Ok(guess_hint(solver, history, answer)),
+        _ => {
+            let count = solver.count_candidates(history);
+            Ok(if count == 1 {
+                "Only 1 candidate word remains.".to_string()
+            } else {
+                format!("{count} candidate words remain.")
+            })
+        }
+    }
+}
+
+/// Reveal one answer letter, or one extra occurrence, that the feedback hasn't shown
+fn letter_hint(history: &[(Word, Pattern)], answer: &Word) -> String {
+    let constraints = Constraints::new(history.to_vec());
+
+    let mut letters = *answer.chars();
+    letters.sort_unstable();
+
+    letters
+        .into_iter()
+        .find_map(|ch| {
+            let count = answer.positions_of(ch).len();
+            let shown = usize::from(constraints.letter_clue(ch).min);
+            (shown < count).then_some((ch, shown))
+        })
+        .map_or_else(
+            || "You already know every letter in the answer.".to_string(),
+            |(ch, shown)| {
+                let letter = char::from(ch.to_ascii_uppercase());
+                if shown == 0 {
+                    format!("The answer contains the letter {letter}.")
+                } else {
+                    format!(
+                        "The answer contains at least {} {letter}s.",
+                        count_word(shown + 1)
+                    )
+                }
+            },
+        )
+}
+
+/// Describe what the feedback so far proves about each letter in the guesses
+///
+/// One line per letter known to be in the answer, distinguishing "at least"
+/// from "exactly" counts and listing known and ruled-out slots (1-based).
+/// Letters shown absent are summarized on a final line.
+#[must_use]
+pub fn clue_summary(history: &[(Word, Pattern)]) -> Vec<String> {
+    let constraints = Constraints::new(history.to_vec());
+
+    let mut guessed: Vec<u8> = history
+        .iter()
+        .flat_map(|(guess, _)| guess.chars().iter().copied())
+        .collect();
+    guessed.sort_unstable();
+    guessed.dedup();
+
+    let mut lines = Vec::new();
+    let mut absent = String::new();
+    for letter in guessed {
+        let clue = constraints.letter_clue(letter);
+        let upper = char::from(letter.to_ascii_uppercase());
+        if clue.max == 0 {
+            absent.push(upper);
+        } else {
+            lines.push(describe_clue(upper, &clue));
+        }
+    }
+    if !absent.is_empty() {
+        lines.push(format!(
+            "No {}.",
+            absent
+                .chars()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    lines
+}
+
+/// Phrase one letter's clue, e.g. "Contains exactly one E, not in slot 3."
+fn describe_clue(letter: char, clue: &LetterClue) -> String {
+    let bound = if clue.is_exact() {
+        "exactly"
+    } else {
+        "at least"
+    };
+    let plural = if clue.min == 1 { "" } else { "s" };
+    let count = format!(
+        "Contains {bound} {} {letter}{plural}",
+        count_word(usize::from(clue.min))
+    );
+
+    let excluded: Vec<usize> = clue
+        .excluded
+        .iter()
+        .copied()
+        .filter(|i| !clue.known.contains(i))
+        .collect();
+    match (clue.known.is_empty(), excluded.is_empty()) {
+        (true, true) => format!("{count}."),
+        (false, true) => format!("{count}, in {}.", slots(&clue.known, "and")),
+        (true, false) => format!("{count}, not in {}.", slots(&excluded, "or")),
+        (false, false) => format!(
+            "{count}, in {} and not in {}.",
+            slots(&clue.known, "and"),
+            slots(&excluded, "or")
+        ),
+    }
+}
+
+/// Format 0-based positions as 1-based slots joined by `conjunction`, e.g.
+/// "slots 1 and 3" for known positions or "slots 1 or 3" for ruled-out ones
+fn slots(positions: &[usize], conjunction: &str) -> String {
+    let numbers: Vec<String> = positions.iter().map(|i| (i + 1).to_string()).collect();
+    match numbers.as_slice() {
+        [only] => format!("slot {only}"),
+        [rest @ .., last] => format!("slots {} {conjunction} {last}", rest.join(", ")),
+        [] => String::new(),
+    }
+}
+
+/// Suggest the solver's next guess, avoiding the answer itself
+fn guess_hint<S: Strategy>(
+    solver: &Solver<S>,
+    history: &[(Word, Pattern)],
+    answer: &Word,
+) -> String {
+    let suggestion = solver
+        .next_guess(history)
+        .filter(|guess| *guess != answer)
+        .or_else(|| {
+            solver
+                .rank_guesses(history, 2)
+                .into_iter()
+                .map(|(word, _)| word)
+                .find(|word| *word != answer)
+        });
+
+    suggestion.map_or_else(
+        || "No good guesses left to suggest - you have enough to solve it.".to_string(),
+        |guess| format!("A good next guess is {}.", word_label(guess)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::EntropyStrategy;
+
+    fn setup() -> (Vec<Word>, Vec<Word>) {
+        let all_words: Vec<Word> = ["crane", "slate", "irate", "crate", "grate", "trace"]
+            .iter()
+            .map(|w| Word::new(*w).unwrap())
+            .collect();
+        let answer_words = all_words[2..].to_vec();
+        (all_words, answer_words)
+    }
+
+    #[test]
+    fn level_one_reveals_single_unknown_letter() {
+        let (all_words, answer_words) = setup();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let answer = Word::new("grate").unwrap();
+
+        // No history: alphabetically first letter of GRATE
+        assert_eq!(
+            hint(&solver, &[], &answer, 1).unwrap(),
+            "The answer contains the letter A."
+        );
+
+        // CRANE reveals R, A, E - next unrevealed letter is G
+        let guess = Word::new("crane").unwrap();
+        let pattern = Pattern::calculate(&guess, &answer);
+        let history = vec![(guess, pattern)];
+        assert_eq!(
+            hint(&solver, &history, &answer, 1).unwrap(),
+            "The answer contains the letter G."
+        );
+    }
+
+    #[test]
+    fn level_one_all_letters_known() {
+        let (all_words, answer_words) = setup();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let answer = Word::new("crate").unwrap();
+
+        let guess = Word::new("trace").unwrap();
+        let pattern = Pattern::calculate(&guess, &answer);
+        let history = vec![(guess, pattern)];
+
+        assert_eq!(
+            hint(&solver, &history, &answer, 1).unwrap(),
+            "You already know every letter in the answer."
+        );
+    }
+
+    #[test]
+    fn level_two_never_names_answer() {
+        let (all_words, answer_words) = setup();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        for answer in &answer_words {
+            let text = hint(&solver, &[], answer, 2).unwrap();
+            assert!(!text.contains(&answer.text().to_uppercase()), "{text}");
+        }
+    }
+
+    #[test]
+    fn level_three_counts_candidates() {
+        let (all_words, answer_words) = setup();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let answer = Word::new("grate").unwrap();
+
+        assert_eq!(
+            hint(&solver, &[], &answer, 3).unwrap(),
+            "4 candidate words remain."
+        );
+        assert!(hint(&solver, &[], &answer, 0).is_err());
+    }
+
+    #[test]
+    fn level_one_reveals_extra_occurrence() {
+        let all_words: Vec<Word> = ["geese", "there"]
+            .iter()
+            .map(|w| Word::new(*w).unwrap())
+            .collect();
+        let solver = Solver::new(EntropyStrategy, &all_words, &all_words);
+        let answer = Word::new("geese").unwrap();
+
+        // THERE shows two of GEESE's three Es; E sorts before G
+        let guess = Word::new("there").unwrap();
+        let pattern = Pattern::calculate(&guess, &answer);
+        let history = vec![(guess, pattern)];
+        assert_eq!(
+            hint(&solver, &history, &answer, 1).unwrap(),
+            "The answer contains at least three Es."
+        );
+    }
+
+    #[test]
+    fn clue_summary_distinguishes_exact_counts() {
+        let answer = Word::new("crane").unwrap();
+        let history: Vec<(Word, Pattern)> = ["table", "eerie"]
+            .iter()
+            .map(|g| {
+                let guess = Word::new(*g).unwrap();
+                let pattern = Pattern::calculate(&guess, &answer);
+                (guess, pattern)
+            })
+            .collect();
+
+        let lines = clue_summary(&history[..1]);
+        assert!(lines.contains(&"Contains at least one A, not in slot 2.".to_string()));
+        assert!(lines.contains(&"Contains at least one E, in slot 5.".to_string()));
+
+        let lines = clue_summary(&history);
+        assert!(
+            lines.contains(
+                &"Contains exactly one E, in slot 5 and not in slots 1 or 2.".to_string()
+            )
+        );
+        assert!(lines.contains(&"Contains at least one R, not in slot 3.".to_string()));
+        assert_eq!(lines.last().unwrap(), "No B, I, L, T.");
+    }
+
+    #[test]
+    fn clue_summary_counts_greens_from_different_guesses() {
+        let answer = Word::new("geese").unwrap();
+        let history: Vec<(Word, Pattern)> = ["beach", "spite"]
+            .iter()
+            .map(|g| {
+                let guess = Word::new(*g).unwrap();
+                let pattern = Pattern::calculate(&guess, &answer);
+                (guess, pattern)
+            })
+            .collect();
+
+        let lines = clue_summary(&history);
+        assert!(
+            lines.contains(&"Contains at least two Es, in slots 2 and 5.".to_string()),
+            "{lines:?}"
+        );
+    }
+
+    #[test]
+    fn hints_are_deterministic() {
+        use crate::solver::AdaptiveStrategy;
+
+        let (mut all_words, answer_words) = setup();
+        // Tells IRATE from GRATE as well as IRATE does, so the suggestion depends
+        // on which candidate the random tier picks
+        all_words.push(Word::new("gamer").unwrap());
+        let answer = Word::new("grate").unwrap();
+        let crane = all_words[0].clone();
+        let history = vec![(crane.clone(), Pattern::calculate(&crane, &answer))];
+
+        // IRATE and GRATE remain, the adaptive strategy's random tier
+        let solver = Solver::new(AdaptiveStrategy::default(), &all_words, &answer_words);
+        assert_eq!(solver.count_candidates(&history), 2);
+
+        // Fresh solvers each time, as separate runs of the command would build
+        let hint_with = |seed| {
+            let strategy = AdaptiveStrategy::default().with_random_seed(seed);
+            let solver = Solver::new(strategy, &all_words, &answer_words);
+            (1..=3)
+                .map(|level| hint(&solver, &history, &answer, level).unwrap())
+                .collect::<Vec<_>>()
+        };
+        let first = hint_with(0);
+        for _ in 0..20 {
+            assert_eq!(hint_with(0), first);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Guaranteed solve command
+//!
+//! Answers "how many more guesses does this position need, at worst?" by
+//! running the exact endgame search on the candidates left after some
+//! clues. The search is exponential, so it only runs on small sets.
+
+use crate::core::{Pattern, Word};
+use crate::solver::selection::plan_endgame;
+use crate::solver::{Solver, Strategy};
+
+/// Most candidates searched exactly by default
+///
+/// The search time grows steeply with the candidate count: with the full
+/// guess pool, 8 candidates take about a second on one core and 12 about
+/// half a minute.
+pub const DEFAULT_EXACT_LIMIT: usize = 12;
+
+/// Most candidates the search supports at all (they are tracked as a bitmask)
+const MAX_EXACT_LIMIT: usize = 64;
+
+/// The fewest guesses that guarantee a solve from a position
+#[derive(Debug, Clone)]
+pub struct GuaranteedSolve {
+    /// Candidates consistent with the history
+    pub candidates: usize,
+    /// Guesses already played
+    pub played: usize,
+    /// A guess that achieves the guarantee
+    pub guess: String,
+    /// Whether the guess could itself be the answer
+    pub is_candidate: bool,
+    /// Guesses needed in the worst case from here, including `guess`
+    pub worst: usize,
+    /// Candidates that need all `worst` guesses
+    pub at_worst: usize,
+    /// Expected guesses from here when playing this line
+    pub expected: f64,
+}
+
+impl GuaranteedSolve {
+    /// Worst-case guess count for the whole game
+    #[must_use]
+    pub const fn total_worst(&self) -> usize {
+        self.played + self.worst
+    }
+}
+
+/// Find the minimum guaranteed guess count from the position after `history`
+///
+/// Searches the full game tree over the remaining candidates with the
+/// solver's guess pool (see [`plan_endgame`]).
+///
+/// # Errors
+///
+/// Returns an error if no answer fits the history, more than `limit`
+/// candidates remain (the search would not finish in reasonable time), or
+/// no guess in the pool can tell the candidates apart.
+pub fn min_guaranteed<S: Strategy>(
+    solver: &Solver<S>,
+    history: &[(Word, Pattern)],
+    limit: usize,
+) -> Result<GuaranteedSolve, String> {
+    let candidates = solver.get_candidates(history);
+    if candidates.is_empty() {
+        return Err("No answers fit this feedback".to_string());
+    }
+    let limit = limit.min(MAX_EXACT_LIMIT);
+    if candidates.len() > limit {
+        let advice = if limit < MAX_EXACT_LIMIT {
+            "add more clues or raise --max-candidates"
+        } else {
+            "add more clues"
+        };
+        return Err(format!(
+            "{} candidates remain; the exact search is limited to {limit}, so {advice}",
+            candidates.len()
+        ));
+    }
+
+    let guess_refs: Vec<&Word> = solver.all_words().iter().collect();
+    let plan = plan_endgame(&guess_refs, &candidates)
+        .ok_or("No guess in the pool can tell the candidates apart")?;
+
+    Ok(GuaranteedSolve {
+        candidates: candidates.len(),
+        played: history.len(),
+        guess: plan.guess.text().to_string(),
+        is_candidate: candidates.contains(&plan.guess),
+        worst: plan.worst,
+        at_worst: plan.at_worst,
+        expected: plan.expected,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::EntropyStrategy;
+    use crate::solver::minimax::calculate_max_remaining;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn guarantee_after_clues() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(ANSWERS);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let history = vec![(
+            Word::new("salet").unwrap(),
+            Pattern::from_str("GG---").unwrap(),
+        )];
+        let candidates = solver.get_candidates(&history);
+        assert_eq!(candidates.len(), 8);
+
+        let result = min_guaranteed(&solver, &history, DEFAULT_EXACT_LIMIT).unwrap();
+        assert_eq!(result.candidates, candidates.len());
+        assert_eq!(result.played, 1);
+        assert_eq!(result.total_worst(), 1 + result.worst);
+        assert!(result.at_worst >= 1 && result.at_worst <= result.candidates);
+        assert!(result.expected >= 1.0 && result.expected <= result.worst as f64);
+
+        // No guess tells all 8 apart, so 2 can't be guaranteed; the best
+        // guess's worst group must be solvable in the remaining 2
+        assert_eq!(result.worst, 3);
+        let pool: Vec<&Word> = all_words.iter().collect();
+        assert!(
+            pool.iter()
+                .all(|guess| calculate_max_remaining(guess, &candidates) > 1)
+        );
+    }
+
+    #[test]
+    fn refuses_large_candidate_sets() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(ANSWERS);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        let error = min_guaranteed(&solver, &[], DEFAULT_EXACT_LIMIT).unwrap_err();
+        assert!(error.starts_with("2315 candidates remain"), "{error}");
+        assert!(error.contains("--max-candidates"));
+        let error = min_guaranteed(&solver, &[], 1000).unwrap_err();
+        assert!(error.contains("limited to 64"), "{error}");
+
+        let impossible = vec![(
+            Word::new("salet").unwrap(),
+            Pattern::from_str("GGGGY").unwrap(),
+        )];
+        assert!(min_guaranteed(&solver, &impossible, DEFAULT_EXACT_LIMIT).is_err());
+    }
+
+    #[test]
+    fn single_candidate_takes_one_guess() {
+        let all_words = words_from_slice(&["crane", "slate", "irate"]);
+        let answer_words = words_from_slice(&["irate"]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        let result = min_guaranteed(&solver, &[], DEFAULT_EXACT_LIMIT).unwrap();
+        assert_eq!(result.guess, "irate");
+        assert!(result.is_candidate);
+        assert_eq!((result.worst, result.at_worst), (1, 1));
+    }
+}
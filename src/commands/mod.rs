@@ -2,12 +2,50 @@
 
 pub mod analyze;
 pub mod benchmark;
+pub mod coach;
+pub mod diff;
+pub mod filter;
+pub mod first_guess;
+pub mod hard_opening;
+pub mod hint;
+pub mod min_guaranteed;
+pub mod pattern_stats;
+pub mod reconstruct;
 pub mod simple;
 pub mod solve;
 pub mod test_all;
+pub mod tournament;
+pub mod traps;
+pub mod verify;
+pub mod worst_case;
 
-pub use analyze::{AnalysisResult, analyze_word};
-pub use benchmark::{BenchmarkResult, run_benchmark};
-pub use simple::run_simple;
-pub use solve::{SolveConfig, SolveResult, solve_word};
-pub use test_all::{TestAllStatistics, print_test_all_statistics, run_test_all};
+pub use analyze::{
+    AnalysisResult, CandidateSet, EntropyBreakdown, OPENER_PERCENTILES, OpenerEntropy,
+    OpenerPercentile, PositionContribution, SkillScore, analyze_word, entropy_breakdown,
+    opener_percentiles, opener_rank, rank_openers, skill_score, write_openers_csv,
+};
+pub use benchmark::{
+    BeamRun, BenchmarkResult, TimingStats, TurnTiming, compare_beam_widths, run_benchmark,
+    run_benchmark_iterations, sample_words,
+};
+pub use coach::{CoachReport, CoachTurn, Grade, coach};
+pub use diff::{Divergence, StrategyDiff, diff_strategies, diff_strategies_all};
+pub use filter::{filter_answers, parse_position_class};
+pub use first_guess::{FirstGuessAnalysis, RemainingBucket, first_guess_analysis};
+pub use hard_opening::{HARD_OPENING_LENGTH, HardOpening, OpeningStep, hard_opening};
+pub use hint::{clue_summary, hint};
+pub use min_guaranteed::{DEFAULT_EXACT_LIMIT, GuaranteedSolve, min_guaranteed};
+pub use pattern_stats::{PatternGroup, PatternStats, pattern_stats};
+pub use reconstruct::{GridRow, Reconstruction, parse_grid, reconstruct};
+pub use simple::{DEFAULT_REVEAL_AT, run_simple};
+pub use solve::{GuessStep, SolveConfig, SolveResult, solve_word, solve_word_with_progress};
+pub use test_all::{
+    HARD_GUESS_THRESHOLD, HardWord, ProgressMode, TestAllStatistics, print_test_all_statistics,
+    run_test_all, select_hard_words, write_test_all_statistics,
+};
+pub use tournament::{HeadToHead, Tournament, tournament};
+pub use traps::{DEFAULT_TRAP_SIZE, TrapGroup, TrapReport, find_traps};
+pub use verify::{VerifyReport, verify_lists};
+pub use worst_case::{
+    GuessPath, SolveTree, WorstCaseReport, worst_case, worst_case_from_tree, write_tree_dot,
+};
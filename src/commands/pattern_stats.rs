@@ -0,0 +1,106 @@
+//! Pattern statistics command
+//!
+//! Shows how a guess splits the answers: how many fall into each feedback
+//! pattern it can produce.
+
+use crate::core::{Pattern, Word};
+use crate::solver::entropy::{group_by_pattern, shannon_entropy};
+
+/// Answers that produce one feedback pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatternGroup {
+    pub pattern: Pattern,
+    pub count: usize,
+}
+
+/// Partition of the answers by the feedback a guess produces
+#[derive(Debug, Clone)]
+pub struct PatternStats {
+    pub guess: String,
+    /// Answers the guess was scored against
+    pub total: usize,
+    pub entropy: f64,
+    /// Every realized pattern, largest group first; equal groups follow
+    /// pattern order (more greens, then more yellows, first)
+    pub groups: Vec<PatternGroup>,
+}
+
+impl PatternStats {
+    /// The largest group, which is the worst case minimax optimizes
+    #[must_use]
+    pub fn largest(&self) -> Option<PatternGroup> {
+        self.groups.first().copied()
+    }
+}
+
+/// Count the answers behind each pattern `guess` can produce
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The guess is invalid (not 5 letters or contains non-ASCII)
+/// - The guess is not in the provided word list
+pub fn pattern_stats(
+    guess: &str,
+    all_words: &[Word],
+    answers: &[Word],
+) -> Result<PatternStats, String> {
+    let guess_obj = Word::new(guess).map_err(|e| format!("Invalid word: {e}"))?;
+    if !all_words.iter().any(|w| w.text() == guess_obj.text()) {
+        return Err(format!("Word '{guess}' not in word list"));
+    }
+
+    let answer_refs: Vec<&Word> = answers.iter().collect();
+    let counts = group_by_pattern(&guess_obj, &answer_refs);
+    let entropy = shannon_entropy(&counts);
+
+    let mut groups: Vec<PatternGroup> = counts
+        .into_iter()
+        .map(|(pattern, count)| PatternGroup { pattern, count })
+        .collect();
+    groups.sort_by(|a, b| b.count.cmp(&a.count).then(a.pattern.cmp(&b.pattern)));
+
+    Ok(PatternStats {
+        guess: guess_obj.text().to_string(),
+        total: answers.len(),
+        entropy,
+        groups,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::entropy::calculate_metrics;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn groups_partition_the_answers() {
+        let all_words = words_from_slice(ALLOWED);
+        let answers = words_from_slice(&ANSWERS[..300]);
+
+        let stats = pattern_stats("SALET", &all_words, &answers).unwrap();
+
+        assert_eq!(stats.guess, "salet");
+        assert_eq!(stats.groups.iter().map(|g| g.count).sum::<usize>(), 300);
+        assert!(stats.groups.len() <= 243);
+        for pair in stats.groups.windows(2) {
+            assert!(pair[0].count >= pair[1].count);
+        }
+
+        let answer_refs: Vec<&Word> = answers.iter().collect();
+        let metrics = calculate_metrics(&Word::new("salet").unwrap(), &answer_refs);
+        assert_eq!(stats.largest().unwrap().count, metrics.max_partition);
+        assert!((stats.entropy - metrics.entropy).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_unknown_guess() {
+        let all_words = words_from_slice(&["crane", "slate"]);
+        let answers = words_from_slice(&["crane"]);
+
+        assert!(pattern_stats("salet", &all_words, &answers).is_err());
+        assert!(pattern_stats("sal", &all_words, &answers).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Reconstruct command
+//!
+//! Works backwards from a share grid: with the answer known, lists the
+//! guesses that could have produced each row of squares.
+
+use crate::core::{FxHashMap, Pattern, Word};
+
+/// One row of a share grid and the guesses that fit it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridRow {
+    pub pattern: Pattern,
+    /// Guess pool words producing exactly `pattern` against the answer, in
+    /// guess pool order
+    pub guesses: Vec<String>,
+}
+
+/// Possible guesses for every row of a share grid
+#[derive(Debug, Clone)]
+pub struct Reconstruction {
+    pub answer: String,
+    pub rows: Vec<GridRow>,
+}
+
+/// Read the rows of a share grid
+///
+/// Lines before the first row (such as a "Wordle 1,234 4/6" header) and
+/// blank lines are skipped. Rows use the squares or letters accepted by
+/// [`Pattern::from_str`].
+///
+/// # Errors
+///
+/// Returns an error if there are no rows, or a line after the first row is
+/// not a valid row.
+pub fn parse_grid(text: &str) -> Result<Vec<Pattern>, String> {
+    let mut rows = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match Pattern::from_str(line) {
+            Some(pattern) => rows.push(pattern),
+            None if rows.is_empty() => {}
+            None => return Err(format!("Line {}: '{line}' is not a grid row", i + 1)),
+        }
+    }
+
+    if rows.is_empty() {
+        return Err("No grid rows found".to_string());
+    }
+    Ok(rows)
+}
+
+/// List the guesses that could have produced each row of `grid` for `answer`
+///
+/// Every guess pool word is scored against the answer once and grouped by
+/// pattern, so each row is a lookup. The all-green row can only be the
+/// answer itself.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The answer is invalid (not 5 letters or contains non-ASCII)
+/// - The grid is empty, or an all-green row comes before the last row
+pub fn reconstruct(
+    answer: &str,
+    grid: &[Pattern],
+    all_words: &[Word],
+) -> Result<Reconstruction, String> {
+    let answer = Word::new(answer).map_err(|e| format!("Invalid answer: {e}"))?;
+    if grid.is_empty() {
+        return Err("The grid has no rows".to_string());
+    }
+    if let Some(row) = grid[..grid.len() - 1].iter().position(|p| p.is_perfect()) {
+        return Err(format!(
+            "Row {} is all green, but the grid continues after it",
+            row + 1
+        ));
+    }
+
+    let mut by_pattern: FxHashMap<Pattern, Vec<String>> = FxHashMap::default();
+    for guess in all_words {
+        by_pattern
+            .entry(Pattern::calculate(guess, &answer))
+            .or_default()
+            .push(guess.text().to_string());
+    }
+
+    let rows = grid
+        .iter()
+        .map(|&pattern| GridRow {
+            pattern,
+            guesses: if pattern.is_perfect() {
+                vec![answer.text().to_string()]
+            } else {
+                by_pattern.get(&pattern).cloned().unwrap_or_default()
+            },
+        })
+        .collect();
+
+    Ok(Reconstruction {
+        answer: answer.text().to_string(),
+        rows,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::ALLOWED;
+    use crate::wordlists::loader::words_from_slice;
+
+    #[test]
+    fn parse_grid_skips_header() {
+        let grid = "Wordle 1,234 3/6\n\n⬜🟨⬜⬜⬜\n🟩🟩⬜🟩🟨\n🟩🟩🟩🟩🟩\n";
+        let rows = parse_grid(grid).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], Pattern::from_str("-Y---").unwrap());
+        assert!(rows[2].is_perfect());
+
+        assert!(parse_grid("Wordle 1,234 X/6\n").is_err());
+        assert!(parse_grid("⬜🟨⬜⬜⬜\nnot a row\n").is_err());
+    }
+
+    #[test]
+    fn rows_list_matching_guesses() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer = Word::new("turbo").unwrap();
+        let grid = [
+            Pattern::calculate(&Word::new("salet").unwrap(), &answer),
+            Pattern::calculate(&Word::new("court").unwrap(), &answer),
+            Pattern::PERFECT,
+        ];
+
+        let result = reconstruct("TURBO", &grid, &all_words).unwrap();
+
+        assert_eq!(result.answer, "turbo");
+        assert!(result.rows[0].guesses.contains(&"salet".to_string()));
+        assert!(result.rows[1].guesses.contains(&"court".to_string()));
+        for row in &result.rows {
+            for guess in &row.guesses {
+                let guess = Word::new(guess.as_str()).unwrap();
+                assert_eq!(Pattern::calculate(&guess, &answer), row.pattern);
+            }
+        }
+        assert_eq!(result.rows[2].guesses, ["turbo"]);
+
+        let early_win = [Pattern::PERFECT, grid[0]];
+        assert!(reconstruct("turbo", &early_win, &all_words).is_err());
+    }
+}
@@ -3,18 +3,32 @@
 //! Text-based interactive solver without TUI
 
 use crate::core::{Pattern, Word};
-use crate::solver::entropy::calculate_metrics;
-use crate::solver::{Solver, Strategy};
-use std::io::{self, Write};
+use crate::output::formatters::{
+    duplicate_letter_note, endgame_risk_note, format_surprise, likely_answer_note, word_label,
+};
+use crate::solver::entropy::{GuessMetrics, calculate_metrics};
+use crate::solver::{GameStatus, MAX_GUESSES, Solver, Strategy};
+use rustyline::DefaultEditor;
+use rustyline::error::ReadlineError;
+use std::io::{self, IsTerminal, Write};
+
+/// Default candidate count at or below which simple mode lists candidates
+pub const DEFAULT_REVEAL_AT: usize = 10;
+
+/// Fraction of the maximum entropy (log₂ of candidate count) a candidate
+/// needs as a guess to be marked as a strong discriminating guess
+const STRONG_GUESS_EFFICIENCY: f64 = 0.9;
 
 /// Run the simple interactive CLI mode
 ///
+/// Remaining candidates are listed once at most `reveal_at` remain.
+///
 /// # Errors
 ///
 /// Returns an error if there's an I/O error reading user input or if the solver
 /// cannot provide a valid guess.
 #[allow(clippy::too_many_lines)] // Interactive game loop requires detailed handling
-pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
+pub fn run_simple<S: Strategy>(solver: &Solver<S>, reveal_at: usize) -> Result<(), String> {
     println!("\n╔══════════════════════════════════════════════════════════════╗");
     println!("║              Wordle Solver - Interactive Mode                ║");
     println!("╚══════════════════════════════════════════════════════════════╝\n");
@@ -23,22 +37,29 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
     println!("After each guess, enter the feedback pattern:\n");
     println!("  - Use G/g/🟩 for green (correct position)");
     println!("  - Use Y/y/🟨 for yellow (wrong position)");
-    println!("  - Use -/_/⬜ for gray (not in word)");
+    println!("  - Use -/_/⬜/⬛ for gray (not in word)");
     println!("  - Or type 'win' if you got it right!\n");
     println!("Commands: 'quit' to exit, 'new' for new game, 'undo' to undo last guess\n");
 
+    let mut reader = LineReader::new();
     let mut history: Vec<(Word, Pattern)> = Vec::new();
     let mut turn = 1;
 
     loop {
-        // Get current candidates count
-        let candidates_count = solver.count_candidates(&history);
+        // Filter once per turn: the count, the suggestion and the preview
+        // all use the same candidates
+        let candidates = solver.get_candidates(&history);
+        let candidates_count = candidates.len();
 
         if candidates_count == 0 {
             println!("\n❌ No candidates remain! Your feedback may be incorrect.");
             println!("Type 'undo' to go back, or 'new' to start over.\n");
 
-            match get_user_input("Command")? {
+            let Some(command) = reader.read("Command")? else {
+                println!("\n👋 Thanks for playing!\n");
+                return Ok(());
+            };
+            match command {
                 cmd if cmd == "undo" => {
                     if history.pop().is_some() {
                         turn -= 1;
@@ -59,7 +80,7 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
 
         // Get next guess suggestion
         let guess = solver
-            .next_guess(&history)
+            .next_guess_from(&history, &candidates)
             .ok_or("No valid guesses available")?;
 
         println!("────────────────────────────────────────────────────────────");
@@ -67,10 +88,9 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
         println!("────────────────────────────────────────────────────────────");
 
         // Calculate and display metrics
-        let candidates = solver.get_candidates(&history);
         let metrics = calculate_metrics(guess, &candidates);
 
-        println!("\n📊 Suggested guess: {}", guess.text().to_uppercase());
+        println!("\n📊 Suggested guess: {}", word_label(guess));
         println!("   Entropy:          {:.3} bits", metrics.entropy);
         println!(
             "   Expected info:    {:.1}x reduction",
@@ -84,19 +104,47 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
             "   Worst case:       {} candidates\n",
             metrics.max_partition
         );
+        if let Some(note) = duplicate_letter_note(guess) {
+            println!("💡 {note}\n");
+        }
+        if let Some(risk) = solver.endgame_risk(&history, MAX_GUESSES) {
+            let marker = if risk.guaranteed { "✓" } else { "⚠️" };
+            println!("{marker} {}\n", endgame_risk_note(&risk));
+        }
 
-        // Show some candidates if count is small
-        if candidates_count <= 10 {
-            println!("Remaining candidates:");
-            for candidate in candidates.iter().take(10) {
-                println!("  • {}", candidate.text().to_uppercase());
+        // Show candidates if count is small, most informative guesses first
+        if candidates_count <= reveal_at {
+            println!("Remaining candidates (★ = also a strong discriminating guess):");
+            let max_entropy = (candidates_count as f64).log2();
+            let probabilities = solver.candidate_probabilities(&candidates);
+            for (candidate, metrics) in rank_candidates(&candidates) {
+                let strong = candidates_count > 1
+                    && metrics.entropy >= STRONG_GUESS_EFFICIENCY * max_entropy;
+                let probability = probabilities
+                    .iter()
+                    .find(|(w, _)| *w == candidate)
+                    .map_or(0.0, |&(_, p)| p);
+                println!(
+                    "  {} {} ({:.2} bits as a guess, {:.0}% likely)",
+                    if strong { "★" } else { "•" },
+                    word_label(candidate),
+                    metrics.entropy,
+                    probability * 100.0
+                );
+            }
+            if let Some(note) = likely_answer_note(&probabilities) {
+                println!("{note}");
             }
             println!();
         }
 
         // Get feedback
         let feedback = loop {
-            let input = get_user_input("Enter feedback (G/Y/-, 'win', or command)")?.to_lowercase();
+            let Some(input) = reader.read("Enter feedback (G/Y/-, 'win', or command)")? else {
+                println!("\n👋 Thanks for playing!\n");
+                return Ok(());
+            };
+            let input = input.to_lowercase();
 
             match input.as_str() {
                 "quit" | "q" | "exit" => {
@@ -134,8 +182,15 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
             // Add to history
             history.push((guess.clone(), pattern));
 
-            // Check if solved
-            if pattern.is_perfect() {
+            let remaining = solver.count_candidates(&history);
+            let status = GameStatus::new(&history, MAX_GUESSES, || remaining);
+            if !matches!(status, GameStatus::Won { .. })
+                && let Some(surprise) = format_surprise(candidates_count, remaining)
+            {
+                println!("   {surprise}\n");
+            }
+
+            if let GameStatus::Won { guesses } = status {
                 use colored::Colorize;
 
                 // Celebration banner
@@ -149,7 +204,7 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
                 println!("{}", "═".repeat(70).bright_cyan());
 
                 // Victory stats
-                let performance = match turn {
+                let performance = match guesses {
                     1 => ("🏆 Perfect!", "Incredible hole-in-one!"),
                     2 => ("⭐ Excellent!", "Outstanding performance!"),
                     3 => ("💫 Great!", "Very well played!"),
@@ -162,8 +217,8 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
                 println!("  {}", performance.1.bright_white());
                 println!(
                     "\n  Solution found in {} {}",
-                    turn.to_string().bright_cyan().bold(),
-                    if turn == 1 { "guess" } else { "guesses" }
+                    guesses.to_string().bright_cyan().bold(),
+                    if guesses == 1 { "guess" } else { "guesses" }
                 );
 
                 // Show guess history with emojis
@@ -173,7 +228,7 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
                     println!(
                         "    {}. {} {}",
                         (i + 1).to_string().bright_black(),
-                        word.text().to_uppercase().bright_white().bold(),
+                        word_label(word).bright_white().bold(),
                         pattern_to_emoji(*pat)
                     );
                 }
@@ -181,7 +236,9 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
                 println!("\n{}", "═".repeat(70).bright_cyan());
                 println!();
 
-                match get_user_input("Play again? (yes/no)")?
+                match reader
+                    .read("Play again? (yes/no)")?
+                    .unwrap_or_default()
                     .to_lowercase()
                     .as_str()
                 {
@@ -202,15 +259,93 @@ pub fn run_simple<S: Strategy>(solver: &Solver<S>) -> Result<(), String> {
     }
 }
 
-/// Get user input with a prompt
-fn get_user_input(prompt: &str) -> Result<String, String> {
-    print!("{prompt}: ");
-    io::stdout().flush().map_err(|e| e.to_string())?;
+/// Rank candidates by their entropy as the next guess, highest first
+///
+/// Each candidate is scored against the full candidate set, so cost is
+/// quadratic in the number of candidates.
+#[must_use]
+pub fn rank_candidates<'a>(candidates: &[&'a Word]) -> Vec<(&'a Word, GuessMetrics)> {
+    let mut ranked: Vec<(&'a Word, GuessMetrics)> = candidates
+        .iter()
+        .map(|&candidate| (candidate, calculate_metrics(candidate, candidates)))
+        .collect();
+    ranked.sort_by(|(_, m1), (_, m2)| m2.entropy.total_cmp(&m1.entropy));
+    ranked
+}
 
-    let mut input = String::new();
-    io::stdin()
-        .read_line(&mut input)
-        .map_err(|e| e.to_string())?;
+/// Prompted line input for simple mode
+///
+/// On a terminal, lines can be edited and earlier entries recalled with the
+/// arrow keys; when stdin is piped it falls back to plain line reads, so
+/// scripted input keeps working.
+enum LineReader {
+    Editor(Box<DefaultEditor>),
+    Plain,
+}
 
-    Ok(input.trim().to_string())
+impl LineReader {
+    fn new() -> Self {
+        if io::stdin().is_terminal() {
+            DefaultEditor::new().map_or(Self::Plain, |editor| Self::Editor(Box::new(editor)))
+        } else {
+            Self::Plain
+        }
+    }
+
+    /// Read one trimmed line, or None at end of input (Ctrl-D) or on Ctrl-C
+    fn read(&mut self, prompt: &str) -> Result<Option<String>, String> {
+        match self {
+            Self::Editor(editor) => match editor.readline(&format!("{prompt}: ")) {
+                Ok(line) => {
+                    let line = line.trim().to_string();
+                    if !line.is_empty() {
+                        // Failing to record history only loses recall
+                        let _ = editor.add_history_entry(line.as_str());
+                    }
+                    Ok(Some(line))
+                }
+                Err(ReadlineError::Eof | ReadlineError::Interrupted) => Ok(None),
+                Err(e) => Err(e.to_string()),
+            },
+            Self::Plain => {
+                print!("{prompt}: ");
+                io::stdout().flush().map_err(|e| e.to_string())?;
+
+                let mut input = String::new();
+                let read = io::stdin()
+                    .read_line(&mut input)
+                    .map_err(|e| e.to_string())?;
+
+                Ok((read > 0).then(|| input.trim().to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rank_candidates_highest_entropy_first() {
+        let words = [
+            Word::new("grate").unwrap(),
+            Word::new("crate").unwrap(),
+            Word::new("irate").unwrap(),
+            Word::new("trace").unwrap(),
+        ];
+        let candidates: Vec<&Word> = words.iter().collect();
+
+        let ranked = rank_candidates(&candidates);
+
+        assert_eq!(ranked.len(), candidates.len());
+        for pair in ranked.windows(2) {
+            assert!(pair[0].1.entropy >= pair[1].1.entropy);
+        }
+    }
+
+    #[test]
+    fn rank_candidates_empty() {
+        assert!(rank_candidates(&[]).is_empty());
+    }
 }
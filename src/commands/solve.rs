@@ -3,13 +3,17 @@
 //! Solves a specific target word and returns the solution path.
 
 use crate::core::{Pattern, Word};
-use crate::solver::entropy::calculate_entropy;
-use crate::solver::{Solver, Strategy};
+use crate::output::formatters::word_label;
+use crate::solver::entropy::{GuessMetrics, calculate_entropy, observed_information};
+use crate::solver::{EndgameRisk, MAX_GUESSES, Solver, Strategy, expected_guesses, simulate_game};
+use std::time::{Duration, Instant};
 
 /// Configuration for solving a word
 pub struct SolveConfig {
     pub target: String,
     pub max_guesses: usize,
+    /// Number of runner-up guesses to record per step (0 = off)
+    pub alternatives: usize,
 }
 
 impl SolveConfig {
@@ -17,7 +21,8 @@ impl SolveConfig {
     pub const fn new(target: String) -> Self {
         Self {
             target,
-            max_guesses: 6,
+            max_guesses: MAX_GUESSES,
+            alternatives: 0,
         }
     }
 }
@@ -27,6 +32,63 @@ pub struct SolveResult {
     pub success: bool,
     pub guesses: Vec<GuessStep>,
     pub target: String,
+    pub max_guesses: usize,
+    /// Time the solver took to play the game, excluding per-step analysis
+    pub duration: Duration,
+    /// Candidates still consistent with the final history, sorted; empty on success
+    pub remaining: Vec<String>,
+}
+
+impl SolveResult {
+    /// Total information actually gained across all guesses (in bits)
+    #[must_use]
+    pub fn information_gained(&self) -> f64 {
+        self.guesses.iter().map(GuessStep::information_gained).sum()
+    }
+
+    /// Format the result as a Wordle-style share grid
+    ///
+    /// A header like "`wordle_solver` 4/6" (or "X/6" on failure) followed by one
+    /// line of 🟩🟨⬜ squares per guess.
+    #[must_use]
+    pub fn share_grid(&self) -> String {
+        let score = if self.success {
+            self.guesses.len().to_string()
+        } else {
+            "X".to_string()
+        };
+
+        let mut grid = format!("wordle_solver {score}/{}\n", self.max_guesses);
+        for step in &self.guesses {
+            grid.push('\n');
+            grid.push_str(&step.pattern.to_emoji());
+        }
+        grid
+    }
+
+    /// Format the result as one grep-friendly line
+    ///
+    /// The format is stable for scripts: five space-separated fields,
+    ///
+    /// ```text
+    /// TURBO 4 ✓ salet,court,burnt,turbo 0.8ms
+    /// ```
+    ///
+    /// the target in uppercase, the number of guesses made, `✓` if solved or
+    /// `✗` if not, the guesses in lowercase joined by commas, and the solve
+    /// time in milliseconds with one decimal.
+    #[must_use]
+    pub fn summary_line(&self) -> String {
+        let guesses: Vec<&str> = self.guesses.iter().map(|g| g.word.as_str()).collect();
+        format!(
+            "{} {} {} {} {:.1}ms",
+            self.target.to_uppercase(),
+            self.guesses.len(),
+            if self.success { '✓' } else { '✗' },
+            guesses.join(","),
+            self.duration.as_secs_f64() * 1000.0
+        )
+    }
 }
 
 /// A single guess step in the solution
@@ -37,6 +99,27 @@ pub struct GuessStep {
     pub candidates_after: usize,
     pub entropy: Option<f64>,
     pub expected_remaining: Option<f64>,
+    /// Runner-up guesses by the strategy's criterion, best first
+    pub alternatives: Vec<(String, GuessMetrics)>,
+    /// Set when more candidates remained than guesses before this one
+    pub endgame_risk: Option<EndgameRisk>,
+}
+
+impl GuessStep {
+    /// Information actually gained by this guess's observed pattern (in bits)
+    #[must_use]
+    pub fn information_gained(&self) -> f64 {
+        observed_information(self.candidates_before, self.candidates_after)
+    }
+
+    /// Estimated guesses still needed before this one was played, including it
+    ///
+    /// A rough estimate from the candidate count (see [`expected_guesses`]),
+    /// exactly 1 when a single candidate remained.
+    #[must_use]
+    pub fn expected_guesses(&self) -> f64 {
+        expected_guesses(self.candidates_before)
+    }
 }
 
 /// Solve a specific word using the given solver and strategy
@@ -45,30 +128,50 @@ pub struct GuessStep {
 ///
 /// Returns an error if:
 /// - The target word is invalid (not 5 letters or contains non-ASCII)
+/// - The target is missing from the answer list or the guess pool
 /// - The solver cannot provide a valid guess
 /// - Maximum guess limit is reached without finding the solution
 pub fn solve_word<S: Strategy>(
     config: SolveConfig,
     solver: &Solver<S>,
+) -> Result<SolveResult, String> {
+    solve_word_with_progress(config, solver, |_| {})
+}
+
+/// Solve a specific word, reporting each step as soon as it is ready
+///
+/// Like [`solve_word`], but `on_step` is called with every [`GuessStep`] in
+/// turn order once its analysis is done, so a frontend can render the solve
+/// progressively. The steps are still collected into the returned result.
+///
+/// # Errors
+///
+/// Returns the same errors as [`solve_word`]; `on_step` is not called when
+/// the target is rejected up front.
+pub fn solve_word_with_progress<S: Strategy>(
+    config: SolveConfig,
+    solver: &Solver<S>,
+    mut on_step: impl FnMut(&GuessStep),
 ) -> Result<SolveResult, String> {
     // Find target in answer words
     let target_word = Word::new(&config.target).map_err(|e| format!("Invalid target word: {e}"))?;
+    check_target_listed(&target_word, solver)?;
 
-    // Build history as we go
-    let mut history: Vec<(Word, Pattern)> = Vec::new();
-    let mut guesses: Vec<GuessStep> = Vec::new();
-
-    for _ in 0..config.max_guesses {
-        let candidates_before = solver.count_candidates(&history);
+    let start = Instant::now();
+    let record = simulate_game(solver, &target_word, config.max_guesses, None);
+    let duration = start.elapsed();
+    if !record.success && record.num_guesses() < config.max_guesses {
+        return Err("No candidates remaining".to_string());
+    }
 
-        // Get next guess
-        let guess = solver
-            .next_guess(&history)
-            .ok_or_else(|| "No candidates remaining".to_string())?;
+    let mut guesses: Vec<GuessStep> = Vec::with_capacity(record.num_guesses());
+    for (turn, (guess, pattern)) in record.history.iter().enumerate() {
+        let history = &record.history[..turn];
+        let candidates_before = solver.count_candidates(history);
 
         // Calculate entropy for this guess against remaining candidates (if applicable)
         let (entropy, expected_remaining) = if candidates_before > 1 {
-            let current_candidates = solver.get_candidates(&history);
+            let current_candidates = solver.get_candidates(history);
             let ent = calculate_entropy(guess, &current_candidates);
             let exp_remaining = candidates_before as f64 / ent.exp2();
             (Some(ent), Some(exp_remaining))
@@ -76,41 +179,76 @@ pub fn solve_word<S: Strategy>(
             (None, None)
         };
 
-        // Calculate pattern against target
-        let pattern = Pattern::calculate(guess, &target_word);
-
-        // Add to history
-        history.push((guess.clone(), pattern));
+        // Rank runner-up guesses (skip when the answer is already known)
+        let alternatives = if config.alternatives > 0 && candidates_before > 1 {
+            solver
+                .rank_guesses(history, config.alternatives + 1)
+                .into_iter()
+                .filter(|(word, _)| word.text() != guess.text())
+                .take(config.alternatives)
+                .map(|(word, metrics)| (word.text().to_string(), metrics))
+                .collect()
+        } else {
+            Vec::new()
+        };
 
-        let candidates_after = solver.count_candidates(&history);
+        let candidates_after = solver.count_candidates(&record.history[..=turn]);
 
-        guesses.push(GuessStep {
+        let step = GuessStep {
             word: guess.text().to_string(),
-            pattern,
+            pattern: *pattern,
             candidates_before,
             candidates_after,
             entropy,
             expected_remaining,
-        });
-
-        // Check if solved
-        if pattern.is_perfect() {
-            return Ok(SolveResult {
-                success: true,
-                guesses,
-                target: config.target,
-            });
-        }
+            alternatives,
+            endgame_risk: solver.endgame_risk(history, config.max_guesses),
+        };
+        on_step(&step);
+        guesses.push(step);
     }
 
-    // Failed to solve
+    let remaining = if record.success {
+        Vec::new()
+    } else {
+        let mut words: Vec<String> = solver
+            .get_candidates(&record.history)
+            .iter()
+            .map(|w| w.text().to_string())
+            .collect();
+        words.sort_unstable();
+        words
+    };
+
     Ok(SolveResult {
-        success: false,
+        success: record.success,
         guesses,
         target: config.target,
+        max_guesses: config.max_guesses,
+        duration,
+        remaining,
     })
 }
 
+/// Check the target is one of the solver's answers and can be guessed
+///
+/// Without this a missing target is filtered out by the first guess and the
+/// solve fails with no hint why. The error names the list it's missing from.
+fn check_target_listed<S: Strategy>(target: &Word, solver: &Solver<S>) -> Result<(), String> {
+    let in_answers = solver.answer_words().contains(target);
+    let in_pool = solver.all_words().contains(target);
+    let missing = match (in_answers, in_pool) {
+        (true, true) => return Ok(()),
+        (false, false) => "the answer list or the guess pool",
+        (false, true) => "the current answer list",
+        (true, false) => "the current guess pool",
+    };
+    Err(format!(
+        "{} is valid but not in {missing}; add it with --answers or use a different list",
+        word_label(target)
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,6 +288,42 @@ mod tests {
         for step in &result.guesses {
             assert!(step.candidates_after <= step.candidates_before);
         }
+
+        // The estimate shrinks with the candidates and is exact at one
+        for pair in result.guesses.windows(2) {
+            assert!(pair[1].expected_guesses() <= pair[0].expected_guesses());
+        }
+        let last = result.guesses.last().unwrap();
+        if last.candidates_before == 1 {
+            assert!((last.expected_guesses() - 1.0).abs() < f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn progress_reports_every_step_in_order() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        let mut seen: Vec<(String, usize)> = Vec::new();
+        let result =
+            solve_word_with_progress(SolveConfig::new("abase".to_string()), &solver, |step| {
+                seen.push((step.word.clone(), step.candidates_before));
+            })
+            .unwrap();
+        let recorded: Vec<(String, usize)> = result
+            .guesses
+            .iter()
+            .map(|step| (step.word.clone(), step.candidates_before))
+            .collect();
+        assert_eq!(seen, recorded);
+
+        let mut calls = 0;
+        let error = solve_word_with_progress(SolveConfig::new("xyz".to_string()), &solver, |_| {
+            calls += 1;
+        });
+        assert!(error.is_err());
+        assert_eq!(calls, 0);
     }
 
     #[test]
@@ -166,6 +340,40 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn solve_unlisted_target_names_missing_list() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        let error = |target: &str| solve_word(SolveConfig::new(target.to_string()), &solver).err();
+
+        let neither = error("zzzzz").unwrap();
+        assert!(neither.starts_with("ZZZZZ is valid but not in the answer list or the guess pool"));
+
+        let guess_only = all_words
+            .iter()
+            .find(|w| !answer_words.contains(w))
+            .unwrap();
+        let not_answer = error(guess_only.text()).unwrap();
+        assert!(
+            not_answer.contains("not in the current answer list"),
+            "{not_answer}"
+        );
+
+        let answer_only = answer_words
+            .iter()
+            .find(|w| !all_words.contains(w))
+            .unwrap();
+        let not_guess = error(answer_only.text()).unwrap();
+        assert!(
+            not_guess.contains("not in the current guess pool"),
+            "{not_guess}"
+        );
+
+        assert!(error("abase").is_none());
+    }
+
     #[test]
     fn solve_with_max_guesses_limit() {
         let all_words = words_from_slice(&ALLOWED[..100]);
@@ -181,6 +389,146 @@ mod tests {
         assert!(result.guesses.len() <= 3);
     }
 
+    #[test]
+    fn solve_failure_lists_remaining_candidates() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let mut config = SolveConfig::new("abbey".to_string());
+        config.max_guesses = 1;
+
+        // One guess can't pin down one of 50 answers
+        let failed = solve_word(config, &solver).unwrap();
+        assert!(!failed.success);
+        assert!(failed.remaining.contains(&"abbey".to_string()));
+        assert!(failed.remaining.is_sorted());
+        assert_eq!(failed.remaining.len(), failed.guesses[0].candidates_after);
+
+        let full = solve_word(SolveConfig::new("abbey".to_string()), &solver).unwrap();
+        assert!(full.success);
+        assert!(full.remaining.is_empty());
+    }
+
+    #[test]
+    fn solve_information_gained_sums_steps() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let config = SolveConfig::new("abase".to_string());
+
+        let result = solve_word(config, &solver).unwrap();
+        let summed: f64 = result
+            .guesses
+            .iter()
+            .map(GuessStep::information_gained)
+            .sum();
+
+        assert!((result.information_gained() - summed).abs() < 1e-9);
+        if result.success {
+            // Solving pins the answer down, so all starting uncertainty is resolved
+            let total_bits = (answer_words.len() as f64).log2();
+            assert!((result.information_gained() - total_bits).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn solve_alternatives_off_by_default() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let result = solve_word(SolveConfig::new("abase".to_string()), &solver).unwrap();
+
+        assert!(
+            result
+                .guesses
+                .iter()
+                .all(|step| step.alternatives.is_empty())
+        );
+    }
+
+    #[test]
+    fn solve_records_alternatives() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let mut config = SolveConfig::new("abase".to_string());
+        config.alternatives = 2;
+
+        let result = solve_word(config, &solver).unwrap();
+
+        for step in result.guesses.iter().filter(|s| s.candidates_before > 1) {
+            assert_eq!(step.alternatives.len(), 2);
+            assert!(step.alternatives.iter().all(|(word, _)| *word != step.word));
+        }
+    }
+
+    #[test]
+    fn share_grid_lists_each_guess() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let result = solve_word(SolveConfig::new("abase".to_string()), &solver).unwrap();
+        let grid = result.share_grid();
+        let lines: Vec<&str> = grid.lines().collect();
+
+        assert!(result.success);
+        assert_eq!(
+            lines[0],
+            format!("wordle_solver {}/6", result.guesses.len())
+        );
+        assert_eq!(lines.len(), 2 + result.guesses.len());
+        assert_eq!(lines.last(), Some(&"🟩🟩🟩🟩🟩"));
+    }
+
+    #[test]
+    fn share_grid_marks_failure() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        // One guess can only win if the opener is the answer
+        let opener = solver.first_guess().unwrap();
+        let target = answer_words.iter().find(|&w| w != opener).unwrap();
+        let mut config = SolveConfig::new(target.text().to_string());
+        config.max_guesses = 1;
+
+        let result = solve_word(config, &solver).unwrap();
+        assert!(!result.success);
+        assert!(result.share_grid().starts_with("wordle_solver X/1\n"));
+    }
+
+    #[test]
+    fn summary_line_has_five_fields() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let mut result = solve_word(SolveConfig::new("abase".to_string()), &solver).unwrap();
+        result.duration = Duration::from_micros(812);
+        let line = result.summary_line();
+        let fields: Vec<&str> = line.split(' ').collect();
+
+        let guesses: Vec<&str> = result.guesses.iter().map(|g| g.word.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "ABASE",
+                &result.guesses.len().to_string(),
+                "✓",
+                &guesses.join(","),
+                "0.8ms"
+            ]
+        );
+
+        result.success = false;
+        assert!(result.summary_line().contains(" ✗ "));
+    }
+
     #[test]
     fn solve_perfect_first_guess() {
         let all_words = words_from_slice(&ALLOWED[..100]);
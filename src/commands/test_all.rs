@@ -2,13 +2,82 @@
 //!
 //! Runs the solver against every possible answer word and generates statistics.
 
-use crate::core::{Pattern, Word};
-use crate::solver::{Solver, Strategy};
+use crate::core::Word;
+use crate::output::formatters::progress_bar;
+use crate::solver::{MAX_GUESSES, Solver, Strategy, simulate_game};
 use colored::Colorize;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::ProgressBar;
 use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
+/// How a long run reports its progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// Live progress bar
+    Bar,
+    /// A plain line every 10% of the run, for logs and CI output
+    Lines,
+    /// No progress output
+    Hidden,
+}
+
+impl ProgressMode {
+    /// A bar when stdout and stderr are both terminals, plain lines when
+    /// either is redirected, and nothing if `no_progress` is set
+    #[must_use]
+    pub fn detect(no_progress: bool) -> Self {
+        if no_progress {
+            Self::Hidden
+        } else if std::io::stdout().is_terminal() && std::io::stderr().is_terminal() {
+            Self::Bar
+        } else {
+            Self::Lines
+        }
+    }
+}
+
+/// Progress output for one run, drawn as `mode` asks
+struct Progress {
+    bar: ProgressBar,
+    mode: ProgressMode,
+    total: usize,
+}
+
+impl Progress {
+    fn new(total: usize, mode: ProgressMode) -> Self {
+        let bar = if mode == ProgressMode::Bar {
+            progress_bar(total, "")
+        } else {
+            ProgressBar::hidden()
+        };
+        Self { bar, mode, total }
+    }
+
+    /// Record that `done` items are finished, with `message` summarizing them
+    fn advance(&self, done: usize, message: String) {
+        let step = self.total.div_ceil(10).max(1);
+        if self.mode == ProgressMode::Lines && (done.is_multiple_of(step) || done == self.total) {
+            println!(
+                "  {done}/{} ({}%) | {message}",
+                self.total,
+                done * 100 / self.total
+            );
+        }
+        self.bar.set_message(message);
+        self.bar.inc(1);
+    }
+
+    /// Stop early, leaving `message` in place of the bar
+    fn abandon(&self, message: String) {
+        if self.mode == ProgressMode::Lines {
+            println!("  {message}");
+        }
+        self.bar.abandon_with_message(message);
+    }
+}
+
 /// Result from testing a single word
 #[derive(Debug, Clone)]
 pub struct WordTestResult {
@@ -23,8 +92,12 @@ pub struct WordTestResult {
 #[derive(Debug)]
 pub struct TestAllStatistics {
     pub total_words: usize,
+    /// Words the run set out to test; more than `total_words` if interrupted
+    pub planned_words: usize,
     pub solved: usize,
     pub failed: usize,
+    /// Words not solved within `MAX_GUESSES`, in test order
+    pub failed_words: Vec<String>,
     pub guess_distribution: HashMap<usize, usize>,
     pub total_time: Duration,
     pub average_guesses: f64,
@@ -35,20 +108,121 @@ pub struct TestAllStatistics {
     pub first_guess_used: HashMap<String, usize>,
 }
 
+impl TestAllStatistics {
+    /// Aggregate statistics over the words tested so far
+    ///
+    /// `planned_words` is the size of the full run, so a run stopped early
+    /// still reports how much of it completed.
+    #[must_use]
+    pub fn from_results(
+        results: &[WordTestResult],
+        planned_words: usize,
+        total_time: Duration,
+    ) -> Self {
+        let mut guess_distribution: HashMap<usize, usize> = HashMap::new();
+        let mut first_guess_used: HashMap<String, usize> = HashMap::new();
+        for result in results {
+            if result.success {
+                *guess_distribution.entry(result.num_guesses).or_insert(0) += 1;
+            }
+            if let Some(first) = result.guesses.first() {
+                *first_guess_used.entry(first.clone()).or_insert(0) += 1;
+            }
+        }
+
+        let failed_words: Vec<String> = results
+            .iter()
+            .filter(|r| !r.success)
+            .map(|r| r.word.clone())
+            .collect();
+        let solved_count = results.len() - failed_words.len();
+
+        let total_guesses: usize = results
+            .iter()
+            .filter(|r| r.success)
+            .map(|r| r.num_guesses)
+            .sum();
+        let average_guesses = if solved_count > 0 {
+            total_guesses as f64 / solved_count as f64
+        } else {
+            0.0
+        };
+
+        let max_guesses = results
+            .iter()
+            .filter(|r| r.success)
+            .map(|r| r.num_guesses)
+            .max()
+            .unwrap_or(0);
+
+        let min_guesses = results
+            .iter()
+            .filter(|r| r.success)
+            .map(|r| r.num_guesses)
+            .min()
+            .unwrap_or(0);
+
+        let best_word = results
+            .iter()
+            .filter(|r| r.success)
+            .min_by_key(|r| r.num_guesses)
+            .map(|r| (r.word.clone(), r.num_guesses));
+
+        let mut worst_words: Vec<(String, usize)> = results
+            .iter()
+            .filter(|r| r.success)
+            .filter(|r| r.num_guesses >= 5)
+            .map(|r| (r.word.clone(), r.num_guesses))
+            .collect();
+        worst_words.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+        worst_words.truncate(10);
+
+        Self {
+            total_words: results.len(),
+            planned_words,
+            solved: solved_count,
+            failed: failed_words.len(),
+            failed_words,
+            guess_distribution,
+            total_time,
+            average_guesses,
+            max_guesses,
+            min_guesses,
+            best_word,
+            worst_words,
+            first_guess_used,
+        }
+    }
+
+    /// Whether the run stopped before testing every planned word
+    #[must_use]
+    pub const fn is_partial(&self) -> bool {
+        self.total_words < self.planned_words
+    }
+}
+
 /// Run solver on all answer words (or a limited subset)
 ///
 /// If `forced_first` is provided, it will be used as the first guess instead of
 /// letting the solver choose.
 ///
+/// `stop` is checked before each word; once it is set the run ends and the
+/// statistics cover only the words completed so far (see
+/// `TestAllStatistics::is_partial`).
+///
+/// `progress` only changes how progress is drawn; the statistics are the
+/// same either way.
+///
 /// # Panics
 ///
 /// May panic if the solver encounters an impossible state (e.g., no valid guesses remaining).
-#[allow(clippy::too_many_lines)] // Complex test orchestration
 pub fn run_test_all<S: Strategy>(
     wordle_solver: &Solver<S>,
     answer_words: &[Word],
     limit: Option<usize>,
     forced_first: Option<&Word>,
+    stop: &AtomicBool,
+    progress: ProgressMode,
 ) -> TestAllStatistics {
     let test_words: Vec<&Word> = answer_words
         .iter()
@@ -57,159 +231,154 @@ pub fn run_test_all<S: Strategy>(
 
     println!("🎯 Testing {} words...", test_words.len());
 
-    // Progress bar
-    let pb = ProgressBar::new(test_words.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) | {msg}")
-            .unwrap()
-            .progress_chars("█▓▒░"),
-    );
-
+    let progress = Progress::new(test_words.len(), progress);
     let mut results = Vec::new();
-    let mut guess_distribution: HashMap<usize, usize> = HashMap::new();
-    let mut first_guess_used: HashMap<String, usize> = HashMap::new();
+    let mut total_guesses = 0;
 
     let total_start = Instant::now();
 
-    for (idx, &answer_word) in test_words.iter().enumerate() {
-        let word_start = Instant::now();
-        let mut history: Vec<(Word, Pattern)> = Vec::new();
-        let mut guesses = Vec::new();
-        let mut success = false;
-
-        for turn in 1..=6 {
-            // Get next guess
-            let guess = if let (1, Some(forced)) = (turn, forced_first) {
-                // Use forced first word on first turn
-                forced
-            } else {
-                // Otherwise use solver
-                match wordle_solver.next_guess(&history) {
-                    Some(g) => g,
-                    None => break, // No candidates remaining
-                }
-            };
-
-            let guess_text = guess.text().to_string();
-            guesses.push(guess_text.clone());
-
-            // Track first guess
-            if guesses.len() == 1 {
-                *first_guess_used.entry(guess_text.clone()).or_insert(0) += 1;
-            }
-
-            // Check if correct
-            if guess.text() == answer_word.text() {
-                success = true;
-                break;
-            }
-
-            // Calculate pattern
-            let pattern = Pattern::calculate(guess, answer_word);
-
-            // Add to history
-            history.push((guess.clone(), pattern));
+    for &answer_word in &test_words {
+        if stop.load(Ordering::Relaxed) {
+            break;
         }
 
-        let num_guesses = guesses.len();
+        let word_start = Instant::now();
+        let record = simulate_game(wordle_solver, answer_word, MAX_GUESSES, forced_first);
         let duration = word_start.elapsed();
 
         results.push(WordTestResult {
             word: answer_word.text().to_string(),
-            guesses,
-            num_guesses,
-            success,
+            guesses: record.guesses().map(|g| g.text().to_string()).collect(),
+            num_guesses: record.num_guesses(),
+            success: record.success,
             duration,
         });
 
-        if success {
-            *guess_distribution.entry(num_guesses).or_insert(0) += 1;
-        }
-
-        // Update progress
-        if idx % 10 == 0 && !results.is_empty() {
-            let avg =
-                results.iter().map(|r| r.num_guesses).sum::<usize>() as f64 / results.len() as f64;
-            pb.set_message(format!("Avg: {avg:.2}"));
-        }
-        pb.inc(1);
+        total_guesses += record.num_guesses();
+        let avg = total_guesses as f64 / results.len() as f64;
+        progress.advance(results.len(), format!("Avg: {avg:.2}"));
     }
 
-    pb.finish_with_message("Complete!");
-
-    let total_time = total_start.elapsed();
-
-    // Calculate statistics
-    let solved_count = results.iter().filter(|r| r.success).count();
-    let failed_count = results.len() - solved_count;
-
-    let total_guesses: usize = results
-        .iter()
-        .filter(|r| r.success)
-        .map(|r| r.num_guesses)
-        .sum();
-    let average_guesses = if solved_count > 0 {
-        total_guesses as f64 / solved_count as f64
+    if results.len() < test_words.len() {
+        progress.abandon(format!(
+            "Interrupted after {} of {} words",
+            results.len(),
+            test_words.len()
+        ));
     } else {
-        0.0
-    };
+        progress.bar.finish_with_message("Complete!");
+    }
 
-    let max_guesses = results
-        .iter()
-        .filter(|r| r.success)
-        .map(|r| r.num_guesses)
-        .max()
-        .unwrap_or(0);
+    TestAllStatistics::from_results(&results, test_words.len(), total_start.elapsed())
+}
 
-    let min_guesses = results
-        .iter()
-        .filter(|r| r.success)
-        .map(|r| r.num_guesses)
-        .min()
-        .unwrap_or(0);
+/// Guess count at or above which `--only-hard` treats an answer as hard
+pub const HARD_GUESS_THRESHOLD: usize = 5;
 
-    let best_word = results
-        .iter()
-        .filter(|r| r.success)
-        .min_by_key(|r| r.num_guesses)
-        .map(|r| (r.word.clone(), r.num_guesses));
+/// An answer chosen for a focused run, with the reference result that chose it
+#[derive(Debug, Clone)]
+pub struct HardWord {
+    pub word: Word,
+    /// Guesses the reference strategy used
+    pub guesses: usize,
+    /// False if the reference strategy failed to solve it
+    pub success: bool,
+}
 
-    let mut worst_words: Vec<(String, usize)> = results
-        .iter()
-        .filter(|r| r.success)
-        .filter(|r| r.num_guesses >= 5)
-        .map(|r| (r.word.clone(), r.num_guesses))
-        .collect();
-    worst_words.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
-    worst_words.truncate(10);
-
-    TestAllStatistics {
-        total_words: results.len(),
-        solved: solved_count,
-        failed: failed_count,
-        guess_distribution,
-        total_time,
-        average_guesses,
-        max_guesses,
-        min_guesses,
-        best_word,
-        worst_words,
-        first_guess_used,
+/// Select the answers a reference solver needs at least `min_guesses` for
+///
+/// Unsolved answers always count as hard. Results keep the order of
+/// `answer_words`, so a deterministic reference strategy (e.g. a seeded
+/// adaptive strategy) always selects the same set. Checks `stop` before each
+/// word like [`run_test_all`]; an interrupted selection is incomplete.
+pub fn select_hard_words<S: Strategy>(
+    reference: &Solver<S>,
+    answer_words: &[Word],
+    min_guesses: usize,
+    stop: &AtomicBool,
+    progress: ProgressMode,
+) -> Vec<HardWord> {
+    let progress = Progress::new(answer_words.len(), progress);
+
+    let mut hard = Vec::new();
+    for (idx, answer) in answer_words.iter().enumerate() {
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let record = simulate_game(reference, answer, MAX_GUESSES, None);
+        if !record.success || record.num_guesses() >= min_guesses {
+            hard.push(HardWord {
+                word: answer.clone(),
+                guesses: record.num_guesses(),
+                success: record.success,
+            });
+        }
+        progress.advance(idx + 1, format!("{} hard", hard.len()));
     }
+    progress.bar.finish_and_clear();
+
+    hard
 }
 
 /// Print test-all statistics with beautiful formatting
-#[allow(clippy::too_many_lines)] // Comprehensive output formatting
+///
+/// # Panics
+///
+/// Panics if writing to stdout fails, like `println!`.
 pub fn print_test_all_statistics(stats: &TestAllStatistics) {
-    println!("\n{}", "═".repeat(70));
-    println!(" Test Results ");
-    println!("{}", "═".repeat(70));
+    write_test_all_statistics(&mut io::stdout().lock(), stats).expect("failed printing to stdout");
+}
+
+/// Write the statistics [`print_test_all_statistics`] prints
+///
+/// A run with no finished words, such as one interrupted before the first,
+/// has nothing to average, so it only gets a note saying so.
+///
+/// # Errors
+///
+/// Returns any error from the underlying writer.
+#[allow(clippy::too_many_lines)] // Comprehensive output formatting
+pub fn write_test_all_statistics<W: Write>(
+    out: &mut W,
+    stats: &TestAllStatistics,
+) -> io::Result<()> {
+    if stats.total_words == 0 {
+        if stats.is_partial() {
+            writeln!(
+                out,
+                "\nInterrupted before any word was tested ({} planned).",
+                stats.planned_words
+            )?;
+        } else {
+            writeln!(out, "\nNo words to test.")?;
+        }
+        return Ok(());
+    }
+
+    writeln!(out, "\n{}", "═".repeat(70))?;
+    if stats.is_partial() {
+        writeln!(out, " Partial Test Results (interrupted) ")?;
+    } else {
+        writeln!(out, " Test Results ")?;
+    }
+    writeln!(out, "{}", "═".repeat(70))?;
 
     // Overall performance
-    println!("\n📊 {}", "Overall Performance".bright_cyan().bold());
-    println!("  Total words tested:  {}", stats.total_words);
-    println!(
+    writeln!(out, "\n📊 {}", "Overall Performance".bright_cyan().bold())?;
+    if stats.is_partial() {
+        writeln!(
+            out,
+            "  Total words tested:  {} of {} {}",
+            stats.total_words,
+            stats.planned_words,
+            "(partial)".yellow()
+        )?;
+    } else {
+        writeln!(out, "  Total words tested:  {}", stats.total_words)?;
+    }
+    writeln!(
+        out,
         "  Successfully solved: {} {}",
         stats.solved,
         format!(
@@ -217,9 +386,10 @@ pub fn print_test_all_statistics(stats: &TestAllStatistics) {
             stats.solved as f64 / stats.total_words as f64 * 100.0
         )
         .green()
-    );
+    )?;
     if stats.failed > 0 {
-        println!(
+        writeln!(
+            out,
             "  Failed to solve:     {} {}",
             stats.failed,
             format!(
@@ -227,47 +397,63 @@ pub fn print_test_all_statistics(stats: &TestAllStatistics) {
                 stats.failed as f64 / stats.total_words as f64 * 100.0
             )
             .red()
-        );
+        )?;
+        // The default strategy solves every standard answer, so any failure
+        // is worth seeing by name
+        let failed: Vec<String> = stats
+            .failed_words
+            .iter()
+            .map(|w| w.to_uppercase())
+            .collect();
+        writeln!(
+            out,
+            "  Failed words:        {}",
+            failed.join(", ").red().bold()
+        )?;
     }
-    println!(
+    writeln!(
+        out,
         "  Average guesses:     {}",
         format!("{:.3}", stats.average_guesses)
             .bright_yellow()
             .bold()
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  Total time:          {:.2}s",
         stats.total_time.as_secs_f64()
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  Time per word:       {:.1}ms",
         stats.total_time.as_millis() as f64 / stats.total_words as f64
-    );
+    )?;
 
     // Guess distribution
-    println!("\n📈 {}", "Guess Distribution".bright_cyan().bold());
+    writeln!(out, "\n📈 {}", "Guess Distribution".bright_cyan().bold())?;
     let max_count = *stats.guess_distribution.values().max().unwrap_or(&1);
     for guesses in 1..=6 {
         let count = stats.guess_distribution.get(&guesses).unwrap_or(&0);
         if stats.solved > 0 {
             let percentage = *count as f64 / stats.solved as f64 * 100.0;
-            let bar_len = if max_count > 0 {
-                (*count * 40 / max_count).max(usize::from(*count > 0))
-            } else {
-                0
-            };
+            let bar_len = (*count * 40)
+                .checked_div(max_count)
+                .map_or(0, |len| len.max(usize::from(*count > 0)));
             let bar = format!(
                 "{}{}",
                 "█".repeat(bar_len).green(),
                 "░".repeat(40_usize.saturating_sub(bar_len)).bright_black()
             );
 
-            println!("  {guesses} guesses: {bar} {count:4} ({percentage:5.1}%)");
+            writeln!(
+                out,
+                "  {guesses} guesses: {bar} {count:4} ({percentage:5.1}%)"
+            )?;
         }
     }
 
     // Information theory metrics
-    println!("\n🧮 Information Theory Metrics");
+    writeln!(out, "\n🧮 Information Theory Metrics")?;
     let total_bits = (stats.total_words as f64).log2();
     let bits_per_guess = if stats.average_guesses > 0.0 {
         total_bits / stats.average_guesses
@@ -280,32 +466,43 @@ pub fn print_test_all_statistics(stats: &TestAllStatistics) {
     } else {
         0.0
     };
-    println!("  Total information:   {total_bits:.2} bits");
-    println!("  Bits per guess:      {bits_per_guess:.2} bits");
-    println!(
+    writeln!(out, "  Total information:   {total_bits:.2} bits")?;
+    writeln!(out, "  Bits per guess:      {bits_per_guess:.2} bits")?;
+    writeln!(
+        out,
         "  Efficiency:          {efficiency:.1}% (vs theoretical max {theoretical_max_bits:.2} bits/guess)"
-    );
+    )?;
 
     // Best and worst cases
     if let Some((word, guesses)) = &stats.best_word {
-        println!("\n✨ {}", "Best Performance".green().bold());
-        println!(
+        writeln!(out, "\n✨ {}", "Best Performance".green().bold())?;
+        writeln!(
+            out,
             "  {} solved in {} guess{}",
             word.to_uppercase().bright_green(),
             guesses,
             if *guesses == 1 { "" } else { "es" }
-        );
+        )?;
     }
 
     if !stats.worst_words.is_empty() {
-        println!("\n😰 {}", "Hardest Words (5-6 guesses)".yellow().bold());
+        writeln!(
+            out,
+            "\n😰 {}",
+            "Hardest Words (5-6 guesses)".yellow().bold()
+        )?;
         for (word, guesses) in stats.worst_words.iter().take(5) {
-            println!("  {} ({} guesses)", word.to_uppercase().yellow(), guesses);
+            writeln!(
+                out,
+                "  {} ({} guesses)",
+                word.to_uppercase().yellow(),
+                guesses
+            )?;
         }
     }
 
     // First guess analysis
-    println!("\n🎯 First Guess Usage");
+    writeln!(out, "\n🎯 First Guess Usage")?;
     let mut first_guesses: Vec<(String, usize)> = stats
         .first_guess_used
         .iter()
@@ -315,23 +512,29 @@ pub fn print_test_all_statistics(stats: &TestAllStatistics) {
 
     for (word, count) in first_guesses.iter().take(5) {
         let percentage = *count as f64 / stats.total_words as f64 * 100.0;
-        println!(
+        writeln!(
+            out,
             "  {}: {} times ({:.1}%)",
             word.to_uppercase(),
             count,
             percentage
-        );
+        )?;
     }
 
     // Theoretical comparison
-    println!("\n📐 {}", "Theoretical Comparison".bright_cyan().bold());
-    println!(
+    writeln!(
+        out,
+        "\n📐 {}",
+        "Theoretical Comparison".bright_cyan().bold()
+    )?;
+    writeln!(
+        out,
         "  Our average:         {} guesses",
         format!("{:.3}", stats.average_guesses)
             .bright_yellow()
             .bold()
-    );
-    println!("  Theoretical optimal: 3.421 guesses (MIT research)");
+    )?;
+    writeln!(out, "  Theoretical optimal: 3.421 guesses (MIT research)")?;
 
     let difference = stats.average_guesses - 3.421;
     let diff_str = format!("{difference:+.3} guesses");
@@ -342,7 +545,7 @@ pub fn print_test_all_statistics(stats: &TestAllStatistics) {
     } else {
         diff_str.red()
     };
-    println!("  Difference:          {colored_diff}");
+    writeln!(out, "  Difference:          {colored_diff}")?;
 
     let performance = 3.421 / stats.average_guesses * 100.0;
     let perf_str = format!("{performance:.1}% of optimal");
@@ -353,5 +556,152 @@ pub fn print_test_all_statistics(stats: &TestAllStatistics) {
     } else {
         perf_str.red()
     };
-    println!("  Performance:         {colored_perf}");
+    writeln!(out, "  Performance:         {colored_perf}")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::EntropyStrategy;
+
+    fn result(word: &str, guesses: &[&str], success: bool) -> WordTestResult {
+        WordTestResult {
+            word: word.to_string(),
+            guesses: guesses.iter().map(|g| (*g).to_string()).collect(),
+            num_guesses: guesses.len(),
+            success,
+            duration: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn from_results_aggregates_partial_run() {
+        let results = [
+            result("irate", &["salet", "irate"], true),
+            result("grate", &["salet", "crate", "frate", "grate"], true),
+            result(
+                "crate",
+                &["crane", "slate", "irate", "grate", "frate", "brate"],
+                false,
+            ),
+        ];
+
+        let stats = TestAllStatistics::from_results(&results, 10, Duration::from_secs(1));
+
+        assert_eq!(stats.total_words, 3);
+        assert!(stats.is_partial());
+        assert_eq!(stats.solved, 2);
+        assert_eq!(stats.failed, 1);
+        assert_eq!(stats.failed_words, ["crate"]);
+        assert!((stats.average_guesses - 3.0).abs() < 1e-9);
+        assert_eq!((stats.min_guesses, stats.max_guesses), (2, 4));
+        assert_eq!(stats.guess_distribution.get(&2), Some(&1));
+        assert_eq!(stats.first_guess_used.get("salet"), Some(&2));
+        assert_eq!(stats.first_guess_used.get("crane"), Some(&1));
+    }
+
+    #[test]
+    fn statistics_without_words_only_explain_why() {
+        let write = |stats: &TestAllStatistics| {
+            let mut out = Vec::new();
+            write_test_all_statistics(&mut out, stats).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        let interrupted = TestAllStatistics::from_results(&[], 3, Duration::ZERO);
+        assert_eq!(
+            write(&interrupted).trim(),
+            "Interrupted before any word was tested (3 planned)."
+        );
+        let nothing_planned = TestAllStatistics::from_results(&[], 0, Duration::ZERO);
+        assert_eq!(write(&nothing_planned).trim(), "No words to test.");
+
+        let results = [result("irate", &["salet", "irate"], true)];
+        let tested = write(&TestAllStatistics::from_results(
+            &results,
+            3,
+            Duration::ZERO,
+        ));
+        assert!(tested.contains("Partial Test Results"));
+        let not_finite = |token: &str| {
+            let number = token.trim_matches(|c| matches!(c, '(' | ')' | '%'));
+            ["NaN", "inf", "-inf"].contains(&number)
+        };
+        assert!(!tested.split_whitespace().any(not_finite), "{tested}");
+    }
+
+    #[test]
+    fn run_test_all_stops_when_flag_set() {
+        let words = vec![
+            Word::new("irate").unwrap(),
+            Word::new("crate").unwrap(),
+            Word::new("grate").unwrap(),
+        ];
+        let solver = Solver::new(EntropyStrategy, &words, &words);
+
+        let stats = run_test_all(
+            &solver,
+            &words,
+            None,
+            None,
+            &AtomicBool::new(true),
+            ProgressMode::Hidden,
+        );
+        assert_eq!(stats.total_words, 0);
+        assert_eq!(stats.planned_words, 3);
+        assert!(stats.is_partial());
+
+        let stats = run_test_all(
+            &solver,
+            &words,
+            None,
+            None,
+            &AtomicBool::new(false),
+            ProgressMode::Hidden,
+        );
+        assert_eq!(stats.total_words, 3);
+        assert!(!stats.is_partial());
+    }
+
+    #[test]
+    fn no_progress_always_hides() {
+        assert_eq!(ProgressMode::detect(true), ProgressMode::Hidden);
+        // Test output is captured, so there is never a terminal to draw on
+        assert_ne!(ProgressMode::detect(false), ProgressMode::Hidden);
+
+        // Only the bar mode draws a bar; the others must not draw over
+        // their own output (or lack of it)
+        for mode in [ProgressMode::Hidden, ProgressMode::Lines] {
+            assert!(Progress::new(10, mode).bar.is_hidden(), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn select_hard_words_filters_by_guess_count() {
+        let words: Vec<Word> = ["irate", "crate", "grate", "frate", "prate", "orate"]
+            .iter()
+            .map(|w| Word::new(*w).unwrap())
+            .collect();
+        let solver = Solver::new(EntropyStrategy, &words, &words);
+        let stop = AtomicBool::new(false);
+
+        let everything = select_hard_words(&solver, &words, 1, &stop, ProgressMode::Hidden);
+        let texts: Vec<&str> = everything.iter().map(|h| h.word.text()).collect();
+        assert_eq!(
+            texts,
+            words.iter().map(Word::text).collect::<Vec<_>>(),
+            "keeps answer order"
+        );
+
+        let hard = select_hard_words(&solver, &words, 3, &stop, ProgressMode::Hidden);
+        assert!(!hard.is_empty() && hard.len() < words.len());
+        assert!(hard.iter().all(|h| !h.success || h.guesses >= 3));
+
+        let again = select_hard_words(&solver, &words, 3, &stop, ProgressMode::Hidden);
+        assert_eq!(
+            hard.iter().map(|h| h.word.text()).collect::<Vec<_>>(),
+            again.iter().map(|h| h.word.text()).collect::<Vec<_>>()
+        );
+    }
 }
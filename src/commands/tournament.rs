@@ -0,0 +1,142 @@
+//! Strategy tournament command
+//!
+//! Plays several strategies on the same answers and counts, for every pair,
+//! how often one needed fewer guesses than the other.
+
+use crate::core::Word;
+use crate::output::formatters::progress_bar;
+use crate::solver::{MAX_GUESSES, Solver, Strategy, simulate_game};
+
+/// One strategy's record against another over the same answers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeadToHead {
+    /// Answers solved in fewer guesses than the opponent
+    pub wins: usize,
+    /// Answers solved in the same number of guesses (or failed by both)
+    pub ties: usize,
+    /// Answers solved in more guesses than the opponent
+    pub losses: usize,
+}
+
+/// Guess counts of every entrant on every answer
+#[derive(Debug, Clone)]
+pub struct Tournament {
+    /// Per entrant, the guesses used on each answer in order; `None` if unsolved
+    pub results: Vec<Vec<Option<usize>>>,
+}
+
+impl Tournament {
+    /// Number of answers every entrant played
+    #[must_use]
+    pub fn answers(&self) -> usize {
+        self.results.first().map_or(0, Vec::len)
+    }
+
+    /// Record of entrant `a` against entrant `b`
+    ///
+    /// A failure loses to any solve, and two failures tie.
+    #[must_use]
+    pub fn head_to_head(&self, a: usize, b: usize) -> HeadToHead {
+        let mut record = HeadToHead::default();
+        for (x, y) in self.results[a].iter().zip(&self.results[b]) {
+            match x.unwrap_or(usize::MAX).cmp(&y.unwrap_or(usize::MAX)) {
+                std::cmp::Ordering::Less => record.wins += 1,
+                std::cmp::Ordering::Equal => record.ties += 1,
+                std::cmp::Ordering::Greater => record.losses += 1,
+            }
+        }
+        record
+    }
+
+    /// Average guesses of entrant `a` over the answers it solved
+    #[must_use]
+    pub fn mean_guesses(&self, a: usize) -> f64 {
+        let solved: Vec<usize> = self.results[a].iter().flatten().copied().collect();
+        if solved.is_empty() {
+            return 0.0;
+        }
+        solved.iter().sum::<usize>() as f64 / solved.len() as f64
+    }
+
+    /// Answers entrant `a` failed to solve within the guess limit
+    #[must_use]
+    pub fn failures(&self, a: usize) -> usize {
+        self.results[a].iter().filter(|r| r.is_none()).count()
+    }
+}
+
+/// Play every entrant on every answer
+///
+/// Entrants should be deterministic (seed any random choices) so the
+/// tournament is reproducible.
+pub fn tournament<S: Strategy>(entrants: &[Solver<S>], answers: &[Word]) -> Tournament {
+    let pb = progress_bar(entrants.len() * answers.len(), "");
+
+    let results = entrants
+        .iter()
+        .enumerate()
+        .map(|(i, solver)| {
+            pb.set_message(format!("entrant {}/{}", i + 1, entrants.len()));
+            answers
+                .iter()
+                .map(|answer| {
+                    let record = simulate_game(solver, answer, MAX_GUESSES, None);
+                    pb.inc(1);
+                    record.success.then(|| record.num_guesses())
+                })
+                .collect()
+        })
+        .collect();
+    pb.finish_and_clear();
+
+    Tournament { results }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::StrategyType;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn head_to_head_is_symmetric() {
+        let all_words = words_from_slice(&ALLOWED[..100]);
+        let answer_words = words_from_slice(&ANSWERS[..50]);
+        let entrants: Vec<_> = ["entropy", "minimax", "naive"]
+            .iter()
+            .map(|name| Solver::new(StrategyType::from_name(name), &all_words, &answer_words))
+            .collect();
+
+        let result = tournament(&entrants, &answer_words);
+        assert_eq!(result.answers(), answer_words.len());
+
+        for a in 0..entrants.len() {
+            let own = result.head_to_head(a, a);
+            assert_eq!(own.ties, answer_words.len());
+            for b in 0..entrants.len() {
+                let ab = result.head_to_head(a, b);
+                let ba = result.head_to_head(b, a);
+                assert_eq!((ab.wins, ab.ties, ab.losses), (ba.losses, ba.ties, ba.wins));
+                assert_eq!(ab.wins + ab.ties + ab.losses, answer_words.len());
+            }
+        }
+    }
+
+    #[test]
+    fn failures_lose_to_solves() {
+        let result = Tournament {
+            results: vec![vec![Some(3), None, None], vec![Some(3), Some(6), None]],
+        };
+        assert_eq!(
+            result.head_to_head(0, 1),
+            HeadToHead {
+                wins: 0,
+                ties: 2,
+                losses: 1
+            }
+        );
+        assert_eq!(result.failures(0), 2);
+        assert!((result.mean_guesses(1) - 4.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,163 @@
+//! Trap group command
+//!
+//! Finds the answers an opener leaves tangled together: the groups sharing
+//! one feedback pattern that are large enough to cost extra guesses, such as
+//! rhyming families that differ in a single letter.
+
+use crate::core::{Pattern, Word};
+use crate::solver::entropy::group_by_pattern;
+use crate::solver::{Solver, Strategy};
+
+/// Smallest group reported as a trap by default
+pub const DEFAULT_TRAP_SIZE: usize = 50;
+
+/// Answers the opener can't tell apart
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrapGroup {
+    /// Feedback every answer in the group gives the opener
+    pub pattern: Pattern,
+    /// The answers, in answer list order
+    pub answers: Vec<String>,
+}
+
+impl TrapGroup {
+    /// Letters every answer in the group shares, with `?` where they differ
+    ///
+    /// A template like `?ATCH` marks a family only one letter tells apart.
+    #[must_use]
+    pub fn template(&self) -> String {
+        (0..5)
+            .map(|i| {
+                let mut letters = self.answers.iter().map(|a| a.as_bytes()[i]);
+                let first = letters.next().unwrap_or(b'?');
+                if letters.all(|l| l == first) {
+                    char::from(first.to_ascii_uppercase())
+                } else {
+                    '?'
+                }
+            })
+            .collect()
+    }
+}
+
+/// Trap groups an opener leaves among the answers
+#[derive(Debug, Clone)]
+pub struct TrapReport {
+    pub opener: String,
+    /// Answers the opener was scored against
+    pub total: usize,
+    /// Smallest group size reported
+    pub min_size: usize,
+    /// Groups of at least `min_size` answers, largest first; equal groups
+    /// follow pattern order (more greens, then more yellows, first)
+    pub groups: Vec<TrapGroup>,
+}
+
+impl TrapReport {
+    /// Answers that fall into a trap group
+    #[must_use]
+    pub fn trapped(&self) -> usize {
+        self.groups.iter().map(|g| g.answers.len()).sum()
+    }
+}
+
+/// Group the answers by the feedback `opener` gives and keep the large groups
+///
+/// This is the opener's partition of the answers restricted to groups of at
+/// least `min_size`. `opener` defaults to the solver's opener.
+///
+/// # Errors
+///
+/// Returns an error if there is no opener or no answers.
+pub fn find_traps<S: Strategy>(
+    solver: &Solver<S>,
+    opener: Option<&Word>,
+    min_size: usize,
+) -> Result<TrapReport, String> {
+    let opener = opener
+        .or_else(|| solver.first_guess())
+        .ok_or("No opener available")?;
+    let answers = solver.get_candidates(&[]);
+    if answers.is_empty() {
+        return Err("No answers to group".to_string());
+    }
+
+    let mut sizes: Vec<(Pattern, usize)> = group_by_pattern(opener, &answers)
+        .into_iter()
+        .filter(|&(_, count)| count >= min_size.max(1))
+        .collect();
+    sizes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let mut groups: Vec<TrapGroup> = sizes
+        .into_iter()
+        .map(|(pattern, count)| TrapGroup {
+            pattern,
+            answers: Vec::with_capacity(count),
+        })
+        .collect();
+
+    for answer in &answers {
+        let pattern = Pattern::calculate(opener, answer);
+        if let Some(group) = groups.iter_mut().find(|g| g.pattern == pattern) {
+            group.answers.push(answer.text().to_string());
+        }
+    }
+
+    Ok(TrapReport {
+        opener: opener.text().to_string(),
+        total: answers.len(),
+        min_size,
+        groups,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::EntropyStrategy;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn groups_are_the_large_parts_of_the_partition() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(&ANSWERS[..400]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let opener = Word::new("salet").unwrap();
+
+        let report = find_traps(&solver, Some(&opener), 5).unwrap();
+        assert_eq!(report.opener, "salet");
+        assert_eq!(report.total, 400);
+
+        let answer_refs: Vec<&Word> = answer_words.iter().collect();
+        let counts = group_by_pattern(&opener, &answer_refs);
+        let expected: usize = counts.values().filter(|&&c| c >= 5).sum();
+        assert_eq!(report.trapped(), expected);
+        for pair in report.groups.windows(2) {
+            assert!(pair[0].answers.len() >= pair[1].answers.len());
+        }
+        for group in &report.groups {
+            assert_eq!(group.answers.len(), counts[&group.pattern]);
+            for answer in &group.answers {
+                let answer = Word::new(answer.as_str()).unwrap();
+                assert_eq!(Pattern::calculate(&opener, &answer), group.pattern);
+            }
+        }
+
+        // A threshold above the largest group leaves nothing
+        let none = find_traps(&solver, Some(&opener), 401).unwrap();
+        assert!(none.groups.is_empty());
+    }
+
+    #[test]
+    fn template_keeps_shared_letters() {
+        let group = TrapGroup {
+            pattern: Pattern::new(0),
+            answers: vec![
+                "batch".to_string(),
+                "catch".to_string(),
+                "hatch".to_string(),
+            ],
+        };
+        assert_eq!(group.template(), "?ATCH");
+    }
+}
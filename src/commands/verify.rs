@@ -0,0 +1,108 @@
+//! Word list verification command
+//!
+//! Checks the invariants the solver assumes of its word lists: every answer
+//! is a valid word in the guess pool, and neither list repeats a word.
+
+use crate::core::{FxHashMap, Word, WordIndex};
+
+/// Violations found in a guess pool and answer list
+#[derive(Debug, Clone, Default)]
+pub struct VerifyReport {
+    /// Words in the guess pool
+    pub guesses: usize,
+    /// Words in the answer list
+    pub answers: usize,
+    /// Entries rejected as not valid five-letter words, as reported by the loader
+    pub rejected: Vec<String>,
+    /// Answers missing from the guess pool
+    pub answers_not_in_pool: Vec<String>,
+    /// Words listed more than once in the answer list
+    pub duplicate_answers: Vec<String>,
+    /// Words listed more than once in the guess pool
+    pub duplicate_guesses: Vec<String>,
+}
+
+impl VerifyReport {
+    /// Total number of violations of every kind
+    #[must_use]
+    pub fn violations(&self) -> usize {
+        self.rejected.len()
+            + self.answers_not_in_pool.len()
+            + self.duplicate_answers.len()
+            + self.duplicate_guesses.len()
+    }
+
+    /// Whether the lists satisfy every invariant
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.violations() == 0
+    }
+}
+
+/// Check that `answers` is a duplicate-free subset of a duplicate-free `all`
+///
+/// `rejected` carries the entries the loader already dropped as invalid, so
+/// they count as violations too. Words are compared case-insensitively.
+#[must_use]
+pub fn verify_lists(all: &[Word], answers: &[Word], rejected: Vec<String>) -> VerifyReport {
+    let pool = WordIndex::new(all);
+    let answers_not_in_pool = answers
+        .iter()
+        .filter(|answer| !pool.contains(answer))
+        .map(|answer| answer.text().to_string())
+        .collect();
+
+    VerifyReport {
+        guesses: all.len(),
+        answers: answers.len(),
+        rejected,
+        answers_not_in_pool,
+        duplicate_answers: duplicates(answers),
+        duplicate_guesses: duplicates(all),
+    }
+}
+
+/// Words that appear more than once, each listed once in first-seen order
+fn duplicates(words: &[Word]) -> Vec<String> {
+    let mut seen: FxHashMap<&str, usize> = FxHashMap::default();
+    let mut repeated = Vec::new();
+    for word in words {
+        let count = seen.entry(word.text()).or_insert(0);
+        *count += 1;
+        if *count == 2 {
+            repeated.push(word.text().to_string());
+        }
+    }
+    repeated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn embedded_lists_verify() {
+        let report = verify_lists(
+            &words_from_slice(ALLOWED),
+            &words_from_slice(ANSWERS),
+            Vec::new(),
+        );
+        assert!(report.is_ok(), "{report:?}");
+        assert_eq!(report.answers, ANSWERS.len());
+    }
+
+    #[test]
+    fn violations_are_reported() {
+        let all = words_from_slice(&["crane", "slate", "crane", "irate"]);
+        let answers = words_from_slice(&["slate", "grate", "Slate"]);
+
+        let report = verify_lists(&all, &answers, vec!["line 3: 'co-op'".to_string()]);
+        assert_eq!(report.answers_not_in_pool, ["grate"]);
+        assert_eq!(report.duplicate_answers, ["slate"]);
+        assert_eq!(report.duplicate_guesses, ["crane"]);
+        assert_eq!(report.violations(), 4);
+        assert!(!report.is_ok());
+    }
+}
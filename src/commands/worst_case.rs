@@ -0,0 +1,683 @@
+//! Worst-case command
+//!
+//! Walks the solver's decision tree from its opener instead of simulating
+//! every answer separately: answers that share feedback share a node, so each
+//! distinct game state is solved once.
+
+use crate::artifact::{self, Artifact, ByteReader};
+use crate::core::{FxHashMap, Pattern, Word};
+use crate::output::formatters::progress_bar;
+use crate::solver::{MAX_GUESSES, Solver, Strategy};
+use indicatif::ProgressBar;
+use std::io::{self, Write};
+
+/// DOT attributes for a solved answer
+const SOLVED_STYLE: &str = "style=\"filled,rounded\", fillcolor=\"palegreen\", penwidth=2";
+
+/// The guesses the solver makes for one answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuessPath {
+    pub answer: String,
+    /// Every guess in order; ends with the answer if it was solved
+    pub guesses: Vec<String>,
+    /// Feedback for each guess
+    pub patterns: Vec<Pattern>,
+}
+
+/// A node of the solver's decision tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveTree {
+    /// The solver plays `guess`; answers giving any other feedback than
+    /// all-green continue in the child for that feedback
+    Guess {
+        guess: String,
+        /// Whether `guess` is one of the answers left at this node
+        solves: bool,
+        /// Children in pattern order
+        children: Vec<(Pattern, SolveTree)>,
+    },
+    /// Answers still unsolved when the guesses ran out
+    Unsolved(Vec<String>),
+}
+
+impl SolveTree {
+    /// Answers the tree solves
+    #[must_use]
+    pub fn solved(&self) -> usize {
+        match self {
+            Self::Guess {
+                solves, children, ..
+            } => usize::from(*solves) + children.iter().map(|(_, c)| c.solved()).sum::<usize>(),
+            Self::Unsolved(_) => 0,
+        }
+    }
+}
+
+/// Deepest paths through the solver's decision tree
+#[derive(Debug, Clone)]
+pub struct WorstCaseReport {
+    /// Answers in the tree
+    pub total_answers: usize,
+    /// Most guesses any solved answer needs; the solver's guarantee when
+    /// `failed` is empty
+    pub max_guesses: usize,
+    /// Solved answers needing `max_guesses`, in tree order
+    pub worst: Vec<GuessPath>,
+    /// Answers not solved within `MAX_GUESSES`
+    pub failed: Vec<GuessPath>,
+    /// Distinct game states the solver picked a guess for
+    pub nodes: usize,
+    /// Guesses summed over the solved answers
+    pub total_guesses: usize,
+    /// The decision tree itself
+    pub tree: SolveTree,
+}
+
+impl WorstCaseReport {
+    /// Average guesses over the solved answers, as `test-all` reports it
+    #[must_use]
+    pub fn average_guesses(&self) -> f64 {
+        let solved = self.total_answers - self.failed.len();
+        if solved == 0 {
+            return 0.0;
+        }
+        self.total_guesses as f64 / solved as f64
+    }
+}
+
+/// Find the answers the solver needs the most guesses for
+///
+/// Builds the solver's decision tree over its answer list: at each node the
+/// solver picks a guess, and the remaining answers split into child nodes by
+/// the feedback they would give. The tree depth is the worst case. Paths
+/// match [`simulate_game`](crate::solver::simulate_game) for deterministic
+/// strategies, so seed strategies with random choices first.
+///
+/// `forced_first` replaces the solver's opener, as in `run_test_all`.
+pub fn worst_case<S: Strategy>(solver: &Solver<S>, forced_first: Option<&Word>) -> WorstCaseReport {
+    let candidates = solver.get_candidates(&[]);
+
+    let pb = progress_bar(candidates.len(), "");
+
+    let mut walk = TreeWalk {
+        solver,
+        forced_first,
+        pb: &pb,
+        report: WorstCaseReport {
+            total_answers: candidates.len(),
+            max_guesses: 0,
+            worst: Vec::new(),
+            failed: Vec::new(),
+            nodes: 0,
+            total_guesses: 0,
+            tree: SolveTree::Unsolved(Vec::new()),
+        },
+    };
+    walk.report.tree = walk.visit(&mut Vec::new(), candidates);
+    pb.finish_and_clear();
+
+    walk.report
+}
+
+/// State for one depth-first walk of the decision tree
+struct TreeWalk<'s, 'w, S: Strategy> {
+    solver: &'s Solver<'w, S>,
+    forced_first: Option<&'s Word>,
+    pb: &'s ProgressBar,
+    report: WorstCaseReport,
+}
+
+impl<S: Strategy> TreeWalk<'_, '_, S> {
+    /// Visit the node reached by `history`, where `candidates` remain
+    fn visit(&mut self, history: &mut Vec<(Word, Pattern)>, candidates: Vec<&Word>) -> SolveTree {
+        let guess = match (history.is_empty(), self.forced_first) {
+            (true, Some(forced)) => Some(forced),
+            _ if history.len() < MAX_GUESSES => self.solver.next_guess(history),
+            _ => None,
+        };
+        let Some(guess) = guess else {
+            for &answer in &candidates {
+                self.finish(history, answer, false);
+            }
+            return SolveTree::Unsolved(candidates.iter().map(|w| w.text().to_string()).collect());
+        };
+        self.report.nodes += 1;
+
+        let (winner, groups) = split(guess, candidates);
+        if let Some(answer) = winner {
+            history.push((guess.clone(), Pattern::PERFECT));
+            self.finish(history, answer, true);
+            history.pop();
+        }
+        let children = groups
+            .into_iter()
+            .map(|(pattern, group)| {
+                history.push((guess.clone(), pattern));
+                let child = self.visit(history, group);
+                history.pop();
+                (pattern, child)
+            })
+            .collect();
+
+        SolveTree::Guess {
+            guess: guess.text().to_string(),
+            solves: winner.is_some(),
+            children,
+        }
+    }
+
+    /// Replay the saved node reached by `history`, checking it still fits
+    /// `candidates`
+    fn replay(
+        &mut self,
+        history: &mut Vec<(Word, Pattern)>,
+        candidates: Vec<&Word>,
+        node: &SolveTree,
+    ) -> Result<(), String> {
+        let (guess, solves, children) = match node {
+            SolveTree::Unsolved(answers) => {
+                if !answers
+                    .iter()
+                    .map(String::as_str)
+                    .eq(candidates.iter().map(|w| w.text()))
+                {
+                    return Err("an unsolved leaf lists other answers".to_string());
+                }
+                for &answer in &candidates {
+                    self.finish(history, answer, false);
+                }
+                return Ok(());
+            }
+            SolveTree::Guess {
+                guess,
+                solves,
+                children,
+            } => (guess, *solves, children),
+        };
+        if history.len() >= MAX_GUESSES {
+            return Err(format!("more than {MAX_GUESSES} guesses deep"));
+        }
+        let guess = Word::new(guess.as_str()).map_err(|e| e.to_string())?;
+        self.report.nodes += 1;
+
+        let (winner, groups) = split(&guess, candidates);
+        if winner.is_some() != solves {
+            return Err(format!("{} no longer solves where it did", guess.text()));
+        }
+        if !groups
+            .iter()
+            .map(|(p, _)| p)
+            .eq(children.iter().map(|(p, _)| p))
+        {
+            return Err(format!("{} splits the answers differently", guess.text()));
+        }
+        if let Some(answer) = winner {
+            history.push((guess.clone(), Pattern::PERFECT));
+            self.finish(history, answer, true);
+            history.pop();
+        }
+        for ((pattern, group), (_, child)) in groups.into_iter().zip(children) {
+            history.push((guess.clone(), pattern));
+            self.replay(history, group, child)?;
+            history.pop();
+        }
+        Ok(())
+    }
+
+    /// Record the path to a leaf for `answer`
+    fn finish(&mut self, history: &[(Word, Pattern)], answer: &Word, solved: bool) {
+        let path = GuessPath {
+            answer: answer.text().to_string(),
+            guesses: history.iter().map(|(g, _)| g.text().to_string()).collect(),
+            patterns: history.iter().map(|&(_, p)| p).collect(),
+        };
+        let report = &mut self.report;
+
+        if solved {
+            report.total_guesses += history.len();
+        }
+        if !solved {
+            report.failed.push(path);
+        } else if history.len() > report.max_guesses {
+            report.max_guesses = history.len();
+            report.worst = vec![path];
+        } else if history.len() == report.max_guesses {
+            report.worst.push(path);
+        }
+
+        self.pb.inc(1);
+        self.pb
+            .set_message(format!("worst so far: {} guesses", report.max_guesses));
+    }
+}
+
+/// Split `candidates` by their feedback to `guess`
+///
+/// Returns the candidate `guess` solves, if any, and the rest grouped by
+/// pattern in pattern order, so trees are stable.
+fn split<'w>(
+    guess: &Word,
+    candidates: Vec<&'w Word>,
+) -> (Option<&'w Word>, Vec<(Pattern, Vec<&'w Word>)>) {
+    let mut solved = None;
+    let mut groups: FxHashMap<Pattern, Vec<&Word>> = FxHashMap::default();
+    for answer in candidates {
+        let pattern = Pattern::calculate(guess, answer);
+        if pattern.is_perfect() {
+            solved = Some(answer);
+        } else {
+            groups.entry(pattern).or_default().push(answer);
+        }
+    }
+    let mut groups: Vec<_> = groups.into_iter().collect();
+    groups.sort_unstable_by_key(|(pattern, _)| *pattern);
+    (solved, groups)
+}
+
+/// Rebuild the report for a saved decision tree, such as one loaded with
+/// [`artifact::load`]
+///
+/// The tree is replayed over the solver's answers rather than trusted: each
+/// guess must split the answers at its node into exactly its children, so
+/// a tree saved for other word lists is rejected. Which guess the solver
+/// would pick is not checked, as that costs as much as rebuilding the tree;
+/// key saved trees on [`SOLVER_REVISION`](crate::solver::SOLVER_REVISION)
+/// instead.
+///
+/// # Errors
+///
+/// Returns an error if the tree does not fit the solver's answer list.
+pub fn worst_case_from_tree<S: Strategy>(
+    solver: &Solver<S>,
+    tree: SolveTree,
+) -> Result<WorstCaseReport, String> {
+    let candidates = solver.get_candidates(&[]);
+    let pb = ProgressBar::hidden();
+    let mut walk = TreeWalk {
+        solver,
+        forced_first: None,
+        pb: &pb,
+        report: WorstCaseReport {
+            total_answers: candidates.len(),
+            max_guesses: 0,
+            worst: Vec::new(),
+            failed: Vec::new(),
+            nodes: 0,
+            total_guesses: 0,
+            tree: SolveTree::Unsolved(Vec::new()),
+        },
+    };
+    walk.replay(&mut Vec::new(), candidates, &tree)
+        .map_err(|e| format!("The decision tree does not fit the word lists: {e}"))?;
+    walk.report.tree = tree;
+    Ok(walk.report)
+}
+
+/// Saved as a pre-order walk: a tag byte (0 unsolved, 1 guess), then for an
+/// unsolved leaf a `u32` count and the answers, and for a guess node the
+/// guess, whether it solves, a `u16` child count and each child after its
+/// pattern
+impl Artifact for SolveTree {
+    const KIND: u8 = 1;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Unsolved(answers) => {
+                out.push(0);
+                out.extend_from_slice(&(answers.len() as u32).to_le_bytes());
+                for answer in answers {
+                    artifact::put_word(out, answer);
+                }
+            }
+            Self::Guess {
+                guess,
+                solves,
+                children,
+            } => {
+                out.push(1);
+                artifact::put_word(out, guess);
+                out.push(u8::from(*solves));
+                out.extend_from_slice(&(children.len() as u16).to_le_bytes());
+                for (pattern, child) in children {
+                    artifact::put_pattern(out, *pattern);
+                    child.encode(out);
+                }
+            }
+        }
+    }
+
+    fn decode(reader: &mut ByteReader<'_>) -> Option<Self> {
+        decode_tree(reader, 0)
+    }
+}
+
+/// Decode the node at `depth`, refusing trees deeper than any game
+fn decode_tree(reader: &mut ByteReader<'_>, depth: usize) -> Option<SolveTree> {
+    if depth > MAX_GUESSES {
+        return None;
+    }
+    let text = |reader: &mut ByteReader<'_>| reader.word().map(|w| w.text().to_string());
+    match reader.u8()? {
+        0 => {
+            let count = reader.u32()?;
+            (0..count)
+                .map(|_| text(reader))
+                .collect::<Option<_>>()
+                .map(SolveTree::Unsolved)
+        }
+        1 => {
+            let guess = text(reader)?;
+            let solves = match reader.u8()? {
+                0 => false,
+                1 => true,
+                _ => return None,
+            };
+            let count = reader.u16()?;
+            let children = (0..count)
+                .map(|_| Some((reader.pattern()?, decode_tree(reader, depth + 1)?)))
+                .collect::<Option<_>>()?;
+            Some(SolveTree::Guess {
+                guess,
+                solves,
+                children,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Write a decision tree in Graphviz DOT format
+///
+/// Guesses are boxes and edges are labeled with their feedback as `G`, `Y`
+/// and `-`. Solved answers are green leaves: a guess that can be the answer
+/// gets a `GGGGG` edge to one, or becomes one itself when nothing else is
+/// left. Answers left unsolved are grouped in a red leaf. Render with e.g.
+/// `dot -Tsvg tree.dot -o tree.svg`.
+///
+/// # Errors
+///
+/// Returns any error from the underlying writer.
+pub fn write_tree_dot<W: Write>(mut writer: W, tree: &SolveTree) -> io::Result<()> {
+    writeln!(writer, "digraph solve_tree {{")?;
+    writeln!(writer, "    rankdir=LR;")?;
+    writeln!(writer, "    node [shape=box, fontname=\"Helvetica\"];")?;
+    writeln!(writer, "    edge [fontname=\"Courier\"];")?;
+    let mut next_id = 0;
+    write_dot_node(&mut writer, tree, &mut next_id)?;
+    writeln!(writer, "}}")?;
+    writer.flush()
+}
+
+/// Feedback as an edge label, like `GY-GY`
+fn dot_pattern(pattern: Pattern) -> String {
+    pattern.to_emoji_with(['-', 'Y', 'G'])
+}
+
+/// Write `tree` and its edges, returning the id of its root node
+fn write_dot_node<W: Write>(
+    writer: &mut W,
+    tree: &SolveTree,
+    next_id: &mut usize,
+) -> io::Result<usize> {
+    let id = *next_id;
+    *next_id += 1;
+    match tree {
+        SolveTree::Unsolved(answers) => {
+            let label: Vec<String> = answers.iter().map(|a| a.to_uppercase()).collect();
+            writeln!(
+                writer,
+                "    n{id} [label=\"{}\", style=filled, fillcolor=\"lightpink\"];",
+                label.join("\\n")
+            )?;
+        }
+        SolveTree::Guess {
+            guess,
+            solves,
+            children,
+        } => {
+            let guess = guess.to_uppercase();
+            if *solves && children.is_empty() {
+                writeln!(writer, "    n{id} [label=\"{guess}\", {SOLVED_STYLE}];")?;
+                return Ok(id);
+            }
+            writeln!(writer, "    n{id} [label=\"{guess}\"];")?;
+            if *solves {
+                let leaf = *next_id;
+                *next_id += 1;
+                writeln!(writer, "    n{leaf} [label=\"{guess}\", {SOLVED_STYLE}];")?;
+                writeln!(
+                    writer,
+                    "    n{id} -> n{leaf} [label=\"{}\"];",
+                    dot_pattern(Pattern::PERFECT)
+                )?;
+            }
+            for (pattern, child) in children {
+                let child = write_dot_node(writer, child, next_id)?;
+                writeln!(
+                    writer,
+                    "    n{id} -> n{child} [label=\"{}\"];",
+                    dot_pattern(*pattern)
+                )?;
+            }
+        }
+    }
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{
+        ADAPTIVE_PRESETS, AdaptiveStrategy, EntropyStrategy, GameRecord, simulate_game,
+    };
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn tree_matches_simulated_games() {
+        let all_words = words_from_slice(&ALLOWED[..200]);
+        let answer_words = words_from_slice(&ANSWERS[..60]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        let report = worst_case(&solver, None);
+
+        let records: Vec<_> = answer_words
+            .iter()
+            .map(|answer| simulate_game(&solver, answer, MAX_GUESSES, None))
+            .collect();
+        let max = records
+            .iter()
+            .filter(|r| r.success)
+            .map(GameRecord::num_guesses)
+            .max()
+            .unwrap();
+
+        assert_eq!(report.total_answers, answer_words.len());
+        assert_eq!(report.max_guesses, max);
+        assert_eq!(
+            report.failed.len(),
+            records.iter().filter(|r| !r.success).count()
+        );
+        // Shared prefixes are solved once rather than once per answer
+        let selections: usize = records.iter().map(GameRecord::num_guesses).sum();
+        assert!(report.nodes < selections);
+        let wins: Vec<&GameRecord> = records.iter().filter(|r| r.success).collect();
+        let average =
+            wins.iter().map(|r| r.num_guesses()).sum::<usize>() as f64 / wins.len() as f64;
+        assert!((report.average_guesses() - average).abs() < 1e-12);
+
+        for path in &report.worst {
+            let answer = Word::new(path.answer.as_str()).unwrap();
+            let record = simulate_game(&solver, &answer, MAX_GUESSES, None);
+            let guesses: Vec<&str> = record.guesses().map(Word::text).collect();
+            assert_eq!(guesses, path.guesses);
+            assert_eq!(path.guesses.last(), Some(&path.answer));
+        }
+    }
+
+    #[test]
+    fn tree_records_every_answer() {
+        let all_words = words_from_slice(&ALLOWED[..200]);
+        let answer_words = words_from_slice(&ANSWERS[..60]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        let report = worst_case(&solver, None);
+        assert_eq!(
+            report.tree.solved(),
+            report.total_answers - report.failed.len()
+        );
+        let SolveTree::Guess { guess, .. } = &report.tree else {
+            panic!("the root plays the opener");
+        };
+        assert_eq!(
+            Some(guess.as_str()),
+            report.worst[0].guesses.first().map(String::as_str)
+        );
+
+        let mut dot = Vec::new();
+        write_tree_dot(&mut dot, &report.tree).unwrap();
+        let dot = String::from_utf8(dot).unwrap();
+        assert!(dot.starts_with("digraph solve_tree {"));
+        assert!(dot.trim_end().ends_with('}'));
+        // One green leaf per solved answer, and a tree: one edge per node but the root
+        assert_eq!(dot.matches("palegreen").count(), report.tree.solved());
+        let nodes = dot
+            .lines()
+            .filter(|l| l.contains("[label=") && !l.contains("->"))
+            .count();
+        assert_eq!(dot.matches(" -> ").count(), nodes - 1);
+    }
+
+    #[test]
+    fn dot_marks_solved_and_unsolved_leaves() {
+        let tree = SolveTree::Guess {
+            guess: "salet".to_string(),
+            solves: true,
+            children: vec![
+                (
+                    Pattern::from_str("--Y--").unwrap(),
+                    SolveTree::Unsolved(vec!["lucky".to_string(), "lynch".to_string()]),
+                ),
+                (
+                    Pattern::from_str("Y----").unwrap(),
+                    SolveTree::Guess {
+                        guess: "crush".to_string(),
+                        solves: true,
+                        children: Vec::new(),
+                    },
+                ),
+            ],
+        };
+        assert_eq!(tree.solved(), 2);
+
+        let mut dot = Vec::new();
+        write_tree_dot(&mut dot, &tree).unwrap();
+        let dot = String::from_utf8(dot).unwrap();
+        assert!(dot.contains("n0 [label=\"SALET\"];"));
+        assert!(dot.contains(&format!("n1 [label=\"SALET\", {SOLVED_STYLE}];")));
+        assert!(dot.contains("n0 -> n1 [label=\"GGGGG\"];"));
+        assert!(
+            dot.contains("n2 [label=\"LUCKY\\nLYNCH\", style=filled, fillcolor=\"lightpink\"];")
+        );
+        assert!(dot.contains("n0 -> n2 [label=\"--Y--\"];"));
+        assert!(dot.contains(&format!("n3 [label=\"CRUSH\", {SOLVED_STYLE}];")));
+        assert!(dot.contains("n0 -> n3 [label=\"Y----\"];"));
+    }
+
+    #[test]
+    fn saved_trees_replay_to_the_same_report() {
+        let all_words = words_from_slice(&ALLOWED[..200]);
+        let answer_words = words_from_slice(&ANSWERS[..60]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let report = worst_case(&solver, None);
+
+        let key = artifact::ArtifactKey::new(&all_words, &answer_words, "entropy");
+        let mut bytes = Vec::new();
+        artifact::write_artifact(&mut bytes, &key, &report.tree).unwrap();
+        let tree: SolveTree = artifact::read_artifact(bytes.as_slice(), &key).unwrap();
+        assert_eq!(tree, report.tree);
+
+        let replayed = worst_case_from_tree(&solver, tree).unwrap();
+        assert_eq!(replayed.total_answers, report.total_answers);
+        assert_eq!(replayed.max_guesses, report.max_guesses);
+        assert_eq!(replayed.worst, report.worst);
+        assert_eq!(replayed.failed, report.failed);
+        assert_eq!(replayed.nodes, report.nodes);
+        assert_eq!(replayed.total_guesses, report.total_guesses);
+
+        // Fewer answers than the tree was built for
+        let fewer = Solver::new(EntropyStrategy, &all_words, &answer_words[..59]);
+        let error = worst_case_from_tree(&fewer, report.tree).unwrap_err();
+        assert!(error.contains("does not fit"), "{error}");
+    }
+
+    #[test]
+    fn forced_first_opens_every_path() {
+        let all_words = words_from_slice(&ALLOWED[..200]);
+        let answer_words = words_from_slice(&ANSWERS[..30]);
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let opener = &all_words[7];
+
+        let report = worst_case(&solver, Some(opener));
+
+        for path in report.worst.iter().chain(&report.failed) {
+            assert_eq!(path.guesses[0], opener.text());
+        }
+    }
+
+    /// Correctness contract for the shipped default: every answer within
+    /// `MAX_GUESSES`. Takes about a minute in release builds, so run it with
+    /// `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore = "solves all 2,315 answers; run with --release -- --ignored"]
+    fn default_solver_solves_every_answer() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(ANSWERS);
+        let strategy = AdaptiveStrategy::default().with_random_seed(0);
+        let solver = Solver::new(strategy, &all_words, &answer_words);
+
+        let report = worst_case(&solver, None);
+
+        let failures: Vec<String> = report
+            .failed
+            .iter()
+            .map(|path| format!("{}: {}", path.answer, path.guesses.join(" → ")))
+            .collect();
+        assert!(
+            failures.is_empty(),
+            "{} of {} answers not solved in {MAX_GUESSES} guesses:\n{}",
+            failures.len(),
+            report.total_answers,
+            failures.join("\n")
+        );
+        assert_eq!(report.total_answers, ANSWERS.len());
+        assert!(report.max_guesses <= MAX_GUESSES);
+    }
+
+    /// Each preset still plays the average and worst case it advertises.
+    /// Walks the full tree once per preset (a few minutes in release builds),
+    /// so run it with `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore = "solves all 2,315 answers per preset; run with --release -- --ignored"]
+    fn presets_match_their_measured_performance() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(ANSWERS);
+
+        for preset in &ADAPTIVE_PRESETS {
+            let strategy = preset.strategy().with_random_seed(0);
+            let solver = Solver::new(strategy, &all_words, &answer_words);
+            let report = worst_case(&solver, None);
+
+            assert!(report.failed.is_empty(), "{}", preset.name);
+            assert_eq!(report.max_guesses, preset.worst, "{}", preset.name);
+            assert_eq!(report.worst.len(), preset.at_worst, "{}", preset.name);
+            assert!(
+                (report.average_guesses() - preset.average).abs() < 5e-5,
+                "{}: measured {:.4}, advertised {}",
+                preset.name,
+                report.average_guesses(),
+                preset.average
+            );
+        }
+    }
+}
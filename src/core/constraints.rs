@@ -0,0 +1,519 @@
+//! Feedback constraints on possible answers
+//!
+//! A set of observed (guess, pattern) pairs that a candidate answer must
+//! reproduce exactly to remain possible, plus per-letter summaries of what
+//! that feedback reveals. Positions can also be restricted to a class of
+//! letters, such as vowels, for puzzle variants that give softer clues.
+
+use super::{Pattern, Word};
+use alloc::vec::Vec;
+
+/// What the feedback reveals about one letter of the answer
+///
+/// Greens and yellows for a letter give a lower bound on its count. A grey
+/// tile for the same letter in the same guess caps the count at that bound,
+/// so e.g. EERIE against an answer with a single E proves there is *exactly*
+/// one E, not just at least one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LetterClue {
+    /// Fewest occurrences consistent with the feedback
+    pub min: u8,
+    /// Most occurrences consistent with the feedback
+    pub max: u8,
+    /// Positions known to hold the letter (green tiles)
+    pub known: Vec<usize>,
+    /// Positions known not to hold the letter (yellow or grey tiles)
+    pub excluded: Vec<usize>,
+}
+
+impl LetterClue {
+    /// Whether the feedback pins down the exact number of occurrences
+    #[must_use]
+    pub const fn is_exact(&self) -> bool {
+        self.min == self.max
+    }
+}
+
+/// A set of letters one position of the answer may hold
+///
+/// Y counts as a consonant.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::core::LetterClass;
+///
+/// assert!(LetterClass::VOWELS.contains(b'e'));
+/// assert!(LetterClass::CONSONANTS.contains(b'y'));
+/// assert_eq!(LetterClass::parse("vowel"), Some(LetterClass::VOWELS));
+/// assert!(LetterClass::parse("RST").unwrap().contains(b's'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LetterClass {
+    /// Bit `i` is set if letter `b'a' + i` is in the class
+    mask: u32,
+}
+
+impl LetterClass {
+    /// Every letter, i.e. no restriction
+    pub const ANY: Self = Self {
+        mask: (1 << 26) - 1,
+    };
+    /// A, E, I, O and U
+    pub const VOWELS: Self = Self::from_letters(b"aeiou");
+    /// Every letter that isn't a vowel, Y included
+    pub const CONSONANTS: Self = Self::VOWELS.complement();
+
+    /// The class holding `letters`; case-insensitive, non-letters are ignored
+    #[must_use]
+    pub const fn from_letters(letters: &[u8]) -> Self {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < letters.len() {
+            let letter = letters[i].to_ascii_lowercase();
+            if letter.is_ascii_lowercase() {
+                mask |= 1 << (letter - b'a');
+            }
+            i += 1;
+        }
+        Self { mask }
+    }
+
+    /// Parse `vowel`, `consonant` (either may be plural) or a set of letters
+    /// like `rst`
+    ///
+    /// Returns `None` for anything else, including an empty set.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.trim().to_ascii_lowercase().as_str() {
+            "vowel" | "vowels" => Some(Self::VOWELS),
+            "consonant" | "consonants" => Some(Self::CONSONANTS),
+            letters if !letters.is_empty() && letters.bytes().all(|b| b.is_ascii_lowercase()) => {
+                Some(Self::from_letters(letters.as_bytes()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Letters not in this class
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self {
+            mask: !self.mask & Self::ANY.mask,
+        }
+    }
+
+    /// Letters in both classes
+    #[must_use]
+    pub const fn intersect(self, other: Self) -> Self {
+        Self {
+            mask: self.mask & other.mask,
+        }
+    }
+
+    /// Whether `letter` (ASCII, either case) is in the class
+    #[must_use]
+    pub const fn contains(self, letter: u8) -> bool {
+        let letter = letter.to_ascii_lowercase();
+        letter.is_ascii_lowercase() && self.mask & (1 << (letter - b'a')) != 0
+    }
+
+    /// Whether no letter is in the class
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.mask == 0
+    }
+}
+
+impl Default for LetterClass {
+    fn default() -> Self {
+        Self::ANY
+    }
+}
+
+/// Observed feedback that candidate answers must satisfy
+///
+/// Each entry is a guess and the pattern it produced. A word satisfies the
+/// constraints if guessing each guess against it yields the recorded pattern
+/// and each of its letters is in the class its position is restricted to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Constraints {
+    history: Vec<(Word, Pattern)>,
+    /// Letters each position may hold, on top of the feedback
+    classes: [LetterClass; 5],
+}
+
+impl Constraints {
+    /// Create constraints from a guess history
+    #[must_use]
+    pub const fn new(history: Vec<(Word, Pattern)>) -> Self {
+        Self {
+            history,
+            classes: [LetterClass::ANY; 5],
+        }
+    }
+
+    /// Restrict `position` (0-based) to the letters in `class`
+    ///
+    /// Restrictions compose with the feedback and with each other: restricting
+    /// a position twice keeps only the letters in both classes. They narrow
+    /// [`Self::allows`] only; hard mode and letter clues still reflect the
+    /// feedback alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is 5 or more.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Constraints, LetterClass, Pattern, Word};
+    ///
+    /// // An E somewhere but not last, and a consonant first
+    /// let guess = Word::new("crane").unwrap();
+    /// let pattern = Pattern::from_str("----Y").unwrap();
+    /// let mut constraints = Constraints::new(vec![(guess, pattern)]);
+    /// constraints.restrict(0, LetterClass::CONSONANTS);
+    ///
+    /// assert!(constraints.allows(&Word::new("blues").unwrap()));
+    /// assert!(!constraints.allows(&Word::new("elbow").unwrap()));
+    /// ```
+    pub fn restrict(&mut self, position: usize, class: LetterClass) {
+        self.classes[position] = self.classes[position].intersect(class);
+    }
+
+    /// The letters `position` (0-based) is restricted to
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is 5 or more.
+    #[must_use]
+    pub const fn class_at(&self, position: usize) -> LetterClass {
+        self.classes[position]
+    }
+
+    /// Add an observed (guess, pattern) pair
+    pub fn push(&mut self, guess: Word, pattern: Pattern) {
+        self.history.push((guess, pattern));
+    }
+
+    /// Get the underlying guess history
+    #[must_use]
+    pub fn history(&self) -> &[(Word, Pattern)] {
+        &self.history
+    }
+
+    /// Check if a candidate answer is consistent with every observation
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Constraints, Pattern, Word};
+    ///
+    /// let guess = Word::new("crane").unwrap();
+    /// let answer = Word::new("slate").unwrap();
+    /// let pattern = Pattern::calculate(&guess, &answer);
+    ///
+    /// let constraints = Constraints::new(vec![(guess, pattern)]);
+    /// assert!(constraints.allows(&answer));
+    /// assert!(!constraints.allows(&Word::new("crane").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn allows(&self, candidate: &Word) -> bool {
+        candidate
+            .chars()
+            .iter()
+            .zip(&self.classes)
+            .all(|(&letter, class)| class.contains(letter))
+            && self
+                .history
+                .iter()
+                .all(|(guess, pattern)| Pattern::calculate(guess, candidate) == *pattern)
+    }
+
+    /// Check if `guess` is legal in hard mode after this feedback
+    ///
+    /// Hard mode requires every green letter to be played in the same position
+    /// again, and every revealed letter (green or yellow) to be used at least
+    /// as many times as the feedback revealed it. A yellow letter may be played
+    /// in a position it was already tried in.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Constraints, Pattern, Word};
+    ///
+    /// // CRANE against GRATE: R, A and E are green
+    /// let guess = Word::new("crane").unwrap();
+    /// let pattern = Pattern::calculate(&guess, &Word::new("grate").unwrap());
+    /// let constraints = Constraints::new(vec![(guess, pattern)]);
+    ///
+    /// assert!(constraints.hard_mode_allows(&Word::new("trace").unwrap()));
+    /// assert!(!constraints.hard_mode_allows(&Word::new("slate").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn hard_mode_allows(&self, guess: &Word) -> bool {
+        self.history.iter().all(|(previous, pattern)| {
+            let digits = pattern.digits();
+            (0..5).all(|i| {
+                let letter = previous.char_at(i);
+                match digits[i] {
+                    0 => true,
+                    2 if guess.char_at(i) != letter => false,
+                    _ => {
+                        let revealed = previous
+                            .positions_of(letter)
+                            .iter()
+                            .filter(|&&j| digits[j] != 0)
+                            .count();
+                        guess.positions_of(letter).len() >= revealed
+                    }
+                }
+            })
+        })
+    }
+
+    /// Summarize what the feedback reveals about `letter` (lowercase ASCII)
+    ///
+    /// With no feedback on the letter, the count ranges over 0..=5 and no
+    /// positions are known.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Constraints, Pattern, Word};
+    ///
+    /// // Two Es guessed, one yellow and one grey: exactly one E
+    /// let guess = Word::new("there").unwrap();
+    /// let answer = Word::new("crane").unwrap();
+    /// let constraints = Constraints::new(vec![(guess.clone(), Pattern::calculate(&guess, &answer))]);
+    ///
+    /// let clue = constraints.letter_clue(b'e');
+    /// assert!(clue.is_exact());
+    /// assert_eq!((clue.min, clue.max), (1, 1));
+    /// ```
+    #[must_use]
+    pub fn letter_clue(&self, letter: u8) -> LetterClue {
+        let mut clue = LetterClue {
+            min: 0,
+            max: 5,
+            known: Vec::new(),
+            excluded: Vec::new(),
+        };
+
+        for (guess, pattern) in &self.history {
+            let digits = pattern.digits();
+            let mut shown = 0;
+            let mut greyed = false;
+            for &i in guess.positions_of(letter) {
+                match digits[i] {
+                    2 => {
+                        shown += 1;
+                        if !clue.known.contains(&i) {
+                            clue.known.push(i);
+                        }
+                    }
+                    digit => {
+                        if digit == 1 {
+                            shown += 1;
+                        } else {
+                            greyed = true;
+                        }
+                        if !clue.excluded.contains(&i) {
+                            clue.excluded.push(i);
+                        }
+                    }
+                }
+            }
+            clue.min = clue.min.max(shown);
+            if greyed {
+                clue.max = clue.max.min(shown);
+            }
+        }
+
+        // Greens for the letter at different slots, even from different
+        // guesses, are each a separate copy
+        clue.min = clue
+            .min
+            .max(u8::try_from(clue.known.len()).unwrap_or(u8::MAX));
+        clue.known.sort_unstable();
+        clue.excluded.sort_unstable();
+        clue
+    }
+
+    /// Letters (lowercase ASCII) the feedback proves are not in the answer
+    ///
+    /// A letter is absent once a grey tile caps its count at zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Constraints, Pattern, Word};
+    ///
+    /// let guess = Word::new("crane").unwrap();
+    /// let pattern = Pattern::calculate(&guess, &Word::new("slate").unwrap());
+    /// let constraints = Constraints::new(vec![(guess, pattern)]);
+    ///
+    /// assert_eq!(constraints.absent_letters(), b"cnr");
+    /// ```
+    #[must_use]
+    pub fn absent_letters(&self) -> Vec<u8> {
+        (b'a'..=b'z')
+            .filter(|&letter| self.letter_clue(letter).max == 0)
+            .collect()
+    }
+}
+
+impl From<Vec<(Word, Pattern)>> for Constraints {
+    fn from(history: Vec<(Word, Pattern)>) -> Self {
+        Self::new(history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_constraints_allow_everything() {
+        let constraints = Constraints::default();
+        assert!(constraints.allows(&Word::new("crane").unwrap()));
+        assert!(constraints.history().is_empty());
+    }
+
+    #[test]
+    fn push_narrows_allowed_words() {
+        let answer = Word::new("grate").unwrap();
+        let other = Word::new("irate").unwrap();
+        let mut constraints = Constraints::default();
+
+        let guess = Word::new("crane").unwrap();
+        constraints.push(guess.clone(), Pattern::calculate(&guess, &answer));
+        assert!(constraints.allows(&answer));
+        assert!(constraints.allows(&other)); // CRANE can't tell GRATE from IRATE
+
+        let guess = Word::new("igloo").unwrap();
+        constraints.push(guess.clone(), Pattern::calculate(&guess, &answer));
+        assert!(constraints.allows(&answer));
+        assert!(!constraints.allows(&other));
+    }
+
+    #[test]
+    fn letter_classes_compose_with_feedback() {
+        let guess = Word::new("crane").unwrap();
+        let answer = Word::new("those").unwrap();
+        let mut constraints =
+            Constraints::new(vec![(guess.clone(), Pattern::calculate(&guess, &answer))]);
+        let other = Word::new("exist").unwrap();
+        assert!(constraints.allows(&answer));
+
+        constraints.restrict(1, LetterClass::CONSONANTS);
+        assert!(constraints.allows(&answer));
+        assert!(!constraints.allows(&Word::new("boule").unwrap()));
+        assert!(!constraints.allows(&other)); // still fails the feedback
+
+        // Restrictions intersect
+        constraints.restrict(1, LetterClass::parse("hst").unwrap());
+        constraints.restrict(1, LetterClass::parse("h").unwrap());
+        assert_eq!(constraints.class_at(1), LetterClass::from_letters(b"h"));
+        constraints.restrict(1, LetterClass::VOWELS);
+        assert!(constraints.class_at(1).is_empty());
+        assert!(!constraints.allows(&answer));
+    }
+
+    #[test]
+    fn letter_class_parsing() {
+        assert_eq!(
+            LetterClass::parse("Consonants"),
+            Some(LetterClass::CONSONANTS)
+        );
+        assert_eq!(LetterClass::VOWELS.complement(), LetterClass::CONSONANTS);
+        assert_eq!(LetterClass::default(), LetterClass::ANY);
+        for bad in ["", "a1", "vow el"] {
+            assert!(LetterClass::parse(bad).is_none(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn hard_mode_requires_greens_and_revealed_letters() {
+        let hard = |guess: &str, answer: &str, next: &str| {
+            let guess = Word::new(guess).unwrap();
+            let pattern = Pattern::calculate(&guess, &Word::new(answer).unwrap());
+            Constraints::new(vec![(guess, pattern)]).hard_mode_allows(&Word::new(next).unwrap())
+        };
+
+        // CRANE vs REACT: A green, C R E yellow
+        assert!(hard("crane", "react", "trace"));
+        assert!(hard("crane", "react", "crane")); // yellows may stay put
+        assert!(!hard("crane", "react", "grate")); // drops C
+        assert!(!hard("crane", "react", "recut")); // moves the green A
+
+        // EERIE vs THERE: two Es revealed, so two must be played
+        assert!(hard("eerie", "there", "where"));
+        assert!(!hard("eerie", "there", "shire"));
+
+        assert!(Constraints::default().hard_mode_allows(&Word::new("fuzzy").unwrap()));
+    }
+
+    fn clue_for(guess: &str, answer: &str, letter: u8) -> LetterClue {
+        let guess = Word::new(guess).unwrap();
+        let answer = Word::new(answer).unwrap();
+        let pattern = Pattern::calculate(&guess, &answer);
+        Constraints::new(vec![(guess, pattern)]).letter_clue(letter)
+    }
+
+    #[test]
+    fn letter_clue_single_yellow_is_lower_bound() {
+        let clue = clue_for("table", "crane", b'a');
+        assert_eq!((clue.min, clue.max), (1, 5));
+        assert!(!clue.is_exact());
+        assert_eq!(clue.excluded, vec![1]);
+    }
+
+    #[test]
+    fn letter_clue_double_letter_reveals_exact_count() {
+        // EERIE vs CRANE: one E is green at slot 5, the others grey
+        let clue = clue_for("eerie", "crane", b'e');
+        assert_eq!((clue.min, clue.max), (1, 1));
+        assert_eq!(clue.known, vec![4]);
+        assert_eq!(clue.excluded, vec![0, 1]);
+    }
+
+    #[test]
+    fn letter_clue_grey_only_means_absent() {
+        let clue = clue_for("slate", "crane", b's');
+        assert_eq!((clue.min, clue.max), (0, 0));
+    }
+
+    #[test]
+    fn letter_clue_combines_guesses() {
+        let answer = Word::new("crane").unwrap();
+        let mut constraints = Constraints::default();
+
+        let guess = Word::new("table").unwrap();
+        constraints.push(guess.clone(), Pattern::calculate(&guess, &answer));
+        assert!(!constraints.letter_clue(b'e').is_exact());
+
+        let guess = Word::new("eerie").unwrap();
+        constraints.push(guess.clone(), Pattern::calculate(&guess, &answer));
+        let clue = constraints.letter_clue(b'e');
+        assert_eq!((clue.min, clue.max), (1, 1));
+        assert_eq!(clue.known, vec![4]);
+
+        assert_eq!(constraints.absent_letters(), b"bilt");
+
+        // GEESE: one E green in BEACH, another in SPITE, so at least two
+        let answer = Word::new("geese").unwrap();
+        let mut constraints = Constraints::default();
+        for guess in ["beach", "spite"] {
+            let guess = Word::new(guess).unwrap();
+            constraints.push(guess.clone(), Pattern::calculate(&guess, &answer));
+        }
+        let clue = constraints.letter_clue(b'e');
+        assert_eq!(clue.known, vec![1, 4]);
+        assert_eq!((clue.min, clue.max), (2, 5));
+        assert_eq!(
+            Constraints::default().letter_clue(b'e'),
+            LetterClue {
+                min: 0,
+                max: 5,
+                known: vec![],
+                excluded: vec![],
+            }
+        );
+    }
+}
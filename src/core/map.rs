@@ -0,0 +1,12 @@
+//! Hash map types usable with or without `std`
+//!
+//! With `std` these are the standard library map, so they interoperate with
+//! `rustc_hash::FxHashMap`; without it they come from `hashbrown`.
+
+#[cfg(not(feature = "std"))]
+pub use hashbrown::HashMap;
+#[cfg(feature = "std")]
+pub use std::collections::HashMap;
+
+/// `HashMap` with the Fx hasher
+pub type FxHashMap<K, V> = HashMap<K, V, rustc_hash::FxBuildHasher>;
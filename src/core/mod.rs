@@ -1,7 +1,13 @@
-//! Core domain types (Word, Pattern)
+//! Core domain types (Word, Pattern, Constraints, `WordIndex`)
 
+mod constraints;
+mod map;
 mod pattern;
 mod word;
+mod word_index;
 
-pub use pattern::Pattern;
-pub use word::Word;
+pub use constraints::{Constraints, LetterClass, LetterClue};
+pub use map::{FxHashMap, HashMap};
+pub use pattern::{Pattern, PatternError};
+pub use word::{Word, WordError, fold_accents};
+pub use word_index::WordIndex;
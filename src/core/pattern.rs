@@ -8,15 +8,47 @@
 //! The pattern is stored as a single u8 value (0-242), where each position
 //! contributes digit × 3^position to the total.
 
-use super::Word;
+use super::{FxHashMap, Word};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
 
 /// Feedback pattern for a Wordle guess
 ///
 /// Represents the colored feedback as a single byte value.
 /// Value range: 0-242 (3^5 - 1 = 243 possible patterns)
+///
+/// Patterns are ordered from most to least informative-looking: more greens
+/// first, then more yellows, then by raw value. The raw value breaks ties, so
+/// the order is total and consistent with `Eq`, and [`Pattern::PERFECT`] is
+/// the smallest pattern.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Pattern(u8);
 
+/// Error type for invalid position-list patterns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    PositionOutOfRange(usize),
+    OverlappingPosition(usize),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PositionOutOfRange(pos) => {
+                write!(f, "Position must be 0-4, got {pos}")
+            }
+            Self::OverlappingPosition(pos) => {
+                write!(f, "Position {pos} is listed more than once")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PatternError {}
+
 impl Pattern {
     /// All greens (perfect match)
     pub const PERFECT: Self = Self(242); // 2 + 2×3 + 2×9 + 2×27 + 2×81
@@ -115,6 +147,52 @@ impl Pattern {
         Self(pattern)
     }
 
+    /// Group candidates by the pattern they produce with the guess
+    ///
+    /// Returns the number of candidates behind each pattern, i.e. the
+    /// partition sizes the guess splits the candidates into.
+    #[must_use]
+    pub fn partition(guess: &Word, candidates: &[&Word]) -> FxHashMap<Self, usize> {
+        let mut counts = FxHashMap::default();
+
+        for &candidate in candidates {
+            let pattern = Self::calculate(guess, candidate);
+            *counts.entry(pattern).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    /// The `n` most populous pattern groups `guess` splits the candidates into
+    ///
+    /// Groups are sorted by size, largest first, so the first is the minimax
+    /// worst case. Equal sizes follow pattern order (more greens, then more
+    /// yellows, first), so the result is the same every run.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Pattern, Word};
+    ///
+    /// let guess = Word::new("crane").unwrap();
+    /// let candidates: Vec<Word> = ["grate", "irate", "slate", "pious"]
+    ///     .iter()
+    ///     .map(|w| Word::new(*w).unwrap())
+    ///     .collect();
+    /// let refs: Vec<&Word> = candidates.iter().collect();
+    ///
+    /// // GRATE and IRATE both give -GG-G
+    /// let groups = Pattern::largest_groups(&guess, &refs, 1);
+    /// assert_eq!(groups, vec![(Pattern::from_str("-GG-G").unwrap(), 2)]);
+    /// ```
+    #[must_use]
+    pub fn largest_groups(guess: &Word, candidates: &[&Word], n: usize) -> Vec<(Self, usize)> {
+        let mut groups: Vec<(Self, usize)> =
+            Self::partition(guess, candidates).into_iter().collect();
+        groups.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        groups.truncate(n);
+        groups
+    }
+
     /// Count the number of green feedback squares
     #[must_use]
     pub fn count_greens(self) -> u8 {
@@ -147,12 +225,181 @@ impl Pattern {
         count
     }
 
+    /// Check whether any answer could produce `pattern` when `guess` is guessed
+    ///
+    /// Every value 0-242 is a valid encoding, but some are impossible for a
+    /// given guess (e.g. a yellow after a gray for the same letter, or a
+    /// yellow letter with nowhere left to go).
+    ///
+    /// # Algorithm
+    /// Greens are fixed. Each other position can only hold one of the guess's
+    /// yellow letters (where it differs from the guessed letter) or a filler
+    /// letter absent from the guess - any achievable pattern has an answer of
+    /// that shape. All such answers are tried (at most 6^5).
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Pattern, Word};
+    ///
+    /// let guess = Word::new("speed").unwrap();
+    /// assert!(Pattern::is_achievable(&guess, Pattern::from_str("--YY-").unwrap()));
+    ///
+    /// // Second E can't be yellow when the first E is gray
+    /// assert!(!Pattern::is_achievable(&guess, Pattern::from_str("---Y-").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn is_achievable(guess: &Word, pattern: Self) -> bool {
+        let digits = pattern.digits();
+        let letters = guess.chars();
+
+        let Some(filler) = (b'a'..=b'z').find(|&ch| !guess.has_letter(ch)) else {
+            return false;
+        };
+
+        let yellow_letters: Vec<u8> = (0..5)
+            .filter(|&i| digits[i] == 1)
+            .map(|i| letters[i])
+            .collect();
+
+        // Letters each position may hold in a candidate answer
+        let options: Vec<Vec<u8>> = (0..5)
+            .map(|i| {
+                if digits[i] == 2 {
+                    vec![letters[i]]
+                } else {
+                    let mut opts: Vec<u8> = yellow_letters
+                        .iter()
+                        .copied()
+                        .filter(|&ch| ch != letters[i])
+                        .collect();
+                    opts.sort_unstable();
+                    opts.dedup();
+                    opts.push(filler);
+                    opts
+                }
+            })
+            .collect();
+
+        let mut answer = [0u8; 5];
+        achievable_from(guess, pattern, &options, &mut answer, 0)
+    }
+
+    /// Decode the pattern into per-position digits (0=gray, 1=yellow, 2=green)
+    pub(crate) const fn digits(self) -> [u8; 5] {
+        let mut digits = [0u8; 5];
+        let mut val = self.0;
+        let mut i = 0;
+
+        while i < 5 {
+            digits[i] = val % 3;
+            val /= 3;
+            i += 1;
+        }
+
+        digits
+    }
+
+    /// Build a pattern from explicit green and yellow positions (0-4)
+    ///
+    /// Positions not listed are gray. An alternative to `from_str` for
+    /// button or voice interfaces.
+    ///
+    /// # Errors
+    /// Returns `PatternError` if a position is >= 5 or appears more than once
+    /// (within or across the two lists).
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::Pattern;
+    ///
+    /// let p = Pattern::from_positions(&[0, 3], &[1, 4]).unwrap();
+    /// assert_eq!(p, Pattern::from_str("GY-GY").unwrap());
+    ///
+    /// assert!(Pattern::from_positions(&[0], &[0]).is_err());
+    /// assert!(Pattern::from_positions(&[5], &[]).is_err());
+    /// ```
+    pub fn from_positions(greens: &[usize], yellows: &[usize]) -> Result<Self, PatternError> {
+        let mut digits = [0u8; 5];
+        let mut seen = [false; 5];
+
+        let marked = greens
+            .iter()
+            .map(|&pos| (pos, 2))
+            .chain(yellows.iter().map(|&pos| (pos, 1)));
+        for (pos, digit) in marked {
+            if pos >= 5 {
+                return Err(PatternError::PositionOutOfRange(pos));
+            }
+            if seen[pos] {
+                return Err(PatternError::OverlappingPosition(pos));
+            }
+            seen[pos] = true;
+            digits[pos] = digit;
+        }
+
+        Ok(Self::from_digits(digits))
+    }
+
+    /// Get the green and yellow positions, each in ascending order
+    ///
+    /// Inverse of `from_positions`.
+    #[must_use]
+    pub fn positions(self) -> (Vec<usize>, Vec<usize>) {
+        let digits = self.digits();
+        let greens = (0..5).filter(|&i| digits[i] == 2).collect();
+        let yellows = (0..5).filter(|&i| digits[i] == 1).collect();
+        (greens, yellows)
+    }
+
+    /// The same pattern with one position's feedback hidden (shown as gray)
+    ///
+    /// Patterns that differ only at `position` mask to the same value, so
+    /// partitioning by masked patterns ignores what that tile revealed.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::Pattern;
+    ///
+    /// let a = Pattern::from_str("GY-G-").unwrap();
+    /// let b = Pattern::from_str("G--G-").unwrap();
+    /// assert_eq!(a.masked(1), b.masked(1));
+    /// assert_eq!(a.masked(1), b);
+    /// ```
+    #[must_use]
+    pub const fn masked(self, position: usize) -> Self {
+        let mut digits = self.digits();
+        digits[position] = 0;
+        Self::from_digits(digits)
+    }
+
+    /// Encode per-position digits (0=gray, 1=yellow, 2=green) as a pattern
+    const fn from_digits(digits: [u8; 5]) -> Self {
+        let mut pattern = 0u8;
+        let mut multiplier = 1u8;
+        let mut i = 0;
+
+        while i < 5 {
+            pattern += digits[i] * multiplier;
+            multiplier *= 3;
+            i += 1;
+        }
+
+        Self(pattern)
+    }
+
     /// Parse a pattern from a string like "GYGGY" or "🟩🟨🟩🟩🟨"
     ///
     /// Accepts:
-    /// - 'G'/'g'/🟩 for green
-    /// - 'Y'/'y'/🟨 for yellow
-    /// - '-'/'_'/⬜ for gray
+    /// - 'G'/'g'/'2'/🟩 for green
+    /// - 'Y'/'y'/'1'/🟨 for yellow
+    /// - '-'/'_'/'B'/'b'/'X'/'x'/'0'/⬜/⬛ for gray (⬛ is used by dark-mode
+    ///   share grids; B, X and the digits follow other Wordle tools)
+    ///
+    /// Each character has one meaning, so conventions can even be mixed.
+    ///
+    /// Variation selectors and zero-width code points are stripped before
+    /// matching, so pasted share grids parse regardless of how the platform
+    /// encoded the squares.
     ///
     /// # Examples
     /// ```
@@ -161,11 +408,13 @@ impl Pattern {
     /// let p1 = Pattern::from_str("GY-GY").unwrap();
     /// let p2 = Pattern::from_str("🟩🟨⬜🟩🟨").unwrap();
     /// assert_eq!(p1, p2);
+    /// assert_eq!(Pattern::from_str("GYBGY"), Some(p1));
+    /// assert_eq!(Pattern::from_str("21021"), Some(p1));
     /// ```
     #[must_use]
     #[allow(clippy::should_implement_trait)] // Provides ergonomic Option API; FromStr trait also implemented below
     pub fn from_str(s: &str) -> Option<Self> {
-        let chars: Vec<char> = s.chars().collect();
+        let chars: Vec<char> = s.chars().filter(|&ch| !is_invisible(ch)).collect();
 
         if chars.len() != 5 {
             return None;
@@ -176,9 +425,9 @@ impl Pattern {
 
         for ch in chars {
             let digit = match ch {
-                'G' | 'g' | '🟩' => 2,
-                'Y' | 'y' | '🟨' => 1,
-                '-' | '_' | '⬜' => 0,
+                'G' | 'g' | '2' | '🟩' => 2,
+                'Y' | 'y' | '1' | '🟨' => 1,
+                '-' | '_' | 'B' | 'b' | 'X' | 'x' | '0' | '⬜' | '⬛' => 0,
                 _ => return None,
             };
             pattern += digit * multiplier;
@@ -201,24 +450,79 @@ impl Pattern {
     /// ```
     #[must_use]
     pub fn to_emoji(self) -> String {
-        let mut result = String::with_capacity(10); // 2 bytes per emoji
-        let mut val = self.0;
+        self.to_emoji_with(['⬜', '🟨', '🟩'])
+    }
 
-        for _ in 0..5 {
-            let digit = val % 3;
-            result.push(match digit {
-                2 => '🟩', // Green
-                1 => '🟨', // Yellow
-                _ => '⬜', // Gray
-            });
-            val /= 3;
-        }
+    /// Convert pattern to a string using custom symbols
+    ///
+    /// `symbols` is indexed by feedback value: `[gray, yellow, green]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::Pattern;
+    ///
+    /// let p = Pattern::from_str("GY-GY").unwrap();
+    /// assert_eq!(p.to_emoji_with(['⬜', '🟧', '🟦']), "🟦🟧⬜🟦🟧");
+    /// ```
+    #[must_use]
+    pub fn to_emoji_with(self, symbols: [char; 3]) -> String {
+        self.digits()
+            .iter()
+            .map(|&digit| symbols[digit as usize])
+            .collect()
+    }
+}
+
+/// Depth-first search over candidate answers for `Pattern::is_achievable`
+fn achievable_from(
+    guess: &Word,
+    pattern: Pattern,
+    options: &[Vec<u8>],
+    answer: &mut [u8; 5],
+    position: usize,
+) -> bool {
+    if position == 5 {
+        return Word::from_bytes(*answer)
+            .is_ok_and(|word| Pattern::calculate(guess, &word) == pattern);
+    }
+
+    options[position].iter().any(|&ch| {
+        answer[position] = ch;
+        achievable_from(guess, pattern, options, answer, position + 1)
+    })
+}
+
+/// Check if a code point is a variation selector or zero-width character
+///
+/// These carry no feedback information but commonly ride along with
+/// copy-pasted emoji, so they must not count toward the 5-square length.
+const fn is_invisible(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{FE00}'..='\u{FE0F}' // Variation selectors (VS1-VS16)
+            | '\u{200B}'..='\u{200D}' // Zero-width space / non-joiner / joiner
+            | '\u{2060}' // Word joiner
+            | '\u{FEFF}' // Zero-width no-break space (BOM)
+    )
+}
 
-        result
+impl Ord for Pattern {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        other
+            .count_greens()
+            .cmp(&self.count_greens())
+            .then_with(|| other.count_yellows().cmp(&self.count_yellows()))
+            .then_with(|| self.0.cmp(&other.0))
     }
 }
 
-impl std::str::FromStr for Pattern {
+impl PartialOrd for Pattern {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::str::FromStr for Pattern {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -305,14 +609,145 @@ mod tests {
         assert_eq!(p1.value(), 23);
     }
 
+    #[test]
+    fn pattern_from_str_dark_mode() {
+        let light = Pattern::from_str("🟩🟨⬜⬜🟩").unwrap();
+        let dark = Pattern::from_str("🟩🟨⬛⬛🟩").unwrap();
+        assert_eq!(light, dark);
+    }
+
+    #[test]
+    fn pattern_from_str_variation_selectors() {
+        let plain = Pattern::from_str("🟩🟨⬜⬛🟩").unwrap();
+        let with_selectors =
+            Pattern::from_str("🟩\u{FE0F}🟨\u{FE0F}⬜\u{FE0F}⬛\u{FE0F}🟩\u{FE0F}").unwrap();
+        let partial_selectors = Pattern::from_str("🟩🟨\u{FE0F}⬜⬛\u{FE0E}🟩").unwrap();
+
+        assert_eq!(plain, with_selectors);
+        assert_eq!(plain, partial_selectors);
+    }
+
+    #[test]
+    fn pattern_from_str_zero_width() {
+        let plain = Pattern::from_str("GY-GY").unwrap();
+        let with_zero_width = Pattern::from_str("\u{FEFF}G\u{200B}Y\u{200D}-GY\u{2060}").unwrap();
+        assert_eq!(plain, with_zero_width);
+
+        // Invisible characters alone never make up the length
+        assert!(Pattern::from_str("GY\u{FE0F}\u{FE0F}\u{FE0F}").is_none());
+    }
+
+    #[test]
+    fn pattern_from_str_alternative_conventions() {
+        let expected = Pattern::from_str("GY-GY").unwrap();
+        for input in [
+            "gy-gy", "GY_GY", "GYBGY", "GYbGY", "GYXGY", "GYxGY", "21021", "GY0GY", "2Y-G1",
+        ] {
+            assert_eq!(Pattern::from_str(input), Some(expected), "{input}");
+        }
+
+        assert_eq!(Pattern::from_str("BBBBB"), Some(Pattern::new(0)));
+        assert_eq!(Pattern::from_str("xxxxx"), Some(Pattern::new(0)));
+        assert_eq!(Pattern::from_str("00000"), Some(Pattern::new(0)));
+        assert_eq!(Pattern::from_str("11111"), Pattern::from_str("YYYYY"));
+        assert_eq!(Pattern::from_str("22222"), Some(Pattern::PERFECT));
+        // G always means green, never gray
+        assert_eq!(Pattern::from_str("GGGGG"), Some(Pattern::PERFECT));
+    }
+
     #[test]
     fn pattern_from_str_invalid() {
         assert!(Pattern::from_str("GYGGYX").is_none()); // Too long (6 chars)
         assert!(Pattern::from_str("GYG").is_none()); // Too short
-        assert!(Pattern::from_str("GXGGY").is_none()); // Invalid char
+        assert!(Pattern::from_str("GZGGY").is_none()); // Invalid char
+        assert!(Pattern::from_str("GY3GY").is_none()); // Digits stop at 2
+        assert!(Pattern::from_str("GY GY").is_none()); // Space
         assert!(Pattern::from_str("").is_none()); // Empty
     }
 
+    #[test]
+    fn pattern_achievable_for_real_answers() {
+        let guess = Word::new("speed").unwrap();
+        for answer in ["erase", "steep", "abide", "speed", "creep", "deeps"] {
+            let pattern = Pattern::calculate(&guess, &Word::new(answer).unwrap());
+            assert!(Pattern::is_achievable(&guess, pattern), "{answer}");
+        }
+    }
+
+    #[test]
+    fn pattern_not_achievable() {
+        let speed = Word::new("speed").unwrap();
+        // Yellow E after a gray E
+        assert!(!Pattern::is_achievable(
+            &speed,
+            Pattern::from_str("--_Y-").unwrap()
+        ));
+
+        // All yellow with a repeated letter: E has no free spot left
+        let geese = Word::new("geese").unwrap();
+        assert!(!Pattern::is_achievable(
+            &geese,
+            Pattern::from_str("YYYYY").unwrap()
+        ));
+
+        // Four greens plus a yellow: the yellow letter has nowhere to go
+        let crane = Word::new("crane").unwrap();
+        assert!(!Pattern::is_achievable(
+            &crane,
+            Pattern::from_str("GGGGY").unwrap()
+        ));
+    }
+
+    #[test]
+    fn pattern_achievable_exhaustive_count() {
+        // Every achievable pattern is produced by some answer; a word with
+        // five distinct letters rules out only the 4-green + 1-yellow patterns
+        let crane = Word::new("crane").unwrap();
+        let achievable = (0..243)
+            .filter(|&v| Pattern::is_achievable(&crane, Pattern::new(v)))
+            .count();
+        assert_eq!(achievable, 243 - 5);
+    }
+
+    #[test]
+    fn pattern_from_positions_valid() {
+        let p = Pattern::from_positions(&[0, 2], &[1]).unwrap();
+        assert_eq!(p, Pattern::from_str("GYG--").unwrap());
+        assert_eq!(Pattern::from_positions(&[], &[]).unwrap().value(), 0);
+        assert_eq!(
+            Pattern::from_positions(&[4, 3, 2, 1, 0], &[]).unwrap(),
+            Pattern::PERFECT
+        );
+    }
+
+    #[test]
+    fn pattern_from_positions_invalid() {
+        assert_eq!(
+            Pattern::from_positions(&[1], &[7]),
+            Err(PatternError::PositionOutOfRange(7))
+        );
+        assert_eq!(
+            Pattern::from_positions(&[2], &[2]),
+            Err(PatternError::OverlappingPosition(2))
+        );
+        assert_eq!(
+            Pattern::from_positions(&[3, 3], &[]),
+            Err(PatternError::OverlappingPosition(3))
+        );
+    }
+
+    #[test]
+    fn pattern_positions_round_trip() {
+        for value in 0..243 {
+            let pattern = Pattern::new(value);
+            let (greens, yellows) = pattern.positions();
+            let rebuilt = Pattern::from_positions(&greens, &yellows).unwrap();
+
+            assert_eq!(rebuilt, pattern);
+            assert_eq!(rebuilt.to_emoji(), pattern.to_emoji());
+        }
+    }
+
     #[test]
     fn pattern_count_feedback() {
         // Create pattern manually: YGGYY
@@ -347,4 +782,47 @@ mod tests {
         assert_eq!(pattern.count_greens(), 2); // A and E
         assert_eq!(pattern.count_yellows(), 0); // No yellows
     }
+
+    #[test]
+    fn ordering_greens_then_yellows_then_value() {
+        let mut patterns: Vec<Pattern> = (0..243).map(Pattern::new).collect();
+        patterns.sort();
+
+        assert_eq!(patterns[0], Pattern::PERFECT);
+        assert_eq!(*patterns.last().unwrap(), Pattern::new(0));
+        for pair in patterns.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert!(a < b, "order must be strict: {a:?} vs {b:?}");
+            assert!(a.count_greens() >= b.count_greens());
+            if a.count_greens() == b.count_greens() {
+                assert!(a.count_yellows() >= b.count_yellows());
+            }
+        }
+
+        // One green outranks any number of yellows
+        let green = Pattern::from_str("G----").unwrap();
+        let yellows = Pattern::from_str("YYYYY").unwrap();
+        assert!(green < yellows);
+    }
+
+    #[test]
+    fn largest_groups_sorted_by_size_then_pattern() {
+        let guess = Word::new("salet").unwrap();
+        let candidates: Vec<Word> = ["crane", "brine", "drone", "pious", "cigar", "shout"]
+            .iter()
+            .map(|w| Word::new(*w).unwrap())
+            .collect();
+        let refs: Vec<&Word> = candidates.iter().collect();
+
+        let all = Pattern::largest_groups(&guess, &refs, usize::MAX);
+        assert_eq!(all.iter().map(|&(_, n)| n).sum::<usize>(), candidates.len());
+        assert_eq!(all.len(), Pattern::partition(&guess, &refs).len());
+        for pair in all.windows(2) {
+            let ((p1, n1), (p2, n2)) = (pair[0], pair[1]);
+            assert!(n1 > n2 || (n1 == n2 && p1 < p2));
+        }
+
+        assert_eq!(Pattern::largest_groups(&guess, &refs, 2), all[..2]);
+        assert!(Pattern::largest_groups(&guess, &[], 3).is_empty());
+    }
 }
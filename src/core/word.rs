@@ -2,25 +2,45 @@
 //!
 //! A Word stores a 5-letter word along with letter position indices for pattern calculation.
 
-use rustc_hash::FxHashMap;
-use std::fmt;
+use super::FxHashMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
 
 /// A 5-letter Wordle word with letter position tracking
 ///
 /// Stores the word as bytes and maintains a map of letter positions for duplicate handling.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Equality compares the lowercase letters only, so a word keeps comparing
+/// equal to its lowercase form even when [`Word::new_preserving_case`]
+/// retained different original casing for display.
+#[derive(Debug, Clone)]
 pub struct Word {
     text: String,
     chars: [u8; 5],
     char_positions: FxHashMap<u8, Vec<usize>>,
+    /// Original casing, kept only when it differs from `text`
+    display: Option<String>,
 }
 
+impl PartialEq for Word {
+    fn eq(&self, other: &Self) -> bool {
+        self.chars == other.chars
+    }
+}
+
+impl Eq for Word {}
+
 /// Error type for invalid words
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WordError {
     InvalidLength(usize),
     NonAscii,
-    InvalidCharacters,
+    /// The first non-letter character and its index in the word
+    InvalidCharacters {
+        ch: char,
+        index: usize,
+    },
 }
 
 impl fmt::Display for WordError {
@@ -30,21 +50,87 @@ impl fmt::Display for WordError {
                 write!(f, "Word must be exactly 5 letters, got {len}")
             }
             Self::NonAscii => write!(f, "Word must contain only ASCII letters"),
-            Self::InvalidCharacters => write!(f, "Word contains invalid characters"),
+            Self::InvalidCharacters { ch, index } => match character_name(*ch) {
+                Some(name) => write!(f, "Word contains {name} at index {index}"),
+                None => write!(f, "Word contains invalid character '{ch}' at index {index}"),
+            },
         }
     }
 }
 
-impl std::error::Error for WordError {}
+/// Readable name for punctuation commonly found in word lists
+const fn character_name(ch: char) -> Option<&'static str> {
+    match ch {
+        '\'' | '’' => Some("apostrophe"),
+        '-' => Some("hyphen"),
+        ' ' => Some("space"),
+        '.' => Some("period"),
+        '0'..='9' => Some("digit"),
+        _ => None,
+    }
+}
+
+impl core::error::Error for WordError {}
+
+/// Lowercase `text` and strip diacritics from Latin letters
+///
+/// Precomposed letters from Latin-1 and Latin Extended-A map to their base
+/// letter (é→e, ñ→n, ł→l), and combining marks (U+0300-U+036F) are dropped,
+/// so decomposed input folds the same way. The mapping is a fixed table, so
+/// the result never depends on the platform or locale. Everything else,
+/// including letters like ß or æ that have no single-letter base, is left for
+/// validation to reject.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::core::fold_accents;
+///
+/// assert_eq!(fold_accents("Señor"), "senor");
+/// assert_eq!(fold_accents("cafe\u{301}s"), "cafes");
+/// ```
+#[must_use]
+pub fn fold_accents(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|ch| !('\u{300}'..='\u{36f}').contains(ch))
+        .map(|ch| base_letter(ch).unwrap_or(ch))
+        .collect()
+}
+
+/// ASCII base letter of a lowercase accented Latin letter
+const fn base_letter(ch: char) -> Option<char> {
+    Some(match ch {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ĥ' | 'ħ' => 'h',
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ĵ' => 'j',
+        'ķ' => 'k',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ŵ' => 'w',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        _ => return None,
+    })
+}
 
 impl Word {
     /// Create a new Word from a string
     ///
     /// # Errors
-    /// Returns `WordError` if:
-    /// - Length is not exactly 5
+    /// Returns `WordError` if, checked in this order:
     /// - Contains non-ASCII characters
     /// - Contains non-alphabetic characters
+    /// - Length is not exactly 5
     ///
     /// # Examples
     /// ```
@@ -62,18 +148,23 @@ impl Word {
     pub fn new(text: impl Into<String>) -> Result<Self, WordError> {
         let text: String = text.into().to_lowercase();
 
-        // Validate length
-        if text.len() != 5 {
-            return Err(WordError::InvalidLength(text.len()));
-        }
-
-        // Validate ASCII and alphabetic
+        // Validate ASCII and alphabetic before length, so an entry like
+        // "it's" reports its apostrophe rather than being one letter short
         if !text.is_ascii() {
             return Err(WordError::NonAscii);
         }
 
-        if !text.chars().all(|c| c.is_ascii_lowercase()) {
-            return Err(WordError::InvalidCharacters);
+        if let Some((index, ch)) = text
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_lowercase())
+        {
+            return Err(WordError::InvalidCharacters { ch, index });
+        }
+
+        // Validate length
+        if text.len() != 5 {
+            return Err(WordError::InvalidLength(text.len()));
         }
 
         // Convert to bytes - safe to unwrap as we validated length == 5
@@ -82,17 +173,110 @@ impl Word {
             .try_into()
             .expect("length already validated");
 
+        Ok(Self::from_validated(text, chars))
+    }
+
+    /// Create a new Word that remembers the casing it was written with
+    ///
+    /// Logic (equality, patterns, [`Word::text`]) still uses the lowercase
+    /// form; the original is only returned by [`Word::display_text`].
+    ///
+    /// # Errors
+    /// Returns `WordError` under the same conditions as [`Word::new`].
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::Word;
+    ///
+    /// let word = Word::new_preserving_case("Paris").unwrap();
+    /// assert_eq!(word.text(), "paris");
+    /// assert_eq!(word.display_text(), "Paris");
+    /// assert_eq!(word, Word::new("paris").unwrap());
+    /// ```
+    pub fn new_preserving_case(text: impl Into<String>) -> Result<Self, WordError> {
+        let original: String = text.into();
+        let mut word = Self::new(original.as_str())?;
+        if original != word.text {
+            word.display = Some(original);
+        }
+        Ok(word)
+    }
+
+    /// Create a new Word from input that may contain accented letters
+    ///
+    /// The input is folded to ASCII with [`fold_accents`] before the usual
+    /// validation, and the folded form is used for both logic and display.
+    /// Standard Wordle is ASCII-only, so nothing else folds accents; use this
+    /// for foreign-language lists or input.
+    ///
+    /// # Errors
+    /// Returns `WordError` under the same conditions as [`Word::new`], applied
+    /// to the folded text.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::Word;
+    ///
+    /// let word = Word::new_normalized("Piñón").unwrap();
+    /// assert_eq!(word.text(), "pinon");
+    /// assert_eq!(word.display_text(), "pinon");
+    /// assert!(Word::new("piñón").is_err());
+    /// ```
+    pub fn new_normalized(text: &str) -> Result<Self, WordError> {
+        Self::new(fold_accents(text))
+    }
+
+    /// Create a new Word from a fixed byte array
+    ///
+    /// Avoids the UTF-8 round-trip of `Word::new` for callers that already
+    /// have bytes. Uppercase ASCII letters are lowercased.
+    ///
+    /// # Errors
+    /// Returns `WordError` if:
+    /// - Any byte is non-ASCII
+    /// - Any byte is not an ASCII letter
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::Word;
+    ///
+    /// let word = Word::from_bytes(*b"CRANE").unwrap();
+    /// assert_eq!(word.text(), "crane");
+    ///
+    /// assert!(Word::from_bytes(*b"cr4ne").is_err());
+    /// ```
+    pub fn from_bytes(bytes: [u8; 5]) -> Result<Self, WordError> {
+        if !bytes.is_ascii() {
+            return Err(WordError::NonAscii);
+        }
+
+        if let Some(index) = bytes.iter().position(|b| !b.is_ascii_alphabetic()) {
+            return Err(WordError::InvalidCharacters {
+                ch: char::from(bytes[index]),
+                index,
+            });
+        }
+
+        let chars = bytes.map(|b| b.to_ascii_lowercase());
+        let text = chars.iter().map(|&b| char::from(b)).collect();
+
+        Ok(Self::from_validated(text, chars))
+    }
+
+    /// Build a Word from already-validated lowercase text and its bytes
+    fn from_validated(text: String, chars: [u8; 5]) -> Self {
         // Build position map for fast lookup
         let mut char_positions: FxHashMap<u8, Vec<usize>> = FxHashMap::default();
         for (i, &ch) in chars.iter().enumerate() {
             char_positions.entry(ch).or_default().push(i);
         }
 
-        Ok(Self {
+        Self {
             text,
             chars,
             char_positions,
-        })
+            display: None,
+        }
     }
 
     /// Get the word as a string slice
@@ -102,6 +286,16 @@ impl Word {
         &self.text
     }
 
+    /// Get the word as originally written, for display
+    ///
+    /// Same as [`Word::text`] unless the word was created with
+    /// [`Word::new_preserving_case`] from input that was not all lowercase.
+    #[inline]
+    #[must_use]
+    pub fn display_text(&self) -> &str {
+        self.display.as_deref().unwrap_or(&self.text)
+    }
+
     /// Get the word as a byte array
     #[inline]
     #[must_use]
@@ -131,9 +325,7 @@ impl Word {
     /// Returns an empty slice if the letter doesn't appear.
     #[inline]
     pub fn positions_of(&self, letter: u8) -> &[usize] {
-        self.char_positions
-            .get(&letter)
-            .map_or(&[], std::vec::Vec::as_slice)
+        self.char_positions.get(&letter).map_or(&[], Vec::as_slice)
     }
 
     /// Get the count of each letter in the word
@@ -175,11 +367,49 @@ mod tests {
         assert_eq!(word2.text(), "crane");
     }
 
+    #[test]
+    fn preserved_case_is_display_only() {
+        let word = Word::new_preserving_case("McCoy").unwrap();
+        assert_eq!(word.text(), "mccoy");
+        assert_eq!(word.display_text(), "McCoy");
+        assert_eq!(word, Word::new("MCCOY").unwrap());
+        assert_eq!(word.to_string(), "mccoy");
+
+        // Casing is only kept when it differs, and plain constructors drop it
+        assert_eq!(
+            Word::new_preserving_case("crane").unwrap().display_text(),
+            "crane"
+        );
+        assert_eq!(Word::new("McCoy").unwrap().display_text(), "mccoy");
+        assert!(Word::new_preserving_case("Mc-Coy").is_err());
+    }
+
+    #[test]
+    fn normalized_words_fold_accents() {
+        let plain = Word::new("cafes").unwrap();
+        for input in ["cafés", "CAFÉS", "cafe\u{301}s", "CAFE\u{301}S", "çâfès"] {
+            let word = Word::new_normalized(input).unwrap();
+            assert_eq!(word, plain, "{input}");
+            assert_eq!(word.display_text(), "cafes");
+        }
+        assert_eq!(
+            Word::new_normalized("Łódź!").unwrap_err(),
+            Word::new("lodz!").unwrap_err()
+        );
+        assert_eq!(Word::new_normalized("ñandú").unwrap().text(), "nandu");
+
+        // Letters without a single-letter base are still rejected
+        assert!(Word::new_normalized("straße").is_err());
+        assert!(Word::new_normalized("ærøes").is_err());
+        // Plain Word::new stays ASCII-only
+        assert_eq!(Word::new("cafés"), Err(WordError::NonAscii));
+    }
+
     #[test]
     fn word_creation_invalid_length() {
         assert!(matches!(
-            Word::new("too long"),
-            Err(WordError::InvalidLength(8))
+            Word::new("toolong"),
+            Err(WordError::InvalidLength(7))
         ));
         assert!(matches!(
             Word::new("shrt"),
@@ -195,6 +425,58 @@ mod tests {
         assert!(Word::new("cran!").is_err()); // Punctuation
     }
 
+    #[test]
+    fn word_invalid_characters_reports_first_offender() {
+        let err = Word::new("co-op").unwrap_err();
+        assert_eq!(err, WordError::InvalidCharacters { ch: '-', index: 2 });
+        assert_eq!(err.to_string(), "Word contains hyphen at index 2");
+
+        let err = Word::new("o'day").unwrap_err();
+        assert_eq!(err.to_string(), "Word contains apostrophe at index 1");
+
+        // Characters are checked before length
+        let err = Word::new("it's").unwrap_err();
+        assert_eq!(err, WordError::InvalidCharacters { ch: '\'', index: 2 });
+        assert_eq!(err.to_string(), "Word contains apostrophe at index 2");
+
+        let err = Word::new("cran!").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Word contains invalid character '!' at index 4"
+        );
+    }
+
+    #[test]
+    fn word_from_bytes_valid() {
+        let word = Word::from_bytes(*b"crane").unwrap();
+        assert_eq!(word.text(), "crane");
+        assert_eq!(word.chars(), b"crane");
+        assert_eq!(word, Word::new("crane").unwrap());
+    }
+
+    #[test]
+    fn word_from_bytes_uppercase_normalized() {
+        let word = Word::from_bytes(*b"CrAnE").unwrap();
+        assert_eq!(word.text(), "crane");
+        assert_eq!(word.positions_of(b'c'), &[0]);
+    }
+
+    #[test]
+    fn word_from_bytes_invalid() {
+        assert_eq!(
+            Word::from_bytes(*b"cran3"),
+            Err(WordError::InvalidCharacters { ch: '3', index: 4 })
+        );
+        assert_eq!(
+            Word::from_bytes(*b"cra e"),
+            Err(WordError::InvalidCharacters { ch: ' ', index: 3 })
+        );
+        assert_eq!(
+            Word::from_bytes([b'c', b'r', 0xC3, b'n', b'e']),
+            Err(WordError::NonAscii)
+        );
+    }
+
     #[test]
     fn word_char_at() {
         let word = Word::new("crane").unwrap();
@@ -0,0 +1,103 @@
+//! Word list membership index
+//!
+//! Hash-based lookup over a word list, replacing linear scans when the same
+//! list is queried repeatedly (validation, autocomplete, forced guesses).
+
+use super::FxHashMap;
+use super::Word;
+
+/// O(1) membership and lookup over a borrowed word list
+#[derive(Debug, Clone, Default)]
+pub struct WordIndex<'a> {
+    by_text: FxHashMap<&'a str, &'a Word>,
+}
+
+impl<'a> WordIndex<'a> {
+    /// Build an index over `words`
+    ///
+    /// If the list contains duplicates, lookups return the first occurrence.
+    #[must_use]
+    pub fn new(words: &'a [Word]) -> Self {
+        let mut by_text = FxHashMap::default();
+        for word in words {
+            by_text.entry(word.text()).or_insert(word);
+        }
+        Self { by_text }
+    }
+
+    /// Check if a word is in the indexed list
+    #[must_use]
+    pub fn contains(&self, word: &Word) -> bool {
+        self.by_text.contains_key(word.text())
+    }
+
+    /// Find the indexed word with the given text (case-insensitive)
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Word, WordIndex};
+    ///
+    /// let words = vec![Word::new("crane").unwrap(), Word::new("slate").unwrap()];
+    /// let index = WordIndex::new(&words);
+    ///
+    /// assert_eq!(index.find("SLATE"), Some(&words[1]));
+    /// assert_eq!(index.find("irate"), None);
+    /// ```
+    #[must_use]
+    pub fn find(&self, text: &str) -> Option<&'a Word> {
+        self.by_text.get(text.to_lowercase().as_str()).copied()
+    }
+
+    /// Number of distinct words in the index
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.by_text.len()
+    }
+
+    /// Check if the index is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.by_text.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words() -> Vec<Word> {
+        vec![
+            Word::new("crane").unwrap(),
+            Word::new("slate").unwrap(),
+            Word::new("crane").unwrap(),
+        ]
+    }
+
+    #[test]
+    fn contains_indexed_words_only() {
+        let words = words();
+        let index = WordIndex::new(&words);
+
+        assert!(index.contains(&Word::new("crane").unwrap()));
+        assert!(index.contains(&Word::new("slate").unwrap()));
+        assert!(!index.contains(&Word::new("irate").unwrap()));
+    }
+
+    #[test]
+    fn find_returns_first_occurrence() {
+        let words = words();
+        let index = WordIndex::new(&words);
+
+        let found = index.find("crane").unwrap();
+        assert!(std::ptr::eq(found, &raw const words[0]));
+        assert_eq!(index.find("Crane"), Some(&words[0]));
+        assert_eq!(index.find("cran"), None);
+    }
+
+    #[test]
+    fn len_counts_distinct_words() {
+        let words = words();
+        assert_eq!(WordIndex::new(&words).len(), 2);
+        assert!(WordIndex::new(&[]).is_empty());
+    }
+}
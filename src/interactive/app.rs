@@ -1,17 +1,24 @@
 //! TUI application state and logic
 
-use crate::core::{Pattern, Word};
-use crate::solver::entropy::calculate_metrics;
-use crate::solver::{AdaptiveStrategy, Solver};
+use super::theme::Theme;
+use crate::core::{Pattern, Word, WordIndex};
+use crate::output::formatters::{duplicate_letter_note, format_surprise, word_label};
+use crate::solver::entropy::{GuessMetrics, calculate_metrics};
+use crate::solver::{
+    AdaptiveStrategy, AdaptiveTier, AnswerWeight, CommitRule, GameStatus, MAX_GUESSES, Solver,
+    Strategy, expected_guesses,
+};
 use anyhow::Result;
 use crossterm::{
     event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+        KeyModifiers,
     },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
+use rayon::prelude::*;
 use std::io;
 
 /// State snapshot for undo functionality
@@ -25,10 +32,14 @@ pub struct StateSnapshot {
 pub struct App<'a> {
     pub solver: Solver<'a, AdaptiveStrategy>,
     pub all_words: &'a [Word],
+    pub word_index: WordIndex<'a>,
     pub answer_words: &'a [Word],
+    pub answer_index: WordIndex<'a>,
     pub mode: AppMode,
     pub history: Vec<HistoryEntry>,
     pub current_guess: Option<GuessInfo>,
+    /// The information-optimal suggestion, kept so it can be restored
+    pub safe_guess: Option<GuessInfo>,
     pub input_buffer: String,
     pub messages: Vec<Message>,
     pub stats: Statistics,
@@ -36,6 +47,7 @@ pub struct App<'a> {
     pub input_mode: InputMode,
     pub manual_word: String,
     pub undo_stack: Vec<StateSnapshot>,
+    pub theme: Theme,
 }
 
 #[derive(Debug, Clone)]
@@ -65,6 +77,35 @@ pub struct GuessInfo {
     pub entropy: f64,
     pub expected_remaining: f64,
     pub max_partition: usize,
+    /// Rough estimate of guesses left to finish, including this one
+    pub expected_guesses: f64,
+    /// Chance this guess is the answer (0 if it can't be)
+    pub win_probability: f64,
+    pub kind: GuessKind,
+}
+
+impl GuessInfo {
+    /// Describe what playing this guess is expected to achieve
+    #[must_use]
+    pub fn outcome(&self) -> String {
+        format!(
+            "{} wins now {:.0}% of the time, ≈{:.1} candidates remain on average",
+            self.word.to_uppercase(),
+            self.win_probability * 100.0,
+            self.expected_remaining
+        )
+    }
+}
+
+/// Where the current guess came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessKind {
+    /// The solver's information-optimal guess
+    Safe,
+    /// The most likely answer among the candidates
+    Risky,
+    /// A word the user entered
+    Manual,
 }
 
 #[derive(Debug, Clone)]
@@ -95,10 +136,13 @@ impl<'a> App<'a> {
         Self {
             solver,
             all_words,
+            word_index: WordIndex::new(all_words),
             answer_words,
+            answer_index: WordIndex::new(answer_words),
             mode: AppMode::Playing,
             history: Vec::new(),
             current_guess: None,
+            safe_guess: None,
             input_buffer: String::new(),
             messages: vec![
                 Message {
@@ -116,9 +160,55 @@ impl<'a> App<'a> {
             input_mode: InputMode::Feedback,
             manual_word: String::new(),
             undo_stack: Vec::new(),
+            theme: Theme::default(),
         }
     }
 
+    /// Open every game with `opener` (see [`Solver::with_opener`])
+    #[must_use]
+    pub fn with_opener(mut self, opener: Option<&'a Word>) -> Self {
+        self.solver = self.solver.with_opener(opener);
+        self
+    }
+
+    /// Guess the likeliest candidate outright when `rule` says it is
+    /// near-certain (see [`Solver::with_commit`])
+    #[must_use]
+    pub fn with_commit(mut self, rule: Option<CommitRule>) -> Self {
+        self.solver = self.solver.with_commit(rule);
+        self
+    }
+
+    /// Weight answers by likelihood, for the shown probabilities and the
+    /// commit rule (see [`Solver::with_answer_weight`])
+    #[must_use]
+    pub fn with_answer_weight(mut self, weight: Option<AnswerWeight<'a>>) -> Self {
+        self.solver = self.solver.with_answer_weight(weight);
+        self
+    }
+
+    /// Limit the entropy search in the 101+ candidate tier to the `width`
+    /// guesses with the best letter-frequency scores (`None`, the default,
+    /// searches every guess)
+    ///
+    /// Keeps early-game suggestions quick on slow machines; smaller
+    /// candidate sets, where optimality matters most, are always searched in
+    /// full.
+    #[must_use]
+    pub fn with_beam_width(mut self, width: Option<usize>) -> Self {
+        let mut strategy = self.solver.strategy().clone();
+        strategy.beam_width = width;
+        self.solver = self.solver.with_strategy(strategy);
+        self
+    }
+
+    /// Use the given color theme for feedback rendering
+    #[must_use]
+    pub const fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
     pub fn compute_suggestion(&mut self) {
         let guess = self.solver.next_guess(&self.get_history_for_solver());
 
@@ -129,19 +219,67 @@ impl<'a> App<'a> {
             // Calculate metrics
             let metrics = calculate_metrics(guess_word, &candidates);
 
-            self.current_guess = Some(GuessInfo {
-                word: guess_word.text().to_string(),
-                entropy: metrics.entropy,
-                expected_remaining: metrics.expected_remaining,
-                max_partition: metrics.max_partition,
-            });
+            let info = guess_info(
+                &self.solver,
+                guess_word,
+                metrics,
+                &candidates,
+                GuessKind::Safe,
+            );
+            self.safe_guess = Some(info.clone());
+            self.current_guess = Some(info);
+            if let Some(note) = duplicate_letter_note(guess_word) {
+                self.add_message(&note, MessageStyle::Info);
+            }
         } else {
             self.current_guess = None;
+            self.safe_guess = None;
             self.add_message("No valid guesses remaining!", MessageStyle::Error);
         }
     }
 
-    fn get_history_for_solver(&self) -> Vec<(Word, Pattern)> {
+    /// Show the most likely answer instead of the information-optimal guess
+    ///
+    /// Candidates equally likely to be the answer are ranked by the strategy,
+    /// so a miss still splits the rest as well as a candidate can.
+    pub fn show_risky_guess(&mut self) {
+        let candidates = self.solver.get_candidates(&self.get_history_for_solver());
+        let strategy = self.solver.strategy();
+        let scored: Vec<(&Word, f64, GuessMetrics)> = self
+            .solver
+            .candidate_probabilities(&candidates)
+            .into_par_iter()
+            .map(|(word, probability)| (word, probability, calculate_metrics(word, &candidates)))
+            .collect();
+        let best = scored.into_iter().max_by(|(_, p1, m1), (_, p2, m2)| {
+            p1.total_cmp(p2)
+                .then_with(|| strategy.compare_guesses(m1, m2, candidates.len()))
+        });
+
+        let Some((word, _, metrics)) = best else {
+            self.add_message("No candidates remaining!", MessageStyle::Error);
+            return;
+        };
+        let info = guess_info(&self.solver, word, metrics, &candidates, GuessKind::Risky);
+        let mut text = format!("Risky: {}", info.outcome());
+        if let Some(safe) = &self.safe_guess {
+            text = format!("{text}; safe {}", safe.outcome());
+        }
+        self.add_message(&text, MessageStyle::Info);
+        self.current_guess = Some(info);
+    }
+
+    /// Go back to the information-optimal guess
+    pub fn show_safe_guess(&mut self) {
+        if let Some(safe) = self.safe_guess.clone() {
+            self.add_message(&format!("Safe: {}", safe.outcome()), MessageStyle::Info);
+            self.current_guess = Some(safe);
+        } else {
+            self.add_message("No suggestion available!", MessageStyle::Error);
+        }
+    }
+
+    pub(crate) fn get_history_for_solver(&self) -> Vec<(Word, Pattern)> {
         self.history
             .iter()
             .filter_map(|entry| Word::new(&entry.guess).ok().map(|w| (w, entry.pattern)))
@@ -149,76 +287,98 @@ impl<'a> App<'a> {
     }
 
     pub fn handle_feedback(&mut self, feedback: &str) {
-        // Parse the feedback pattern
         if let Some(pattern) = Pattern::from_str(feedback) {
-            if let Some(guess_info) = &self.current_guess {
-                let candidates_before =
-                    self.solver.count_candidates(&self.get_history_for_solver());
-
-                // Add to history
-                let guess_word = guess_info.word.clone();
-                self.history.push(HistoryEntry {
-                    guess: guess_word,
-                    pattern,
-                    entropy: guess_info.entropy,
-                    candidates_before,
-                    candidates_after: 0, // Will be updated
-                });
-
-                // Update solver history and get new count
-                let candidates_after = self.solver.count_candidates(&self.get_history_for_solver());
-                if let Some(last) = self.history.last_mut() {
-                    last.candidates_after = candidates_after;
-                }
+            self.apply_feedback(pattern);
+        } else {
+            self.add_message("Invalid pattern! Use G/Y/-  or 🟩🟨⬜", MessageStyle::Error);
+        }
+    }
 
-                // Check if solved
-                if pattern.is_perfect() {
-                    self.stats.games_won += 1;
-                    self.stats.total_games += 1;
-                    let guess_count = self.history.len();
-                    if guess_count <= 6 {
-                        self.stats.guess_distribution[guess_count] += 1;
-                    }
+    /// Record `pattern` as the feedback for the current guess
+    fn apply_feedback(&mut self, pattern: Pattern) {
+        if let Some(guess_info) = &self.current_guess {
+            // Reject feedback that no answer could ever produce for this guess
+            if let Ok(guess_word) = Word::new(&guess_info.word)
+                && !Pattern::is_achievable(&guess_word, pattern)
+            {
+                self.add_message(
+                    &format!(
+                        "Impossible pattern for {}! Check your feedback.",
+                        guess_info.word.to_uppercase()
+                    ),
+                    MessageStyle::Error,
+                );
+                return;
+            }
+
+            let candidates_before = self.solver.count_candidates(&self.get_history_for_solver());
+
+            // Add to history
+            let guess_word = guess_info.word.clone();
+            self.history.push(HistoryEntry {
+                guess: guess_word,
+                pattern,
+                entropy: guess_info.entropy,
+                candidates_before,
+                candidates_after: 0, // Will be updated
+            });
+
+            // Update solver history and get new count
+            let candidates_after = self.solver.count_candidates(&self.get_history_for_solver());
+            if let Some(last) = self.history.last_mut() {
+                last.candidates_after = candidates_after;
+            }
 
-                    // Switch to celebration mode
-                    self.input_mode = InputMode::WinCelebration;
-
-                    // Create celebration message based on guess count
-                    let celebration = match guess_count {
-                        1 => "🎯 Hole in one! Extraordinary! 🌟",
-                        2 => "🔥 Magnificent! Two guesses! 🔥",
-                        3 => "✨ Splendid! Three guesses! ✨",
-                        4 => "👏 Great job! Four guesses! 👏",
-                        5 => "🎉 Nice work! Five guesses! 🎉",
-                        6 => "😅 Phew! Got it in six! 😅",
-                        _ => "🎊 Solved! 🎊",
-                    };
-
-                    self.add_message(celebration, MessageStyle::Success);
-                    self.add_message(
-                        "Press 'n' for new game, 'u' to undo, or 'q' to quit.",
-                        MessageStyle::Info,
-                    );
-                } else if candidates_after == 0 {
-                    // Clear current guess since no valid suggestions exist
-                    self.current_guess = None;
-                    self.add_message(
-                        "No candidates remain - pattern may be incorrect. Press 'u' to undo.",
-                        MessageStyle::Error,
-                    );
-                } else {
-                    // Compute next suggestion
-                    self.compute_suggestion();
-                    self.add_message(
-                        &format!("{candidates_after} candidates remaining"),
-                        MessageStyle::Info,
-                    );
+            let status = GameStatus::new(&self.get_history_for_solver(), MAX_GUESSES, || {
+                candidates_after
+            });
+            if let GameStatus::Won {
+                guesses: guess_count,
+            } = status
+            {
+                self.stats.games_won += 1;
+                self.stats.total_games += 1;
+                if guess_count <= MAX_GUESSES {
+                    self.stats.guess_distribution[guess_count] += 1;
                 }
 
-                self.input_buffer.clear();
+                // Switch to celebration mode
+                self.input_mode = InputMode::WinCelebration;
+
+                // Create celebration message based on guess count
+                let celebration = match guess_count {
+                    1 => "🎯 Hole in one! Extraordinary! 🌟",
+                    2 => "🔥 Magnificent! Two guesses! 🔥",
+                    3 => "✨ Splendid! Three guesses! ✨",
+                    4 => "👏 Great job! Four guesses! 👏",
+                    5 => "🎉 Nice work! Five guesses! 🎉",
+                    6 => "😅 Phew! Got it in six! 😅",
+                    _ => "🎊 Solved! 🎊",
+                };
+
+                self.add_message(celebration, MessageStyle::Success);
+                self.add_message(
+                    "Press 'n' for new game, 'u' to undo, or 'q' to quit.",
+                    MessageStyle::Info,
+                );
+            } else if candidates_after == 0 {
+                // Clear current guess since no valid suggestions exist
+                self.current_guess = None;
+                self.add_message(
+                    "No candidates remain - pattern may be incorrect. Press 'u' to undo.",
+                    MessageStyle::Error,
+                );
+            } else {
+                // Compute next suggestion
+                self.compute_suggestion();
+                let mut text = format!("{candidates_after} candidates remaining");
+                if let Some(surprise) = format_surprise(candidates_before, candidates_after) {
+                    text = format!("{text}; {surprise}");
+                }
+                self.add_message(&text, MessageStyle::Info);
             }
-        } else {
-            self.add_message("Invalid pattern! Use G/Y/-  or 🟩🟨⬜", MessageStyle::Error);
+
+            self.input_buffer.clear();
         }
     }
 
@@ -265,62 +425,192 @@ impl<'a> App<'a> {
         self.solver.count_candidates(&self.get_history_for_solver())
     }
 
+    /// Get allowed words starting with the manual word typed so far
+    ///
+    /// Returns at most `limit` matches in word-list order. An empty prefix
+    /// matches nothing, so suggestions only appear once typing starts.
+    #[must_use]
+    pub fn manual_suggestions(&self, limit: usize) -> Vec<&'a Word> {
+        let prefix = self.manual_entry_word();
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+
+        self.all_words
+            .iter()
+            .filter(|w| w.text().starts_with(prefix))
+            .take(limit)
+            .collect()
+    }
+
+    /// Complete the manual word to its first matching allowed word
+    ///
+    /// Returns `false` if nothing was typed or no word matches.
+    pub fn complete_manual_word(&mut self) -> bool {
+        if let Some(word) = self.manual_suggestions(1).first() {
+            self.manual_word = word.text().to_string();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Get the adaptive tier that applies to the current candidate count
+    #[must_use]
+    pub fn current_tier(&self) -> AdaptiveTier {
+        self.solver.strategy().get_tier(self.get_candidates_count())
+    }
+
+    /// Get the word part of the manual entry, before any feedback
+    #[must_use]
+    pub fn manual_entry_word(&self) -> &str {
+        self.manual_word
+            .split_once(' ')
+            .map_or(self.manual_word.as_str(), |(word, _)| word)
+    }
+
     pub fn use_manual_word(&mut self) {
         let word = self.manual_word.clone();
 
         // Validate the word exists in the allowed list
         if let Ok(word_obj) = Word::new(&word) {
-            if self.all_words.iter().any(|w| w.text() == word_obj.text()) {
-                // Calculate metrics for the manual word
-                let candidates = self.solver.get_candidates(&self.get_history_for_solver());
-
-                let metrics = calculate_metrics(&word_obj, &candidates);
-
-                // Compare with suggested word if available
-                if let Some(ref suggested) = self.current_guess
-                    && metrics.entropy < suggested.entropy
-                {
-                    self.add_message(
-                        &format!(
-                            "Note: Suggested word had {:.2} bits ({:.2} more)",
-                            suggested.entropy,
-                            suggested.entropy - metrics.entropy
-                        ),
-                        MessageStyle::Info,
-                    );
-                }
-
-                // Set the manual word as current guess
-                self.current_guess = Some(GuessInfo {
-                    word: word.clone(),
-                    entropy: metrics.entropy,
-                    expected_remaining: metrics.expected_remaining,
-                    max_partition: metrics.max_partition,
-                });
-
-                self.add_message(
-                    &format!(
-                        "Using: {} (entropy: {:.2} bits, {:.1}x reduction)",
-                        word.to_uppercase(),
-                        metrics.entropy,
-                        metrics.entropy.exp2()
-                    ),
-                    MessageStyle::Success,
-                );
-
+            if self.set_manual_guess(&word_obj) {
                 // Switch back to feedback mode
                 self.input_mode = InputMode::Feedback;
                 self.manual_word.clear();
-            } else {
-                self.add_message(
-                    &format!("Word '{}' not in allowed word list!", word.to_uppercase()),
-                    MessageStyle::Error,
-                );
             }
         } else {
             self.add_message("Invalid word format!", MessageStyle::Error);
         }
     }
+
+    /// Apply a combined word-and-feedback entry such as `crane gy-gy`
+    ///
+    /// For when the word played differs from the suggestion: the word becomes
+    /// the current guess and the feedback is applied to it in one step.
+    pub fn use_guess_entry(&mut self, input: &str) {
+        let (word, pattern) = match parse_guess_entry(input) {
+            Ok(entry) => entry,
+            Err(e) => {
+                self.add_message(&e, MessageStyle::Error);
+                return;
+            }
+        };
+        if !Pattern::is_achievable(&word, pattern) {
+            self.add_message(
+                &format!(
+                    "Impossible pattern for {}! Check your feedback.",
+                    word_label(&word)
+                ),
+                MessageStyle::Error,
+            );
+            return;
+        }
+
+        if self.set_manual_guess(&word) {
+            self.input_mode = InputMode::Feedback;
+            self.manual_word.clear();
+            self.apply_feedback(pattern);
+        }
+    }
+
+    /// Make `word` the current guess if it is in the allowed list
+    ///
+    /// Returns `false` (with an error message) if the word is not allowed.
+    fn set_manual_guess(&mut self, word: &Word) -> bool {
+        if !self.word_index.contains(word) {
+            self.add_message(
+                &format!("Word '{}' not in allowed word list!", word_label(word)),
+                MessageStyle::Error,
+            );
+            return false;
+        }
+
+        // Calculate metrics for the manual word
+        let candidates = self.solver.get_candidates(&self.get_history_for_solver());
+        let metrics = calculate_metrics(word, &candidates);
+
+        // Compare with suggested word if available
+        if let Some(ref suggested) = self.current_guess
+            && metrics.entropy < suggested.entropy
+        {
+            self.add_message(
+                &format!(
+                    "Note: Suggested word had {:.2} bits ({:.2} more)",
+                    suggested.entropy,
+                    suggested.entropy - metrics.entropy
+                ),
+                MessageStyle::Info,
+            );
+        }
+
+        // Set the manual word as current guess
+        self.current_guess = Some(guess_info(
+            &self.solver,
+            word,
+            metrics,
+            &candidates,
+            GuessKind::Manual,
+        ));
+
+        self.add_message(
+            &format!(
+                "Using: {} (entropy: {:.2} bits, {:.1}x reduction)",
+                word_label(word),
+                metrics.entropy,
+                metrics.entropy.exp2()
+            ),
+            MessageStyle::Success,
+        );
+        true
+    }
+}
+
+/// Summarize playing `word` against the remaining candidates
+fn guess_info<S: Strategy>(
+    solver: &Solver<S>,
+    word: &Word,
+    metrics: GuessMetrics,
+    candidates: &[&Word],
+    kind: GuessKind,
+) -> GuessInfo {
+    let win_probability = solver
+        .candidate_probabilities(candidates)
+        .into_iter()
+        .find(|(candidate, _)| candidate.text() == word.text())
+        .map_or(0.0, |(_, probability)| probability);
+
+    GuessInfo {
+        word: word.text().to_string(),
+        entropy: metrics.entropy,
+        expected_remaining: metrics.expected_remaining,
+        max_partition: metrics.max_partition,
+        expected_guesses: expected_guesses(candidates.len()),
+        win_probability,
+        kind,
+    }
+}
+
+/// Split a combined entry like `crane gy-gy` into the guessed word and its
+/// feedback
+///
+/// The word and pattern are separated by whitespace; the pattern accepts the
+/// same letters and squares as [`Pattern::from_str`].
+///
+/// # Errors
+///
+/// Returns an error if the entry is not exactly a word and a pattern, or
+/// either part is invalid.
+pub fn parse_guess_entry(input: &str) -> Result<(Word, Pattern), String> {
+    let mut parts = input.split_whitespace();
+    let (Some(word), Some(pattern), None) = (parts.next(), parts.next(), parts.next()) else {
+        return Err("Enter a word and its feedback, e.g. 'crane gy-gy'".to_string());
+    };
+
+    let word = Word::new(word).map_err(|e| format!("Invalid word: {e}"))?;
+    let pattern = Pattern::from_str(pattern)
+        .ok_or_else(|| format!("Invalid pattern '{pattern}'! Use G/Y/-  or 🟩🟨⬜"))?;
+    Ok((word, pattern))
 }
 
 /// Run the TUI application
@@ -356,6 +646,71 @@ pub fn run_tui(app: App) -> Result<()> {
     Ok(())
 }
 
+/// Read one event, returning it if it is a key press
+///
+/// A resize clears the terminal so the next draw repaints every cell at the
+/// new size instead of diffing against stale contents.
+fn next_key_press<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+) -> Result<Option<KeyEvent>> {
+    match event::read()? {
+        // Only process key press events (fixes Windows double-input bug)
+        Event::Key(key) if key.kind == KeyEventKind::Press => Ok(Some(key)),
+        Event::Resize(_, _) => {
+            terminal.clear()?;
+            Ok(None)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Handle a key press while entering a manual word, optionally followed by
+/// its feedback
+fn handle_manual_word_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => {
+            app.input_mode = InputMode::Feedback;
+            app.manual_word.clear();
+            app.add_message("Cancelled manual word entry", MessageStyle::Info);
+        }
+        KeyCode::Tab if !app.manual_word.contains(' ') => {
+            // Complete to the first match, or toggle back when
+            // there is nothing to complete
+            let completed = app.complete_manual_word();
+            if !completed {
+                app.input_mode = InputMode::Feedback;
+                app.manual_word.clear();
+            }
+        }
+        KeyCode::Char(c) if app.manual_word.len() < 5 && c.is_alphabetic() => {
+            app.manual_word.push(c.to_ascii_lowercase());
+        }
+        KeyCode::Char(' ') if app.manual_word.len() == 5 => {
+            // Feedback for the word follows
+            app.manual_word.push(' ');
+        }
+        KeyCode::Char(c)
+            if app.manual_word.contains(' ') && app.manual_word.chars().count() < 11 =>
+        {
+            app.manual_word.push(c);
+        }
+        KeyCode::Backspace => {
+            app.manual_word.pop();
+        }
+        KeyCode::Enter => {
+            if app.manual_word.contains(' ') {
+                let entry = app.manual_word.clone();
+                app.use_guess_entry(&entry);
+            } else if app.manual_word.len() == 5 {
+                app.use_manual_word();
+            } else {
+                app.add_message("Word must be exactly 5 letters!", MessageStyle::Error);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
     // Compute initial suggestion
     app.compute_suggestion();
@@ -363,102 +718,76 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, mut app: Ap
     loop {
         terminal.draw(|f| super::rendering::ui(f, &app))?;
 
-        if let Event::Key(key) = event::read()? {
-            // Only process key press events (fixes Windows double-input bug)
-            if key.kind != KeyEventKind::Press {
-                continue;
-            }
+        let Some(key) = next_key_press(terminal)? else {
+            continue;
+        };
 
-            match app.input_mode {
-                InputMode::WinCelebration => {
-                    match key.code {
-                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                            app.should_quit = true;
-                        }
-                        KeyCode::Char('q') => {
-                            app.should_quit = true;
-                        }
-                        KeyCode::Char('n') => {
-                            app.new_game();
-                        }
-                        KeyCode::Char('u') => {
-                            // Allow undoing even after winning
-                            app.undo_last();
-                            app.input_mode = InputMode::Feedback;
-                        }
-                        _ => {
-                            // In celebration mode, ignore other keys
-                        }
+        match app.input_mode {
+            InputMode::WinCelebration => {
+                match key.code {
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app.should_quit = true;
                     }
-                }
-                InputMode::Feedback => {
-                    match key.code {
-                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                            app.should_quit = true;
-                        }
-                        KeyCode::Char('q') => {
-                            app.should_quit = true;
-                        }
-                        KeyCode::Char('n') => {
-                            app.new_game();
-                            // Don't add 'n' to input buffer
-                        }
-                        KeyCode::Char('u') => {
-                            app.undo_last();
-                            // Don't add 'u' to input buffer
-                        }
-                        KeyCode::Tab => {
-                            // Switch to manual word mode (always allow - recovery path)
-                            app.input_mode = InputMode::ManualWord;
-                            app.add_message("Enter your own word (5 letters)", MessageStyle::Info);
-                        }
-                        KeyCode::Char(c) => {
-                            app.input_buffer.push(c);
-                        }
-                        KeyCode::Backspace => {
-                            app.input_buffer.pop();
-                        }
-                        KeyCode::Enter => {
-                            let input = app.input_buffer.clone();
-                            app.handle_feedback(&input);
-                        }
-                        _ => {}
+                    KeyCode::Char('q') => {
+                        app.should_quit = true;
+                    }
+                    KeyCode::Char('n') => {
+                        app.new_game();
+                    }
+                    KeyCode::Char('u') => {
+                        // Allow undoing even after winning
+                        app.undo_last();
+                        app.input_mode = InputMode::Feedback;
+                    }
+                    _ => {
+                        // In celebration mode, ignore other keys
                     }
                 }
-                InputMode::ManualWord => {
-                    match key.code {
-                        KeyCode::Esc => {
-                            app.input_mode = InputMode::Feedback;
-                            app.manual_word.clear();
-                            app.add_message("Cancelled manual word entry", MessageStyle::Info);
-                        }
-                        KeyCode::Tab => {
-                            // Toggle back to feedback mode
-                            app.input_mode = InputMode::Feedback;
-                            app.manual_word.clear();
-                        }
-                        KeyCode::Char(c) => {
-                            if app.manual_word.len() < 5 && c.is_alphabetic() {
-                                app.manual_word.push(c.to_ascii_lowercase());
-                            }
-                        }
-                        KeyCode::Backspace => {
-                            app.manual_word.pop();
-                        }
-                        KeyCode::Enter => {
-                            if app.manual_word.len() == 5 {
-                                app.use_manual_word();
-                            } else {
-                                app.add_message(
-                                    "Word must be exactly 5 letters!",
-                                    MessageStyle::Error,
-                                );
-                            }
-                        }
-                        _ => {}
+            }
+            InputMode::Feedback => {
+                match key.code {
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app.should_quit = true;
+                    }
+                    KeyCode::Char('q') => {
+                        app.should_quit = true;
+                    }
+                    KeyCode::Char('n') => {
+                        app.new_game();
+                        // Don't add 'n' to input buffer
                     }
+                    KeyCode::Char('u') => {
+                        app.undo_last();
+                        // Don't add 'u' to input buffer
+                    }
+                    KeyCode::Char('s') => {
+                        app.show_safe_guess();
+                    }
+                    KeyCode::Char('r') => {
+                        app.show_risky_guess();
+                    }
+                    KeyCode::Tab => {
+                        // Switch to manual word mode (always allow - recovery path)
+                        app.input_mode = InputMode::ManualWord;
+                        app.add_message(
+                            "Enter your own word, optionally followed by its feedback (e.g. 'crane gy-gy')",
+                            MessageStyle::Info,
+                        );
+                    }
+                    KeyCode::Char(c) => {
+                        app.input_buffer.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        app.input_buffer.pop();
+                    }
+                    KeyCode::Enter => {
+                        let input = app.input_buffer.clone();
+                        app.handle_feedback(&input);
+                    }
+                    _ => {}
                 }
             }
+            InputMode::ManualWord => handle_manual_word_key(&mut app, key),
         }
 
         if app.should_quit {
@@ -468,3 +797,115 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, mut app: Ap
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::selection::beam_prefilter;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+
+    #[test]
+    fn parse_guess_entry_splits_word_and_pattern() {
+        let (word, pattern) = parse_guess_entry("CRANE GYGGY").unwrap();
+        assert_eq!(word.text(), "crane");
+        assert_eq!(pattern, Pattern::from_str("GYGGY").unwrap());
+
+        let (_, squares) = parse_guess_entry("  slate 🟩🟨⬜🟩🟨 ").unwrap();
+        assert_eq!(squares, Pattern::from_str("GY-GY").unwrap());
+
+        for bad in [
+            "crane",
+            "crane gy-gy extra",
+            "cran gy-gy",
+            "crane gyzgy",
+            "",
+        ] {
+            assert!(parse_guess_entry(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn guess_entry_replaces_suggestion_and_applies_feedback() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(ANSWERS);
+        let mut app = App::new(&all_words, &answer_words);
+        app.compute_suggestion();
+
+        let answer = Word::new("turbo").unwrap();
+        let played = Word::new("crane").unwrap();
+        let pattern = Pattern::calculate(&played, &answer);
+        app.input_mode = InputMode::ManualWord;
+        app.use_guess_entry(&format!("CRANE {}", pattern.to_emoji_with(['-', 'Y', 'G'])));
+
+        assert_eq!(app.input_mode, InputMode::Feedback);
+        assert_eq!(app.history.len(), 1);
+        assert_eq!(app.history[0].guess, "crane");
+        assert_eq!(app.history[0].pattern, pattern);
+        let remaining = app.solver.get_candidates(&[(played, pattern)]);
+        assert_eq!(app.get_candidates_count(), remaining.len());
+
+        // Words outside the list and impossible feedback leave the game as is
+        app.use_guess_entry("zzzzz -----");
+        app.use_guess_entry("eerie GGGGY");
+        assert_eq!(app.history.len(), 1);
+    }
+
+    #[test]
+    fn beam_only_limits_large_candidate_sets() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(ANSWERS);
+        let full = App::new(&all_words, &answer_words);
+        let beamed = App::new(&all_words, &answer_words).with_beam_width(Some(50));
+        assert_eq!(beamed.solver.strategy().beam_width, Some(50));
+        assert_eq!(full.solver.first_guess(), beamed.solver.first_guess());
+
+        let salet = Word::new("salet").unwrap();
+        // Over 100 candidates: the guess comes from the beam
+        let wide = vec![(salet.clone(), Pattern::from_str("-----").unwrap())];
+        let candidates = beamed.solver.get_candidates(&wide);
+        assert!(candidates.len() > 100);
+        let pool: Vec<&Word> = all_words.iter().collect();
+        let beam = beam_prefilter(&pool, &candidates, 50);
+        let guess = beamed.solver.next_guess(&wide).unwrap();
+        assert!(beam.contains(&guess));
+
+        // Smaller sets get the full search
+        let narrow = vec![(salet, Pattern::from_str("G---G").unwrap())];
+        assert!(beamed.solver.count_candidates(&narrow) <= 100);
+        assert_eq!(
+            beamed.solver.next_guess(&narrow),
+            full.solver.next_guess(&narrow)
+        );
+    }
+
+    #[test]
+    fn risky_guess_is_a_candidate_and_safe_restores() {
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(ANSWERS);
+        let mut app = App::new(&all_words, &answer_words);
+
+        let answer = Word::new("shake").unwrap();
+        let played = Word::new("salet").unwrap();
+        app.history.push(HistoryEntry {
+            guess: played.text().to_string(),
+            pattern: Pattern::calculate(&played, &answer),
+            entropy: 0.0,
+            candidates_before: answer_words.len(),
+            candidates_after: 0,
+        });
+        app.compute_suggestion();
+        let safe = app.current_guess.clone().unwrap();
+        assert_eq!(safe.kind, GuessKind::Safe);
+
+        app.show_risky_guess();
+        let risky = app.current_guess.clone().unwrap();
+        let candidates = app.solver.get_candidates(&app.get_history_for_solver());
+        assert_eq!(risky.kind, GuessKind::Risky);
+        assert!(candidates.iter().any(|c| c.text() == risky.word));
+        assert!((risky.win_probability - 1.0 / candidates.len() as f64).abs() < 1e-9);
+
+        app.show_safe_guess();
+        assert_eq!(app.current_guess.unwrap().word, safe.word);
+    }
+}
@@ -2,5 +2,7 @@
 
 mod app;
 mod rendering;
+mod theme;
 
 pub use app::{App, run_tui};
+pub use theme::Theme;
@@ -2,7 +2,8 @@
 //!
 //! Visualizations for the Wordle solver interface.
 
-use super::app::{App, InputMode, MessageStyle};
+use super::app::{App, GuessKind, InputMode, MessageStyle};
+use crate::output::formatters::word_label;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -11,8 +12,24 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Gauge, List, ListItem, Paragraph, Wrap},
 };
 
+/// Smallest terminal width the full layout fits in
+pub const MIN_WIDTH: u16 = 60;
+
+/// Smallest terminal height the full layout fits in (header, main content,
+/// input and status bar at their minimum heights)
+pub const MIN_HEIGHT: u16 = 21;
+
+/// Most candidates the candidate panel lists individually
+const LISTED_CANDIDATES: usize = 12;
+
 /// Main UI rendering function
 pub fn ui(f: &mut Frame, app: &App) {
+    let area = f.area();
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        render_too_small(f, area);
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -21,7 +38,7 @@ pub fn ui(f: &mut Frame, app: &App) {
             Constraint::Length(5), // Input area
             Constraint::Length(3), // Status bar
         ])
-        .split(f.area());
+        .split(area);
 
     // Header
     render_header(f, chunks[0]);
@@ -45,6 +62,34 @@ pub fn ui(f: &mut Frame, app: &App) {
     render_status(f, app, chunks[3]);
 }
 
+/// Ask for a bigger terminal instead of squeezing the layout
+fn render_too_small(f: &mut Frame, area: Rect) {
+    let message = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "{}x{} (need {MIN_WIDTH}x{MIN_HEIGHT})",
+            area.width, area.height
+        )),
+        Line::from("Resize to continue"),
+    ];
+
+    // Vertically center when there is room; otherwise start at the top
+    let padding = area.height.saturating_sub(3) / 2;
+    let area = Rect {
+        y: area.y + padding,
+        height: area.height - padding,
+        ..area
+    };
+
+    let paragraph = Paragraph::new(message)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
 fn render_header(f: &mut Frame, area: Rect) {
     let header = Paragraph::new("🎯 Wordle Solver - Interactive Mode")
         .style(
@@ -84,13 +129,18 @@ fn render_current_guess(f: &mut Frame, app: &App, area: Rect) {
         let entropy_bar =
             "█".repeat(entropy_bar_len) + &"░".repeat(18_usize.saturating_sub(entropy_bar_len));
 
+        let label = match guess.kind {
+            GuessKind::Safe => "Suggested: ",
+            GuessKind::Risky => "Risky:     ",
+            GuessKind::Manual => "Using:     ",
+        };
         let content = vec![
             Line::from(vec![
-                Span::raw("Suggested: "),
+                Span::raw(label),
                 Span::styled(
                     guess.word.to_uppercase(),
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(app.theme.present())
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
@@ -104,6 +154,15 @@ fn render_current_guess(f: &mut Frame, app: &App, area: Rect) {
                 guess.expected_remaining
             )),
             Line::from(format!("Worst:     {} candidates", guess.max_partition)),
+            Line::from(format!(
+                "Win now:   {:.0}% chance it's the answer",
+                guess.win_probability * 100.0
+            )),
+            Line::from(format!(
+                "Estimate:  ≈{:.1} more guesses (rough)",
+                guess.expected_guesses
+            )),
+            tier_line(app),
         ];
 
         let paragraph = Paragraph::new(content)
@@ -128,63 +187,106 @@ fn render_current_guess(f: &mut Frame, app: &App, area: Rect) {
     }
 }
 
+/// Describe the active adaptive tier and the candidate range it covers
+fn tier_line(app: &App) -> Line<'static> {
+    let tier = app.current_tier();
+    let range = match app.solver.strategy().tier_range(tier) {
+        (min, None) => format!("{min}+"),
+        (min, Some(max)) if min == max => format!("{min}"),
+        (min, Some(max)) => format!("{min}–{max}"),
+    };
+
+    Line::from(vec![
+        Span::raw("Tier:      "),
+        Span::styled(tier.name(), Style::default().fg(Color::Magenta)),
+        Span::raw(format!(" ({range} candidates)")),
+    ])
+}
+
 fn render_candidates(f: &mut Frame, app: &App, area: Rect) {
-    let candidates_count = app.get_candidates_count();
+    // One past the limit is enough to tell whether to list them; the full
+    // count is only needed when there are too many
+    let candidates: Vec<&crate::core::Word> = app
+        .solver
+        .candidates_iter(&app.get_history_for_solver())
+        .take(LISTED_CANDIDATES + 1)
+        .collect();
 
-    let content = if candidates_count == 0 {
+    let content = if candidates.is_empty() {
         vec![Line::from("Game completed!")]
-    } else if candidates_count <= 12 {
-        // Show individual candidates (up to 12)
-        let solver_history = app
-            .history
-            .iter()
-            .filter_map(|entry| {
-                crate::core::Word::new(&entry.guess)
-                    .ok()
-                    .map(|w| (w, entry.pattern))
-            })
-            .collect::<Vec<_>>();
-
-        let candidates = app.solver.get_candidates(&solver_history);
-        let candidate_refs: Vec<&crate::core::Word> = candidates.clone();
+    } else if candidates.len() <= LISTED_CANDIDATES {
+        // Rows inside the borders below the legend; keep one for a "more"
+        // line when not everything fits
+        let fit = usize::from(area.height.saturating_sub(3));
+        let visible = if candidates.len() <= fit {
+            candidates.len()
+        } else {
+            fit.saturating_sub(1)
+        };
 
         let mut lines = vec![Line::from(vec![
             Span::raw("Remaining: "),
-            Span::styled("🟢", Style::default().fg(Color::Green)),
+            Span::styled("🟢", Style::default().fg(app.theme.correct())),
             Span::raw(" = answer  "),
             Span::styled("⚪", Style::default().fg(Color::White)),
             Span::raw(" = guess only"),
         ])];
 
-        for candidate in candidates.iter().take(12) {
+        let locked = locked_positions(&candidates);
+
+        let probabilities = app.solver.candidate_probabilities(&candidates);
+        for (candidate, probability) in probabilities.iter().take(visible) {
             // Check if this word is in the answer list
-            let is_answer = app
-                .answer_words
-                .iter()
-                .any(|w| w.text() == candidate.text());
+            let is_answer = app.answer_index.contains(candidate);
 
             // Calculate entropy for this candidate
-            let metrics = crate::solver::entropy::calculate_metrics(candidate, &candidate_refs);
+            let metrics = crate::solver::entropy::calculate_metrics(candidate, &candidates);
 
             let (prefix, style) = if is_answer {
-                ("🟢", Style::default().fg(Color::Green))
+                ("🟢", Style::default().fg(app.theme.correct()))
             } else {
-                ("⚪", Style::default().fg(Color::DarkGray))
+                ("⚪", Style::default().fg(app.theme.absent()))
             };
 
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::raw(prefix),
-                Span::raw(" "),
-                Span::styled(format!("{:<5}", candidate.text().to_uppercase()), style),
+            // Letters every candidate shares are settled; the rest still vary
+            let mut spans = vec![Span::raw("  "), Span::raw(prefix), Span::raw(" ")];
+            for (letter, &fixed) in candidate.chars().iter().zip(&locked) {
+                let letter_style = if fixed {
+                    Style::default()
+                        .fg(app.theme.correct())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    style.add_modifier(Modifier::DIM)
+                };
+                spans.push(Span::styled(
+                    char::from(letter.to_ascii_uppercase()).to_string(),
+                    letter_style,
+                ));
+            }
+            spans.extend([
                 Span::styled(
                     format!(" {:.2}b", metrics.entropy),
                     Style::default().fg(Color::Cyan),
                 ),
-            ]));
+                Span::styled(
+                    format!(" {:>3.0}%", probability * 100.0),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]);
+            lines.push(Line::from(spans));
+        }
+        if fit > 0 && visible < candidates.len() {
+            lines.push(Line::from(format!(
+                "  … {} more",
+                candidates.len() - visible
+            )));
+        }
+        if let Some(note) = crate::output::formatters::likely_answer_note(&probabilities) {
+            lines.push(Line::from(note));
         }
         lines
     } else {
+        let candidates_count = app.get_candidates_count();
         vec![
             Line::from(format!("{candidates_count} candidates remaining")),
             Line::from(format!(
@@ -198,7 +300,7 @@ fn render_candidates(f: &mut Frame, app: &App, area: Rect) {
         Block::default()
             .title(" Candidates ")
             .borders(Borders::ALL)
-            .style(Style::default().fg(Color::Green)),
+            .style(Style::default().fg(app.theme.correct())),
     );
 
     f.render_widget(paragraph, area);
@@ -216,7 +318,7 @@ fn render_history(f: &mut Frame, app: &App, area: Rect) {
                 "{}: {} {} [{:.1} bits] {} → {}",
                 app.history.len() - i,
                 entry.guess.to_uppercase(),
-                entry.pattern.to_emoji(),
+                app.theme.emoji(entry.pattern),
                 entry.entropy,
                 entry.candidates_before,
                 entry.candidates_after
@@ -275,7 +377,7 @@ fn render_messages(f: &mut Frame, app: &App, area: Rect) {
         .map(|msg| {
             let style = match msg.style {
                 MessageStyle::Info => Style::default().fg(Color::White),
-                MessageStyle::Success => Style::default().fg(Color::Green),
+                MessageStyle::Success => Style::default().fg(app.theme.correct()),
                 MessageStyle::Error => Style::default().fg(Color::Red),
             };
             ListItem::new(msg.text.clone()).style(style)
@@ -292,17 +394,20 @@ fn render_input(f: &mut Frame, app: &App, area: Rect) {
     let (title, content, color) = match app.input_mode {
         InputMode::WinCelebration => (
             " 🎉 Congratulations! | Press 'n' for new game or 'q' to quit ",
-            "",
-            Color::Green,
+            vec![Line::from("")],
+            app.theme.correct(),
         ),
         InputMode::Feedback => (
             " Enter Feedback (G=Green Y=Yellow -=Gray, or emojis) | TAB for manual word ",
-            app.input_buffer.as_str(),
-            Color::Yellow,
+            vec![Line::from(app.input_buffer.as_str())],
+            app.theme.present(),
         ),
         InputMode::ManualWord => (
-            " Enter Word to Try (5 letters) | ESC to cancel ",
-            app.manual_word.as_str(),
+            " Enter Word to Try, or Word + Feedback (CRANE GY-GY) | TAB to complete | ESC to cancel ",
+            vec![
+                Line::from(app.manual_word.as_str()),
+                manual_suggestions_line(app),
+            ],
             Color::Cyan,
         ),
     };
@@ -320,6 +425,40 @@ fn render_input(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(input, area);
 }
 
+/// List allowed words matching the manual word prefix, or flag a dead end
+fn manual_suggestions_line(app: &App) -> Line<'static> {
+    if app.manual_word.is_empty() {
+        return Line::from("");
+    }
+    if app.manual_word.contains(' ') {
+        return Line::from(Span::styled(
+            "Enter: use this word with its feedback",
+            Style::default()
+                .fg(Color::DarkGray)
+                .remove_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let suggestions = app.manual_suggestions(8);
+    if suggestions.is_empty() {
+        return Line::from(Span::styled(
+            format!(
+                "No valid word starts with '{}'",
+                app.manual_word.to_uppercase()
+            ),
+            Style::default().fg(Color::Red),
+        ));
+    }
+
+    let words: Vec<String> = suggestions.iter().map(|w| word_label(w)).collect();
+    Line::from(Span::styled(
+        format!("→ {}", words.join("  ")),
+        Style::default()
+            .fg(Color::DarkGray)
+            .remove_modifier(Modifier::BOLD),
+    ))
+}
+
 fn render_status(f: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -354,7 +493,7 @@ fn render_status(f: &mut Frame, app: &App, area: Rect) {
     let help_text = if app.get_candidates_count() == 0 && !app.history.is_empty() {
         "q: Quit | n: New Game | u: Undo"
     } else {
-        "q: Quit | u: Undo | Enter: Submit | TAB: Manual Word"
+        "q: Quit | u: Undo | s/r: Safe/Risky | Enter: Submit | TAB: Manual Word"
     };
 
     let help = Paragraph::new(help_text)
@@ -362,3 +501,82 @@ fn render_status(f: &mut Frame, app: &App, area: Rect) {
         .style(Style::default().fg(Color::DarkGray));
     f.render_widget(help, chunks[3]);
 }
+
+/// Positions where every candidate has the same letter
+///
+/// Only called for the short candidate list, so the 5 × candidates scan
+/// stays cheap.
+fn locked_positions(candidates: &[&crate::core::Word]) -> [bool; 5] {
+    let mut locked = [false; 5];
+    if let Some((first, rest)) = candidates.split_first() {
+        for (i, fixed) in locked.iter_mut().enumerate() {
+            *fixed = rest.iter().all(|w| w.char_at(i) == first.char_at(i));
+        }
+    }
+    locked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn draw(app: &App, width: u16, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| ui(f, app)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    #[test]
+    fn tiny_terminals_show_too_small_message() {
+        let all_words = words_from_slice(&ALLOWED[..50]);
+        let answer_words = words_from_slice(&ANSWERS[..10]);
+        let mut app = App::new(&all_words, &answer_words);
+        app.compute_suggestion();
+
+        // Degenerate sizes only need to draw without panicking
+        draw(&app, 0, 0);
+        draw(&app, 1, 1);
+
+        for (width, height) in [(20, 5), (MIN_WIDTH - 1, 40), (120, MIN_HEIGHT - 1)] {
+            let screen = draw(&app, width, height);
+            assert!(screen.contains("Terminal too small"), "{width}x{height}");
+        }
+    }
+
+    #[test]
+    fn locked_positions_mark_shared_letters() {
+        let words = words_from_slice(&["crate", "grate", "irate"]);
+        let candidates: Vec<&crate::core::Word> = words.iter().collect();
+
+        assert_eq!(
+            locked_positions(&candidates),
+            [false, true, true, true, true]
+        );
+        assert_eq!(locked_positions(&candidates[..1]), [true; 5]);
+        assert_eq!(locked_positions(&[]), [false; 5]);
+    }
+
+    #[test]
+    fn minimum_size_renders_full_layout() {
+        let all_words = words_from_slice(&ALLOWED[..50]);
+        // Few enough answers that the candidate list is drawn word by word
+        let answer_words = words_from_slice(&ANSWERS[..10]);
+        let mut app = App::new(&all_words, &answer_words);
+        app.compute_suggestion();
+
+        for (width, height) in [(MIN_WIDTH, MIN_HEIGHT), (80, 24), (200, 60)] {
+            let screen = draw(&app, width, height);
+            assert!(!screen.contains("Terminal too small"), "{width}x{height}");
+            assert!(screen.contains("Candidates"), "{width}x{height}");
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Color themes for the TUI
+//!
+//! Maps Wordle feedback (green/yellow/gray) to terminal colors and emoji so
+//! every panel renders feedback the same way.
+
+use crate::core::Pattern;
+use ratatui::style::Color;
+
+/// Feedback color palette for the TUI
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Theme {
+    /// Classic Wordle green and yellow
+    #[default]
+    Default,
+    /// Brighter green/yellow and white gray for low-contrast terminals
+    HighContrast,
+    /// Blue and orange, distinguishable with red-green color blindness
+    Colorblind,
+}
+
+impl Theme {
+    /// Names accepted by [`Self::from_name`]
+    pub const NAMES: [&'static str; 3] = ["default", "high-contrast", "colorblind"];
+
+    /// Create theme from name string
+    ///
+    /// Supported names are [`Self::NAMES`]; returns `None` for any other.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::Default),
+            "high-contrast" => Some(Self::HighContrast),
+            "colorblind" => Some(Self::Colorblind),
+            _ => None,
+        }
+    }
+
+    /// Color for correct letters in the correct position (green feedback)
+    #[must_use]
+    pub const fn correct(self) -> Color {
+        match self {
+            Self::Default => Color::Green,
+            Self::HighContrast => Color::LightGreen,
+            Self::Colorblind => Color::LightBlue,
+        }
+    }
+
+    /// Color for letters in the wrong position (yellow feedback)
+    #[must_use]
+    pub const fn present(self) -> Color {
+        match self {
+            Self::Default => Color::Yellow,
+            Self::HighContrast => Color::LightYellow,
+            Self::Colorblind => Color::Rgb(245, 121, 58),
+        }
+    }
+
+    /// Color for letters not in the word (gray feedback)
+    #[must_use]
+    pub const fn absent(self) -> Color {
+        match self {
+            Self::Default | Self::Colorblind => Color::DarkGray,
+            Self::HighContrast => Color::White,
+        }
+    }
+
+    /// Render a pattern as emoji squares in this theme
+    #[must_use]
+    pub fn emoji(self, pattern: Pattern) -> String {
+        match self {
+            Self::Default | Self::HighContrast => pattern.to_emoji(),
+            Self::Colorblind => pattern.to_emoji_with(['⬜', '🟧', '🟦']),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_name_accepts_only_known_themes() {
+        assert_eq!(Theme::from_name("high-contrast"), Some(Theme::HighContrast));
+        assert_eq!(Theme::from_name("colorblind"), Some(Theme::Colorblind));
+        assert_eq!(Theme::from_name("neon"), None);
+        assert!(
+            Theme::NAMES
+                .iter()
+                .all(|name| Theme::from_name(name).is_some())
+        );
+    }
+
+    #[test]
+    fn emoji_follows_theme() {
+        let pattern = Pattern::from_str("GY-GY").unwrap();
+        assert_eq!(Theme::Default.emoji(pattern), "🟩🟨⬜🟩🟨");
+        assert_eq!(Theme::Colorblind.emoji(pattern), "🟦🟧⬜🟦🟧");
+    }
+
+    #[test]
+    fn feedback_colors_are_distinct() {
+        for theme in [Theme::Default, Theme::HighContrast, Theme::Colorblind] {
+            assert_ne!(theme.correct(), theme.present());
+            assert_ne!(theme.present(), theme.absent());
+            assert_ne!(theme.correct(), theme.absent());
+        }
+    }
+}
@@ -13,6 +13,15 @@
 //! let pattern = Pattern::calculate(&guess, &answer);
 //! println!("Pattern value: {}", pattern.value());
 //! ```
+//!
+//! # `no_std`
+//!
+//! With the default `std` feature disabled, only [`core`] and the entropy and
+//! minimax calculators in [`solver`] are built, on top of `alloc`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 // Core domain types
 pub mod core;
@@ -21,13 +30,21 @@ pub mod core;
 pub mod solver;
 
 // Word lists
+#[cfg(feature = "std")]
 pub mod wordlists;
 
+// Saved results
+#[cfg(feature = "std")]
+pub mod artifact;
+
 // Command implementations
+#[cfg(feature = "std")]
 pub mod commands;
 
 // Terminal output formatting
+#[cfg(feature = "std")]
 pub mod output;
 
 // Interactive TUI interface
+#[cfg(feature = "std")]
 pub mod interactive;
@@ -4,16 +4,47 @@
 //! Performance: 99.7-99.8% optimal (3.428-3.436 avg guesses)
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::builder::{PossibleValuesParser, RangedU64ValueParser};
+use clap::{Args, Parser, Subcommand};
+use signal_hook::consts::SIGINT;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use wordle_solver::{
+    artifact::{self, ArtifactError, ArtifactKey},
     commands::{
-        SolveConfig, analyze_word, print_test_all_statistics, run_benchmark, run_simple,
-        run_test_all, solve_word,
+        CandidateSet, DEFAULT_EXACT_LIMIT, DEFAULT_REVEAL_AT, DEFAULT_TRAP_SIZE,
+        HARD_GUESS_THRESHOLD, HardWord, OPENER_PERCENTILES, ProgressMode, SolveConfig, SolveTree,
+        analyze_word, clue_summary, coach, compare_beam_widths, diff_strategies,
+        diff_strategies_all, entropy_breakdown, filter_answers, find_traps, first_guess_analysis,
+        hard_opening, hint, min_guaranteed, opener_percentiles, opener_rank, parse_grid,
+        parse_position_class, pattern_stats, print_test_all_statistics, rank_openers, reconstruct,
+        run_benchmark, run_benchmark_iterations, run_simple, run_test_all, sample_words,
+        select_hard_words, skill_score, solve_word, tournament, verify_lists, worst_case,
+        worst_case_from_tree, write_openers_csv, write_tree_dot,
+    },
+    core::{Constraints, Pattern, Word, WordIndex},
+    interactive::Theme,
+    output::{
+        formatters::word_label, print_analysis_result, print_beam_comparison,
+        print_beam_comparison_markdown, print_benchmark_markdown, print_benchmark_result,
+        print_coach_report, print_entropy_breakdown, print_filter_result,
+        print_first_guess_analysis, print_hard_opening, print_min_guaranteed,
+        print_opener_percentiles, print_opener_table, print_pattern_stats, print_reconstruction,
+        print_skill_score, print_solve_result, print_strategy_diff, print_strategy_diff_summary,
+        print_tournament, print_traps, print_verify_report, print_worst_case,
+    },
+    solver::{
+        ADAPTIVE_PRESETS, CommitRule, SOLVER_REVISION, Solver, Strategy, StrategyType,
+        entropy::calculate_entropy,
+    },
+    wordlists::{
+        ALLOWED, ANSWERS,
+        loader::{RejectedEntry, add_missing_answers, parse_answer_weights, words_from_slice},
+        manifest::{Manifest, answers_hash, manifest_path},
     },
-    core::Word,
-    output::{print_analysis_result, print_benchmark_result, print_solve_result},
-    solver::{Solver, Strategy, StrategyType},
-    wordlists::{ALLOWED, ANSWERS, loader::words_from_slice},
 };
 
 #[derive(Parser)]
@@ -27,37 +58,161 @@ struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
 
-    /// Strategy: adaptive (default), entropy, minimax, hybrid, random
+    /// Strategy: adaptive (default), entropy, minimax, hybrid, random, naive
     #[arg(short, long, global = true, default_value = "adaptive")]
     strategy: String,
 
+    /// Tested adaptive configuration: optimal-average, optimal-worstcase, fast
+    #[arg(
+        long,
+        global = true,
+        value_parser = PossibleValuesParser::new(ADAPTIVE_PRESETS.map(|p| p.name))
+    )]
+    preset: Option<String>,
+
     /// Wordlist: 'all' (default, 12972 words), 'answers' (2315 only), or path to file
     #[arg(short = 'w', long, global = true, default_value = "all")]
     wordlist: String,
+
+    /// Answer list file, e.g. a themed subset; guesses still come from -w
+    #[arg(long, global = true, value_name = "PATH")]
+    answers: Option<PathBuf>,
+
+    /// Refuse word list files with any invalid entry instead of skipping it
+    #[arg(long, global = true)]
+    strict_wordlist: bool,
+
+    /// Compute the opener for a custom wordlist and save it to the list's
+    /// .meta.toml manifest
+    #[arg(long, global = true)]
+    save_opener: bool,
+
+    /// Guess the likeliest candidate outright once N or fewer remain
+    #[arg(long, global = true, value_name = "N")]
+    commit_at: Option<usize>,
+
+    /// Guess the likeliest candidate outright once it is at least P likely
+    /// (0 to 1)
+    #[arg(long, global = true, value_name = "P", value_parser = parse_probability)]
+    commit_probability: Option<f64>,
+
+    /// File of `word weight` lines giving each answer's relative likelihood,
+    /// used for shown probabilities and --commit-probability (unlisted
+    /// answers weigh 1)
+    #[arg(long, global = true, value_name = "PATH")]
+    answer_weights: Option<PathBuf>,
+}
+
+/// Parse a probability between 0 and 1
+fn parse_probability(text: &str) -> Result<f64, String> {
+    text.parse::<f64>()
+        .ok()
+        .filter(|p| (0.0..=1.0).contains(p))
+        .ok_or_else(|| format!("'{text}' is not a probability between 0 and 1"))
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Interactive TUI mode (default - beautiful visualizations)
-    Play,
+    Play {
+        /// Score only the N guesses with the best letter-frequency scores
+        /// while over 100 candidates remain, for quicker early suggestions
+        /// on slow machines (off by default)
+        #[arg(long, value_name = "N", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        beam: Option<usize>,
+
+        /// Color theme
+        #[arg(
+            long,
+            default_value = "default",
+            value_parser = PossibleValuesParser::new(Theme::NAMES)
+        )]
+        theme: String,
+    },
 
     /// Simple CLI mode (interactive solver without TUI)
-    Simple,
+    Simple {
+        /// List remaining candidates once this many or fewer remain
+        #[arg(long, default_value_t = DEFAULT_REVEAL_AT, value_name = "N")]
+        reveal_at: usize,
+    },
 
     /// Solve a specific target word
     Solve {
         /// The target word to solve
         word: String,
 
-        /// Show verbose output with candidate counts
-        #[arg(short, long)]
-        verbose: bool,
+        #[command(flatten)]
+        output: SolveOutput,
     },
 
-    /// Analyze the entropy of a specific word
+    /// Analyze the entropy of a specific word, or rank every opener
     Analyze {
         /// Word to analyze
-        word: String,
+        #[arg(required_unless_present = "all_openers")]
+        word: Option<String>,
+
+        #[command(flatten)]
+        history: HistoryArgs,
+
+        /// Rank every guess pool word by its opening entropy against all answers
+        #[arg(long, conflicts_with_all = ["word", "guess", "pattern"])]
+        all_openers: bool,
+
+        /// Write the --all-openers ranking to a CSV file
+        #[arg(long, value_name = "PATH", conflicts_with = "word")]
+        csv: Option<PathBuf>,
+
+        /// Summarize the --all-openers ranking as entropy percentiles
+        #[arg(long, conflicts_with = "word")]
+        percentiles: bool,
+
+        /// Score the word 0-100 against the best guess, like the NYT Wordle bot
+        #[arg(long, conflicts_with = "all_openers")]
+        skill: bool,
+
+        /// Show how much entropy each position's feedback contributes
+        #[arg(long, conflicts_with_all = ["all_openers", "skill"])]
+        breakdown: bool,
+
+        /// Score against the answer list, or against every allowed word
+        #[arg(
+            long,
+            value_name = "SET",
+            default_value = "answers",
+            value_parser = PossibleValuesParser::new(["answers", "all"]),
+            conflicts_with_all = ["all_openers", "skill"]
+        )]
+        against: String,
+    },
+
+    /// List the answers consistent with feedback and per-position letter classes
+    Filter {
+        #[command(flatten)]
+        history: HistoryArgs,
+
+        /// Restrict a position (1-5) to vowel, consonant or a set of letters,
+        /// e.g. 1=vowel or 3=rst; repeatable
+        #[arg(long = "pos", value_name = "N=CLASS")]
+        positions: Vec<String>,
+
+        /// Number of answers to list
+        #[arg(long, default_value_t = 100)]
+        top: usize,
+    },
+
+    /// Give a spoiler-free hint about a hidden answer
+    Hint {
+        /// The hidden answer
+        answer: String,
+
+        /// Guesses made so far, comma-separated
+        #[arg(short, long, value_delimiter = ',')]
+        guesses: Vec<String>,
+
+        /// Hint strength: 1 = a letter, 2 = a good guess, 3 = candidate count
+        #[arg(short, long, default_value = "1")]
+        level: u8,
     },
 
     /// Benchmark solver performance
@@ -69,8 +224,76 @@ enum Commands {
         /// Override first word (default: SALET in full mode, auto in answers-only)
         #[arg(short = 'f', long)]
         first_word: Option<String>,
+
+        /// Seed for random word sampling (random if omitted)
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Test the first N answers instead of a random sample
+        #[arg(long, conflicts_with = "seed")]
+        no_shuffle: bool,
+
+        /// Repeat the timed run N times after a warmup and report mean ± std dev
+        #[arg(short, long)]
+        iterations: Option<usize>,
+
+        #[command(flatten)]
+        tuning: AdaptiveTuning,
+
+        /// Compare the adaptive strategy without a beam and at each of these
+        /// beam widths (comma-separated) on the same words
+        #[arg(
+            long,
+            value_name = "WIDTHS",
+            value_delimiter = ',',
+            value_parser = RangedU64ValueParser::<usize>::new().range(1..),
+            conflicts_with_all = ["iterations", "beam"]
+        )]
+        compare_beams: Vec<usize>,
+
+        /// Print the results as colored text, or as Markdown tables for docs and issues
+        #[arg(
+            long,
+            default_value = "text",
+            value_parser = PossibleValuesParser::new(["text", "markdown"])
+        )]
+        format: String,
     },
 
+    /// Compare two strategies' guess sequences on the same answer
+    DiffStrategies {
+        /// First strategy name
+        first: String,
+
+        /// Second strategy name
+        second: String,
+
+        /// Answer to play both strategies against
+        #[arg(long, required_unless_present = "all")]
+        answer: Option<String>,
+
+        /// Run on every answer and list those where the strategies diverge
+        #[arg(long, conflicts_with = "answer")]
+        all: bool,
+    },
+
+    /// Play strategies head-to-head on every answer and count wins, ties and losses
+    Tournament {
+        /// Strategy or preset names (at least two)
+        #[arg(
+            num_args = 2..,
+            required = true,
+            value_parser = PossibleValuesParser::new(
+                StrategyType::NAMES.into_iter().chain(ADAPTIVE_PRESETS.map(|p| p.name))
+            )
+        )]
+        strategies: Vec<String>,
+    },
+
+    /// Check the word lists: every answer valid and in the guess pool, no
+    /// duplicates (exits non-zero on any violation)
+    Verify,
+
     /// Test solver on ALL possible answers
     TestAll {
         /// Limit number of words to test
@@ -80,180 +303,1434 @@ enum Commands {
         /// Override first word (default: SALET in full mode, auto in answers-only)
         #[arg(short = 'f', long)]
         first_word: Option<String>,
+
+        #[command(flatten)]
+        tuning: AdaptiveTuning,
+
+        /// Only test answers the default strategy needs 5+ guesses for
+        #[arg(long, conflicts_with = "filter_guesses")]
+        only_hard: bool,
+
+        /// Only test answers the default strategy needs N+ guesses for
+        #[arg(long, value_name = "N")]
+        filter_guesses: Option<usize>,
+
+        /// Hide progress output (shown as plain lines when output is redirected)
+        #[arg(long)]
+        no_progress: bool,
+    },
+
+    /// Show how many answers fall into each feedback pattern of a guess
+    PatternStats {
+        /// Guess to partition the answers with
+        #[arg(short, long)]
+        guess: String,
+
+        /// Number of patterns to list, largest groups first
+        #[arg(long, default_value_t = 20)]
+        top: usize,
+    },
+
+    /// List the guesses that could have produced each row of a share grid
+    Reconstruct {
+        /// The answer the grid was played against
+        #[arg(short, long)]
+        answer: String,
+
+        /// File holding the share grid, one row of squares per line
+        #[arg(long, value_name = "FILE")]
+        grid: PathBuf,
+
+        /// Number of guesses to list per row
+        #[arg(long, default_value_t = 20)]
+        top: usize,
+    },
+
+    /// Find the answers needing the most guesses via the solver's decision tree
+    WorstCase {
+        /// Override first word (default: SALET in full mode, auto in answers-only)
+        #[arg(short = 'f', long)]
+        first_word: Option<String>,
+
+        /// Write the decision tree to this file in Graphviz DOT format
+        #[arg(long, value_name = "PATH")]
+        dot: Option<PathBuf>,
+
+        /// Load the decision tree from PATH if it was built for these word
+        /// lists and settings; otherwise build it and save it there
+        #[arg(long, value_name = "PATH")]
+        precompute: Option<PathBuf>,
     },
+
+    /// Plan a fixed hard mode opening line and measure it on every answer
+    HardOpening {
+        /// First word of the line (default: SALET in full mode, auto in answers-only)
+        #[arg(short = 'f', long)]
+        first_word: Option<String>,
+    },
+
+    /// List the large groups of answers an opener can't tell apart
+    Traps {
+        /// Opener to group the answers by (default: SALET in full mode, auto in answers-only)
+        #[arg(short, long)]
+        opener: Option<String>,
+
+        /// Smallest group to report
+        #[arg(long, default_value_t = DEFAULT_TRAP_SIZE)]
+        min_size: usize,
+
+        /// Number of groups to list, largest first
+        #[arg(long, default_value_t = 20)]
+        top: usize,
+    },
+
+    /// Find the fewest guesses that guarantee a solve after some clues
+    MinGuaranteed {
+        #[command(flatten)]
+        history: HistoryArgs,
+
+        /// Refuse positions with more candidates than this (at most 64)
+        #[arg(long, default_value_t = DEFAULT_EXACT_LIMIT, value_name = "N")]
+        max_candidates: usize,
+    },
+
+    /// Show how many candidates an opener leaves across all answers
+    FirstGuess {
+        /// Opener to analyze (default: SALET in full mode, auto in answers-only)
+        #[arg(short, long)]
+        opener: Option<String>,
+    },
+
+    /// Review a played game: grade each guess against the best available
+    Coach {
+        #[command(flatten)]
+        history: HistoryArgs,
+    },
+}
+
+/// Overrides for the adaptive strategy's tuning parameters, plus solver
+/// options that apply to any strategy
+#[derive(Args, Debug, Clone, Copy)]
+struct AdaptiveTuning {
+    /// One dial for the adaptive strategy's worst-case vs average trade-off,
+    /// from 0 (safe, minimax) to 1 (aggressive, entropy); 0.5 is the default
+    /// tuning. The 3-9 candidate tier only follows it above
+    /// --endgame-threshold. The flags below override individual settings.
+    #[arg(long, value_name = "T")]
+    temperature: Option<f64>,
+
+    /// Worst-case penalty λ for the adaptive strategy's 22-100 candidate tier
+    #[arg(long, value_name = "LAMBDA")]
+    minimax_penalty: Option<f64>,
+
+    /// Candidate-preference epsilon for the adaptive strategy's 3-9 candidate
+    /// tier (default 0.1). Only used above --endgame-threshold, which by
+    /// default covers the whole tier; lower it to tune epsilon.
+    #[arg(long, value_name = "EPSILON")]
+    minimax_epsilon: Option<f64>,
+
+    /// Largest candidate count the adaptive strategy solves by exhaustive
+    /// endgame search (default 9; 0 disables it)
+    #[arg(long, value_name = "N")]
+    endgame_threshold: Option<usize>,
+
+    /// Compute entropy for only the N guesses with the best letter-frequency
+    /// scores in the adaptive strategy's 101+ candidate tier (faster, may
+    /// cost guesses)
+    #[arg(long, value_name = "N", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    beam: Option<usize>,
+
+    /// Penalize guesses that play letters already proven absent (any strategy)
+    #[arg(long)]
+    soft_hard_mode: bool,
+}
+
+impl AdaptiveTuning {
+    /// Apply the overrides if `strategy` is adaptive; other strategies are unchanged
+    fn apply(&self, strategy: &mut StrategyType) {
+        if let StrategyType::Adaptive(adaptive) = strategy {
+            if let Some(temperature) = self.temperature {
+                *adaptive = adaptive.clone().with_temperature(temperature);
+            }
+            if let Some(penalty) = self.minimax_penalty {
+                adaptive.minimax_penalty = penalty;
+            }
+            if let Some(epsilon) = self.minimax_epsilon {
+                adaptive.minimax_first_epsilon = epsilon;
+            }
+            if let Some(threshold) = self.endgame_threshold {
+                adaptive.endgame_threshold = threshold;
+            }
+            if self.minimax_epsilon.is_some()
+                && adaptive.endgame_threshold >= adaptive.hybrid_threshold
+            {
+                eprintln!(
+                    "Warning: --minimax-epsilon has no effect while the endgame search \
+                     covers its whole tier; lower --endgame-threshold to use it"
+                );
+            }
+            if let Some(width) = self.beam {
+                adaptive.beam_width = Some(width);
+            }
+        }
+    }
+
+    /// Describe the overrides that are set, one per line
+    fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if let Some(temperature) = self.temperature {
+            lines.push(format!("Temperature: {temperature}"));
+        }
+        if let Some(penalty) = self.minimax_penalty {
+            lines.push(format!("Minimax penalty: {penalty}"));
+        }
+        if let Some(epsilon) = self.minimax_epsilon {
+            lines.push(format!("Minimax-first epsilon: {epsilon}"));
+        }
+        if let Some(threshold) = self.endgame_threshold {
+            lines.push(format!("Endgame threshold: {threshold}"));
+        }
+        if let Some(width) = self.beam {
+            lines.push(format!("Beam width: {width}"));
+        }
+        if self.soft_hard_mode {
+            lines.push("Soft hard mode: on".to_string());
+        }
+        lines
+    }
+
+    /// Print the overrides that are set, one per line
+    fn print(&self) {
+        for line in self.lines() {
+            println!("{line}");
+        }
+    }
+}
+
+/// Relative likelihood of an answer, owned for the whole run
+type WeightFn = Box<dyn Fn(&Word) -> f64 + Sync>;
+
+/// Word lists selected with -w, plus the opener precomputed for them and the
+/// solver settings every command shares
+struct WordLists {
+    /// Guess pool
+    all: Vec<Word>,
+    /// Answer candidates
+    answers: Vec<Word>,
+    /// First guess from a custom list's manifest, used by every strategy
+    opener: Option<Word>,
+    /// First guess computed once per run for the named strategy
+    computed_opener: Option<(String, Word)>,
+    /// Entries dropped while loading because they aren't valid words
+    rejected: Vec<String>,
+    /// When to guess the likeliest candidate outright
+    commit: Option<CommitRule>,
+    /// Relative likelihood of each answer (uniform if unset)
+    answer_weight: Option<WeightFn>,
+    /// [`artifact::content_hash`] of the answer weights file, for artifact keys
+    answer_weights_hash: Option<u64>,
+    /// Manifest of a custom list, which `opener` came from
+    manifest: Option<Manifest>,
+}
+
+impl WordLists {
+    /// The settled opener for the strategy named `strategy_name`, if any
+    ///
+    /// A computed opener only applies to the strategy it was computed for.
+    fn opener_for(&self, strategy_name: &str) -> Option<&Word> {
+        self.opener.as_ref().or_else(|| {
+            self.computed_opener
+                .as_ref()
+                .filter(|(name, _)| name == strategy_name)
+                .map(|(_, opener)| opener)
+        })
+    }
+
+    /// Build a solver over these lists for the strategy named
+    /// `strategy_name`, opening with the list's opener for it
+    ///
+    /// Without one, the solver is prepared so the default opener is found
+    /// once rather than every game.
+    fn solver<S: Strategy>(&self, strategy_name: &str, strategy: S) -> Solver<'_, S> {
+        let solver = match self.opener_for(strategy_name) {
+            Some(opener) => {
+                Solver::new(strategy, &self.all, &self.answers).with_opener(Some(opener))
+            }
+            None => Solver::prepared(strategy, &self.all, &self.answers),
+        };
+        solver
+            .with_commit(self.commit)
+            .with_answer_weight(self.answer_weight.as_deref())
+    }
 }
 
 /// Load wordlists based on the -w flag
 ///
-/// Returns (`guess_pool`, `answer_candidates`)
 /// - "all": Use all 12,972 words for guessing, 2,315 as candidates
 /// - "answers": Use only 2,315 words for both (demonstrates exploration paradox)
-/// - "<path>": Load custom wordlist from file
-fn load_wordlists(wordlist_mode: &str) -> Result<(Vec<Word>, Vec<Word>)> {
-    use wordle_solver::wordlists::loader::load_from_file;
-
+/// - "<path>": Load custom wordlist from file, with the opener from its
+///   manifest if it has one (unless `ignore_manifest`, when replacing it)
+///
+/// With `strict`, a file with any invalid entry is an error.
+fn load_wordlists(wordlist_mode: &str, ignore_manifest: bool, strict: bool) -> Result<WordLists> {
     match wordlist_mode {
         "all" => {
             // Default: full search space
-            let all_words = words_from_slice(ALLOWED);
-            let answer_words = words_from_slice(ANSWERS);
-            Ok((all_words, answer_words))
+            Ok(WordLists {
+                all: words_from_slice(ALLOWED),
+                answers: words_from_slice(ANSWERS),
+                opener: None,
+                computed_opener: None,
+                commit: None,
+                answer_weight: None,
+                answer_weights_hash: None,
+                manifest: None,
+                rejected: rejected_embedded(&[ALLOWED, ANSWERS]),
+            })
         }
         "answers" => {
             // Answers-only mode: demonstrates exploration paradox
             let answer_words = words_from_slice(ANSWERS);
-            Ok((answer_words.clone(), answer_words))
+            Ok(WordLists {
+                all: answer_words.clone(),
+                answers: answer_words,
+                opener: None,
+                computed_opener: None,
+                commit: None,
+                answer_weight: None,
+                answer_weights_hash: None,
+                manifest: None,
+                rejected: rejected_embedded(&[ANSWERS]),
+            })
         }
         path => {
             // Load from custom file
-            let custom_words = load_from_file(path)?;
-            let answer_words = words_from_slice(ANSWERS);
-            Ok((custom_words, answer_words))
+            let (custom_words, rejected) = load_list_file(Path::new(path), strict)
+                .map_err(|e| anyhow::anyhow!("Cannot load wordlist {path}: {e}"))?;
+
+            // A bad manifest is an error rather than a silent fallback, so a
+            // stale opener never goes unnoticed
+            let invalid = |e| {
+                let manifest = manifest_path(Path::new(path));
+                anyhow::anyhow!("Invalid manifest {}: {e}", manifest.display())
+            };
+            let manifest = if ignore_manifest {
+                None
+            } else {
+                Manifest::load(Path::new(path)).map_err(invalid)?
+            };
+            let opener = match &manifest {
+                Some(manifest) => manifest.validate(&custom_words).map_err(invalid)?.cloned(),
+                None => None,
+            };
+
+            let mut rejected: Vec<String> = rejected.iter().map(ToString::to_string).collect();
+            rejected.extend(rejected_embedded(&[ANSWERS]));
+            Ok(WordLists {
+                all: custom_words,
+                answers: words_from_slice(ANSWERS),
+                opener,
+                computed_opener: None,
+                commit: None,
+                answer_weight: None,
+                answer_weights_hash: None,
+                manifest,
+                rejected,
+            })
         }
     }
 }
 
+/// Load a word list file, warning about skipped entries
+///
+/// With `strict`, any invalid entry fails the load instead.
+fn load_list_file(path: &Path, strict: bool) -> std::io::Result<(Vec<Word>, Vec<RejectedEntry>)> {
+    use wordle_solver::wordlists::loader::{load_from_file_checked, load_from_file_strict};
+
+    if strict {
+        return load_from_file_strict(path).map(|words| (words, Vec::new()));
+    }
+    let (words, rejected) = load_from_file_checked(path)?;
+    warn_rejected(&path.display().to_string(), &rejected);
+    Ok((words, rejected))
+}
+
+/// Report entries skipped while loading a word list file
+fn warn_rejected(path: &str, rejected: &[RejectedEntry]) {
+    if rejected.is_empty() {
+        return;
+    }
+    eprintln!(
+        "Warning: skipped {} invalid entries in {path}:",
+        rejected.len()
+    );
+    for entry in rejected.iter().take(10) {
+        eprintln!("  {entry}");
+    }
+    if rejected.len() > 10 {
+        eprintln!("  ... and {} more", rejected.len() - 10);
+    }
+}
+
+/// Replace the answers with a file's words (e.g. a themed subset)
+///
+/// The guess pool is kept, so SALET still opens when it's in the pool.
+/// Answers missing from the pool are added later, by
+/// [`add_missing_answers_to_pool`].
+fn load_answers(lists: &mut WordLists, path: &Path, strict: bool) -> Result<()> {
+    let display = path.display().to_string();
+    let (answers, rejected) = load_list_file(path, strict)
+        .map_err(|e| anyhow::anyhow!("Cannot load answers {display}: {e}"))?;
+    if answers.is_empty() {
+        anyhow::bail!("{display} has no valid answers");
+    }
+
+    lists
+        .rejected
+        .extend(rejected.iter().map(ToString::to_string));
+    lists.answers = answers;
+    Ok(())
+}
+
+/// Weight answers by the `word weight` lines in the file at `path`
+fn load_answer_weights(lists: &mut WordLists, path: &Path) -> Result<()> {
+    let display = path.display().to_string();
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read answer weights {display}: {e}"))?;
+    let weights = parse_answer_weights(&content).map_err(|e| anyhow::anyhow!("{display}: {e}"))?;
+    lists.answer_weights_hash = Some(artifact::content_hash(content.as_bytes()));
+    lists.answer_weight = Some(Box::new(move |word: &Word| {
+        weights.get(word.text()).copied().unwrap_or(1.0)
+    }));
+    Ok(())
+}
+
+/// Forget a saved opener computed for another answer list, so it is
+/// recomputed for the one loaded
+fn drop_opener_for_other_answers(lists: &mut WordLists) {
+    if lists
+        .manifest
+        .as_ref()
+        .is_some_and(|manifest| !manifest.fits_answers(&lists.answers))
+        && let Some(opener) = lists.opener.take()
+    {
+        eprintln!(
+            "Not using the saved opener {}: it was computed for another answer list",
+            word_label(&opener)
+        );
+    }
+}
+
+/// Answers listed when warning that they were added to the guess pool
+const ADDED_ANSWERS_SHOWN: usize = 10;
+
+/// Add the answers missing from the guess pool to it, warning about each
+///
+/// Whatever their source (`--answers`, or the embedded answers next to a
+/// custom `-w` pool), an answer outside the pool could be narrowed down to
+/// but never guessed. Runs after the opener is settled, so a saved
+/// manifest still matches the list file's word count.
+fn add_missing_answers_to_pool(lists: &mut WordLists) {
+    let added = add_missing_answers(&mut lists.all, &lists.answers);
+    if added.is_empty() {
+        return;
+    }
+    let shown: Vec<String> = added
+        .iter()
+        .take(ADDED_ANSWERS_SHOWN)
+        .map(word_label)
+        .collect();
+    let more = added.len().saturating_sub(ADDED_ANSWERS_SHOWN);
+    eprintln!(
+        "Warning: added {} answers missing from the guess pool so they can be guessed: {}{}",
+        added.len(),
+        shown.join(", "),
+        if more > 0 {
+            format!(" ... and {more} more")
+        } else {
+            String::new()
+        }
+    );
+}
+
+/// Embedded entries that aren't valid words (there should be none)
+fn rejected_embedded(lists: &[&[&str]]) -> Vec<String> {
+    lists
+        .iter()
+        .flat_map(|list| list.iter())
+        .filter_map(|text| {
+            Word::new(*text)
+                .err()
+                .map(|e| format!("'{text}' rejected: {e}"))
+        })
+        .collect()
+}
+
+/// Settle the opener for a custom wordlist before any game starts
+///
+/// Without a manifest opener or SALET, the strategy's opener is computed once
+/// here rather than at the start of every game. With `save` (which skipped
+/// any existing manifest), it is also written to the list's manifest for
+/// later runs.
+fn prepare_opener(
+    lists: &mut WordLists,
+    wordlist_mode: &str,
+    strategy_name: &str,
+    save: bool,
+) -> Result<()> {
+    let is_custom = !matches!(wordlist_mode, "all" | "answers");
+    if save && !is_custom {
+        anyhow::bail!("--save-opener needs a custom wordlist path (-w <path>)");
+    }
+    let has_salet = lists.all.iter().any(|w| w.text() == "salet");
+    if !is_custom || (!save && (lists.opener.is_some() || has_salet)) {
+        return Ok(());
+    }
+
+    if lists.opener.is_none() && !has_salet {
+        eprintln!(
+            "Computing the opener for {wordlist_mode} ({} words)...",
+            lists.all.len()
+        );
+    }
+    let opener = lists
+        .solver(strategy_name, StrategyType::from_name(strategy_name))
+        .first_guess()
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("{wordlist_mode} has no words to open with"))?;
+
+    if save {
+        let manifest = Manifest {
+            opener: Some(opener.text().to_string()),
+            words: Some(lists.all.len()),
+            answers: Some(answers_hash(&lists.answers)),
+        };
+        let written = manifest.save(Path::new(wordlist_mode))?;
+        eprintln!(
+            "Saved opener {} to {}",
+            word_label(&opener),
+            written.display()
+        );
+        // Saved to the manifest, so it now applies to every strategy
+        lists.opener = Some(opener);
+    } else {
+        lists.computed_opener = Some((strategy_name.to_string(), opener));
+    }
+    Ok(())
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
     // Load word lists based on -w flag
-    let (all_words, answer_words) = load_wordlists(&cli.wordlist)?;
+    let mut lists = load_wordlists(&cli.wordlist, cli.save_opener, cli.strict_wordlist)?;
+    if let Some(path) = &cli.answers {
+        load_answers(&mut lists, path, cli.strict_wordlist)?;
+    }
+    drop_opener_for_other_answers(&mut lists);
+    if let Some(path) = &cli.answer_weights {
+        load_answer_weights(&mut lists, path)?;
+    }
+    if cli.commit_at.is_some() || cli.commit_probability.is_some() {
+        // Each flag adds its own condition; one candidate is always guessed
+        lists.commit = Some(CommitRule {
+            max_candidates: cli.commit_at.unwrap_or(1),
+            min_probability: cli.commit_probability.unwrap_or(f64::INFINITY),
+        });
+    }
 
     // Default to Play mode if no command given
-    let command = cli.command.unwrap_or(Commands::Play);
+    let command = cli.command.unwrap_or_else(|| Commands::Play {
+        beam: None,
+        theme: "default".to_string(),
+    });
+
+    // Presets are named adaptive configurations, so they stand in for the name
+    let strategy = match cli.preset.as_deref() {
+        Some(preset) if cli.strategy != "adaptive" => anyhow::bail!(
+            "--preset {preset} configures the adaptive strategy; it can't be combined with --strategy {}",
+            cli.strategy
+        ),
+        Some(preset) => preset,
+        None => cli.strategy.as_str(),
+    };
 
+    // Analyze, coach and verify never play a game, so they don't need an opener
+    if cli.save_opener
+        || !matches!(
+            command,
+            Commands::Analyze { .. } | Commands::Coach { .. } | Commands::Verify
+        )
+    {
+        prepare_opener(&mut lists, &cli.wordlist, strategy, cli.save_opener)?;
+    }
+    // Verify reports answers missing from the pool instead of fixing them
+    if !matches!(command, Commands::Verify) {
+        add_missing_answers_to_pool(&mut lists);
+    }
+    run_command(command, strategy, &lists)
+}
+
+/// Run one subcommand against the loaded word lists
+#[allow(clippy::too_many_lines)] // One arm per subcommand
+fn run_command(command: Commands, strategy: &str, lists: &WordLists) -> Result<()> {
     match command {
-        Commands::Play => run_play_command(&all_words, &answer_words),
-        Commands::Simple => run_simple_command(&cli.strategy, &all_words, &answer_words),
-        Commands::Solve { word, verbose } => {
-            run_solve_command(&cli.strategy, &word, verbose, &all_words, &answer_words)
-        }
-        Commands::Analyze { word } => run_analyze_command(&word, &all_words, &answer_words),
-        Commands::Benchmark { count, first_word } => {
-            run_benchmark_command(
-                &cli.strategy,
+        Commands::Play { beam, theme } => run_play_command(&theme, beam, lists),
+        Commands::Simple { reveal_at } => run_simple_command(strategy, reveal_at, lists),
+        Commands::Solve { word, output } => run_solve_command(strategy, &word, output, lists),
+        Commands::Analyze {
+            word,
+            history,
+            all_openers: _,
+            csv,
+            percentiles,
+            skill,
+            breakdown,
+            against,
+        } => match word {
+            Some(word) => {
+                let view = AnalyzeView::from_flags(skill, breakdown);
+                let against = CandidateSet::from_name(&against).unwrap_or_default();
+                run_analyze_command(&word, &history, view, against, lists)
+            }
+            None => run_openers_command(csv.as_deref(), percentiles, lists),
+        },
+        Commands::Filter {
+            history,
+            positions,
+            top,
+        } => run_filter_command(&history, &positions, top, lists),
+        Commands::Hint {
+            answer,
+            guesses,
+            level,
+        } => run_hint_command(strategy, &answer, &guesses, level, lists),
+        Commands::Benchmark {
+            count,
+            first_word,
+            seed,
+            no_shuffle,
+            iterations,
+            tuning,
+            compare_beams,
+            format,
+        } => {
+            let options = BenchmarkOptions {
                 count,
-                first_word.as_deref(),
-                &all_words,
-                &answer_words,
-            );
-            Ok(())
+                first_word: first_word.as_deref(),
+                sampling: Sampling::from_flags(no_shuffle, seed),
+                iterations,
+                tuning,
+                compare_beams,
+                markdown: format == "markdown",
+            };
+            run_benchmark_command(strategy, &options, lists)
         }
-        Commands::TestAll { limit, first_word } => {
-            run_test_all_command(
-                &cli.strategy,
+        Commands::DiffStrategies {
+            first,
+            second,
+            answer,
+            all: _,
+        } => run_diff_command(&first, &second, answer.as_deref(), lists),
+        Commands::Tournament { strategies } => run_tournament_command(&strategies, lists),
+        Commands::Verify => run_verify_command(lists),
+        Commands::TestAll {
+            limit,
+            first_word,
+            tuning,
+            only_hard,
+            filter_guesses,
+            no_progress,
+        } => {
+            let options = TestAllOptions {
                 limit,
-                first_word.as_deref(),
-                &all_words,
-                &answer_words,
-            );
-            Ok(())
+                first_word: first_word.as_deref(),
+                tuning,
+                min_guesses: filter_guesses.or(only_hard.then_some(HARD_GUESS_THRESHOLD)),
+                progress: ProgressMode::detect(no_progress),
+            };
+            run_test_all_command(strategy, &options, lists)
+        }
+        Commands::PatternStats { guess, top } => run_pattern_stats_command(&guess, top, lists),
+        Commands::Reconstruct { answer, grid, top } => {
+            run_reconstruct_command(&answer, &grid, top, lists)
+        }
+        Commands::WorstCase {
+            first_word,
+            dot,
+            precompute,
+        } => run_worst_case_command(
+            strategy,
+            first_word.as_deref(),
+            dot.as_deref(),
+            precompute.as_deref(),
+            lists,
+        ),
+        Commands::HardOpening { first_word } => {
+            run_hard_opening_command(strategy, first_word.as_deref(), lists)
         }
+        Commands::Traps {
+            opener,
+            min_size,
+            top,
+        } => run_traps_command(opener.as_deref(), min_size, top, lists),
+        Commands::MinGuaranteed {
+            history,
+            max_candidates,
+        } => run_min_guaranteed_command(&history, max_candidates, lists),
+        Commands::FirstGuess { opener } => run_first_guess_command(opener.as_deref(), lists),
+        Commands::Coach { history } => run_coach_command(&history, lists),
     }
 }
 
+/// Output options for the solve command
+#[derive(Args, Debug, Clone, Copy)]
+struct SolveOutput {
+    /// Show verbose output with candidate counts
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Show the top K alternative guesses at each step
+    #[arg(short, long, default_value = "0", value_name = "K")]
+    alternatives: usize,
+
+    /// Print a shareable emoji grid of the result
+    #[arg(long)]
+    share: bool,
+
+    /// Print only a one-line summary (target, count, ✓/✗, guesses, time)
+    #[arg(long, conflicts_with_all = ["verbose", "alternatives", "share"])]
+    line: bool,
+}
+
 fn run_solve_command(
     strategy_name: &str,
     word: &str,
-    verbose: bool,
-    all_words: &[Word],
-    answer_words: &[Word],
+    output: SolveOutput,
+    lists: &WordLists,
 ) -> Result<()> {
-    let strategy = StrategyType::from_name(strategy_name);
-    let solver = Solver::new(strategy, all_words, answer_words);
-    solve_command(word, verbose, &solver)
+    let solver = lists.solver(strategy_name, StrategyType::from_name(strategy_name));
+    solve_command(word, output, &solver)
 }
 
-fn solve_command<S: Strategy>(word: &str, verbose: bool, solver: &Solver<S>) -> Result<()> {
-    let config = SolveConfig::new(word.to_string());
+fn solve_command<S: Strategy>(word: &str, output: SolveOutput, solver: &Solver<S>) -> Result<()> {
+    let mut config = SolveConfig::new(word.to_string());
+    config.alternatives = output.alternatives;
     let result = solve_word(config, solver).map_err(|e| anyhow::anyhow!(e))?;
 
-    print_solve_result(&result, verbose);
+    if output.line {
+        println!("{}", result.summary_line());
+    } else {
+        print_solve_result(&result, output.verbose, output.share);
+    }
     Ok(())
 }
 
-fn run_analyze_command(word: &str, all_words: &[Word], answer_words: &[Word]) -> Result<()> {
-    let result = analyze_word(word, all_words, answer_words).map_err(|e| anyhow::anyhow!(e))?;
+/// What `analyze` reports for a single word
+#[derive(Clone, Copy)]
+enum AnalyzeView {
+    Entropy,
+    /// Entropy plus a per-position breakdown
+    Breakdown,
+    Skill,
+}
+
+impl AnalyzeView {
+    /// Pick the view from the mutually exclusive --skill and --breakdown flags
+    const fn from_flags(skill: bool, breakdown: bool) -> Self {
+        if skill {
+            Self::Skill
+        } else if breakdown {
+            Self::Breakdown
+        } else {
+            Self::Entropy
+        }
+    }
+}
+
+/// Earlier guesses and their feedback, given as `--guess` and `--pattern`
+#[derive(Args, Debug, Clone)]
+struct HistoryArgs {
+    /// Prior guesses, comma-separated (paired with --pattern)
+    #[arg(short, long, value_delimiter = ',')]
+    guess: Vec<String>,
+
+    /// Feedback for each prior guess, comma-separated (e.g. ---Y-)
+    #[arg(short, long, value_delimiter = ',', allow_hyphen_values = true)]
+    pattern: Vec<String>,
+}
+
+impl HistoryArgs {
+    /// Pair the guesses with their patterns
+    fn parse(&self) -> Result<Vec<(Word, Pattern)>> {
+        if self.guess.len() != self.pattern.len() {
+            anyhow::bail!(
+                "Got {} guesses but {} patterns; each --guess needs a --pattern",
+                self.guess.len(),
+                self.pattern.len()
+            );
+        }
+
+        self.guess
+            .iter()
+            .zip(&self.pattern)
+            .map(|(g, p)| {
+                let guess = Word::new(g.as_str())
+                    .map_err(|e| anyhow::anyhow!("Invalid guess '{g}': {e}"))?;
+                let pattern =
+                    Pattern::from_str(p).ok_or_else(|| anyhow::anyhow!("Invalid pattern '{p}'"))?;
+                Ok((guess, pattern))
+            })
+            .collect()
+    }
+}
+
+fn run_filter_command(
+    history: &HistoryArgs,
+    positions: &[String],
+    top: usize,
+    lists: &WordLists,
+) -> Result<()> {
+    let mut constraints = Constraints::new(history.parse()?);
+    for spec in positions {
+        let (position, class) = parse_position_class(spec).map_err(|e| anyhow::anyhow!(e))?;
+        constraints.restrict(position, class);
+    }
+
+    let matches = filter_answers(&lists.answers, &constraints);
+    print_filter_result(&matches, lists.answers.len(), top);
+    Ok(())
+}
+
+fn run_analyze_command(
+    word: &str,
+    history: &HistoryArgs,
+    view: AnalyzeView,
+    against: CandidateSet,
+    lists: &WordLists,
+) -> Result<()> {
+    let history = history.parse()?;
+
+    if let AnalyzeView::Skill = view {
+        let score = skill_score(word, &lists.all, &lists.answers, &history)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        print_skill_score(&score);
+        return Ok(());
+    }
+
+    let result = analyze_word(word, &lists.all, &lists.answers, &history, against)
+        .map_err(|e| anyhow::anyhow!(e))?;
     print_analysis_result(&result);
+    if let AnalyzeView::Breakdown = view {
+        let candidates = against.pick(&lists.all, &lists.answers);
+        let breakdown =
+            entropy_breakdown(word, candidates, &history).map_err(|e| anyhow::anyhow!(e))?;
+        print_entropy_breakdown(&breakdown);
+    }
     Ok(())
 }
 
-fn run_benchmark_command(
+fn run_openers_command(csv: Option<&Path>, percentiles: bool, lists: &WordLists) -> Result<()> {
+    println!(
+        "Scoring {} openers against {} answers...",
+        lists.all.len(),
+        lists.answers.len()
+    );
+    let openers = rank_openers(&lists.all, &lists.answers);
+
+    if let Some(path) = csv {
+        let file = File::create(path)
+            .map_err(|e| anyhow::anyhow!("Cannot create {}: {e}", path.display()))?;
+        write_openers_csv(BufWriter::new(file), &openers)?;
+        println!("Wrote {} openers to {}", openers.len(), path.display());
+    }
+
+    if csv.is_some() || percentiles {
+        print_opener_table(&openers, 10);
+    } else {
+        print_opener_table(&openers, openers.len());
+    }
+    if percentiles {
+        // Place the solver's opener among the percentiles
+        let opener = lists.opener.as_ref().map_or("salet", Word::text);
+        print_opener_percentiles(
+            &opener_percentiles(&openers, &OPENER_PERCENTILES),
+            openers.len(),
+            opener_rank(&openers, opener).as_ref(),
+        );
+    }
+    Ok(())
+}
+
+/// How benchmark target words are chosen from the answer list
+#[derive(Clone, Copy)]
+enum Sampling {
+    /// First N answers in list order
+    Prefix,
+    /// Random sample of N answers with the given seed
+    Random(u64),
+}
+
+impl Sampling {
+    /// Pick the sampling from --no-shuffle and --seed, drawing a seed if none is given
+    fn from_flags(no_shuffle: bool, seed: Option<u64>) -> Self {
+        if no_shuffle {
+            Self::Prefix
+        } else {
+            Self::Random(seed.unwrap_or_else(rand::random))
+        }
+    }
+}
+
+fn run_hint_command(
     strategy_name: &str,
-    count: usize,
-    first_word: Option<&str>,
-    all_words: &[Word],
-    answer_words: &[Word],
-) {
-    let strategy = StrategyType::from_name(strategy_name);
-    let solver = Solver::new(strategy, all_words, answer_words);
-    benchmark_command(count, first_word, &solver, all_words, answer_words);
+    answer: &str,
+    guesses: &[String],
+    level: u8,
+    lists: &WordLists,
+) -> Result<()> {
+    let answer = Word::new(answer).map_err(|e| anyhow::anyhow!("Invalid answer: {e}"))?;
+    let history = guesses
+        .iter()
+        .map(|g| {
+            let guess =
+                Word::new(g.as_str()).map_err(|e| anyhow::anyhow!("Invalid guess '{g}': {e}"))?;
+            let pattern = Pattern::calculate(&guess, &answer);
+            Ok((guess, pattern))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Seeded so the suggested guess is the same every run
+    let solver = lists.solver(
+        strategy_name,
+        StrategyType::from_name(strategy_name).with_random_seed(0),
+    );
+    let text = hint(&solver, &history, &answer, level).map_err(|e| anyhow::anyhow!(e))?;
+    let clues = clue_summary(&history);
+    if !clues.is_empty() {
+        println!("Your guesses so far show:");
+        for line in clues {
+            println!("  • {line}");
+        }
+        println!();
+    }
+    println!("💡 {text}");
+    Ok(())
 }
 
-fn benchmark_command<S: Strategy>(
+/// Options for the benchmark command
+struct BenchmarkOptions<'a> {
     count: usize,
+    first_word: Option<&'a str>,
+    sampling: Sampling,
+    iterations: Option<usize>,
+    tuning: AdaptiveTuning,
+    /// Beam widths to compare against the unbeamed adaptive strategy
+    compare_beams: Vec<usize>,
+    /// Print Markdown tables instead of colored text
+    markdown: bool,
+}
+
+fn run_benchmark_command(
+    strategy_name: &str,
+    options: &BenchmarkOptions,
+    lists: &WordLists,
+) -> Result<()> {
+    let mut strategy = StrategyType::from_name(strategy_name);
+    options.tuning.apply(&mut strategy);
+    let index = WordIndex::new(&lists.all);
+    let forced_first = resolve_first_word(&index, options.first_word)?;
+
+    let count = options.count;
+    let description = match options.sampling {
+        Sampling::Prefix => format!("the first {count} words"),
+        Sampling::Random(seed) => format!("{count} random words (seed: {seed})"),
+    };
+    let intro = match forced_first {
+        Some(first) => format!(
+            "Running benchmark on {description} with forced first word {}...",
+            first_word_entropy(first, &lists.answers)
+        ),
+        None => format!("Running benchmark on {description}..."),
+    };
+    // Markdown goes to stdout alone, so it can be piped into a doc
+    if options.markdown {
+        eprintln!("{intro}");
+        for line in options.tuning.lines() {
+            eprintln!("{line}");
+        }
+    } else {
+        println!("{intro}");
+        options.tuning.print();
+    }
+
+    let test_words: Vec<Word> = match options.sampling {
+        Sampling::Prefix => lists.answers.iter().take(count).cloned().collect(),
+        Sampling::Random(seed) => sample_words(&lists.answers, count, seed),
+    };
+
+    if !options.compare_beams.is_empty() {
+        let StrategyType::Adaptive(adaptive) = strategy else {
+            anyhow::bail!("--compare-beams needs the adaptive strategy, not '{strategy_name}'");
+        };
+        let solver = lists
+            .solver(strategy_name, adaptive)
+            .with_soft_hard_mode(options.tuning.soft_hard_mode);
+        let runs = compare_beam_widths(&solver, &options.compare_beams, &test_words, forced_first);
+        if options.markdown {
+            print_beam_comparison_markdown(&runs);
+        } else {
+            print_beam_comparison(&runs);
+        }
+        return Ok(());
+    }
+
+    let solver = lists
+        .solver(strategy_name, strategy)
+        .with_soft_hard_mode(options.tuning.soft_hard_mode);
+    let result = match options.iterations {
+        Some(n) => run_benchmark_iterations(&solver, &test_words, forced_first, n.max(1)),
+        None => run_benchmark(&solver, &test_words, forced_first),
+    };
+    if options.markdown {
+        print_benchmark_markdown(&result);
+    } else {
+        print_benchmark_result(&result);
+    }
+    Ok(())
+}
+
+/// Describe a forced first word's entropy against the answers, e.g.
+/// "TARES: 5.88 bits", so a weak opener is noticed before the run
+fn first_word_entropy(first: &Word, answers: &[Word]) -> String {
+    let answer_refs: Vec<&Word> = answers.iter().collect();
+    format!(
+        "{}: {:.2} bits",
+        word_label(first),
+        calculate_entropy(first, &answer_refs)
+    )
+}
+
+/// Look up a forced first word, failing if it isn't in the guess pool
+///
+/// An unknown word is an error rather than silently falling back to the
+/// solver's own opener, which would make results look like the word was tested.
+fn resolve_first_word<'a>(
+    index: &WordIndex<'a>,
     first_word: Option<&str>,
-    solver: &Solver<S>,
-    all_words: &[Word],
-    answer_words: &[Word],
-) {
-    if let Some(word_str) = first_word {
-        println!("Running benchmark on {count} random words with forced first word: {word_str}...");
+) -> Result<Option<&'a Word>> {
+    first_word
+        .map(|text| {
+            index.find(text).ok_or_else(|| {
+                anyhow::anyhow!("'{text}' is not a valid word in the current wordlist")
+            })
+        })
+        .transpose()
+}
+
+fn run_diff_command(
+    first_name: &str,
+    second_name: &str,
+    answer: Option<&str>,
+    lists: &WordLists,
+) -> Result<()> {
+    let answer_words = &lists.answers;
+    // Seed random choices so divergences reflect the strategies, not chance
+    let first = lists.solver(
+        first_name,
+        StrategyType::from_name(first_name).with_random_seed(0),
+    );
+    let second = lists.solver(
+        second_name,
+        StrategyType::from_name(second_name).with_random_seed(0),
+    );
+
+    if let Some(answer) = answer {
+        let answer = Word::new(answer).map_err(|e| anyhow::anyhow!("Invalid answer: {e}"))?;
+        let diff = diff_strategies(&first, &second, &answer);
+        print_strategy_diff(&diff, first_name, second_name);
     } else {
-        println!("Running benchmark on {count} random words...");
+        println!(
+            "Diffing {first_name} and {second_name} on {} answers...",
+            answer_words.len()
+        );
+        let diffs = diff_strategies_all(&first, &second, answer_words);
+        print_strategy_diff_summary(&diffs, answer_words.len(), first_name, second_name);
     }
+    Ok(())
+}
 
-    // Take first N words from answer list
-    let test_words: Vec<Word> = answer_words.iter().take(count).cloned().collect();
+fn run_tournament_command(names: &[String], lists: &WordLists) -> Result<()> {
+    // Seed random choices so every run plays the same games
+    let strategies: Vec<StrategyType> = names
+        .iter()
+        .map(|name| StrategyType::from_name(name).with_random_seed(0))
+        .collect();
+    for (i, strategy) in strategies.iter().enumerate() {
+        if let Some(first) = strategies[..i].iter().position(|s| s == strategy) {
+            anyhow::bail!(
+                "Strategy '{}' is the same as '{}' (positions {} and {})",
+                names[i],
+                names[first],
+                first + 1,
+                i + 1
+            );
+        }
+    }
 
-    // Convert first_word to Word if provided
-    let forced_first =
-        first_word.and_then(|word_str| all_words.iter().find(|w| w.text() == word_str));
+    let entrants: Vec<_> = names
+        .iter()
+        .zip(strategies)
+        .map(|(name, strategy)| lists.solver(name, strategy))
+        .collect();
 
-    let result = run_benchmark(solver, &test_words, forced_first);
-    print_benchmark_result(&result);
+    println!(
+        "Playing {} strategies on {} answers...",
+        entrants.len(),
+        lists.answers.len()
+    );
+    let result = tournament(&entrants, &lists.answers);
+    print_tournament(&result, names);
+    Ok(())
+}
+
+fn run_verify_command(lists: &WordLists) -> Result<()> {
+    let report = verify_lists(&lists.all, &lists.answers, lists.rejected.clone());
+    print_verify_report(&report);
+    if !report.is_ok() {
+        anyhow::bail!("{} word list violations", report.violations());
+    }
+    println!("Word lists OK");
+    Ok(())
+}
+
+/// Options for the test-all command
+struct TestAllOptions<'a> {
+    limit: Option<usize>,
+    first_word: Option<&'a str>,
+    tuning: AdaptiveTuning,
+    /// Restrict the run to answers the default strategy needs this many guesses for
+    min_guesses: Option<usize>,
+    progress: ProgressMode,
 }
 
 fn run_test_all_command(
     strategy_name: &str,
-    limit: Option<usize>,
-    first_word: Option<&str>,
-    all_words: &[Word],
-    answer_words: &[Word],
-) {
+    options: &TestAllOptions,
+    lists: &WordLists,
+) -> Result<()> {
+    let answer_words = &lists.answers[..];
+    let index = WordIndex::new(&lists.all);
+    let forced_first = resolve_first_word(&index, options.first_word)?;
+
     println!("\n{}", "═".repeat(70));
     println!(" Comprehensive Wordle Solver Test ");
     println!("{}", "═".repeat(70));
     println!("\nTesting against {} possible answers", answer_words.len());
     println!("Strategy: {strategy_name}");
-    if let Some(word) = first_word {
-        println!("Forced first word: {word}");
+    if let Some(first) = forced_first {
+        println!(
+            "Forced first word {}",
+            first_word_entropy(first, answer_words)
+        );
     }
+    options.tuning.print();
     println!();
 
-    // Convert first_word to Word if provided
-    let forced_first =
-        first_word.and_then(|word_str| all_words.iter().find(|w| w.text() == word_str));
+    // First Ctrl-C stops after the current word and reports partial results;
+    // a second one exits immediately
+    let stop = Arc::new(AtomicBool::new(false));
+    for result in [
+        signal_hook::flag::register_conditional_shutdown(SIGINT, 1, Arc::clone(&stop)),
+        signal_hook::flag::register(SIGINT, Arc::clone(&stop)),
+    ] {
+        if let Err(e) = result {
+            eprintln!("Warning: could not install Ctrl-C handler: {e}");
+        }
+    }
 
-    let strategy = StrategyType::from_name(strategy_name);
-    let solver = Solver::new(strategy, all_words, answer_words);
-    let stats = run_test_all(&solver, answer_words, limit, forced_first);
+    let hard_words: Vec<Word>;
+    let test_words = if let Some(min_guesses) = options.min_guesses {
+        println!("🔍 Selecting answers the default strategy needs {min_guesses}+ guesses for...");
+        // Seeded default strategy, so the same words are selected every run
+        // regardless of --strategy, --first-word or the adaptive tuning flags
+        let reference = lists.solver(
+            "adaptive",
+            StrategyType::from_name("adaptive").with_random_seed(0),
+        );
+        let hard = select_hard_words(
+            &reference,
+            answer_words,
+            min_guesses,
+            &stop,
+            options.progress,
+        );
+        if stop.load(Ordering::Relaxed) {
+            println!("Interrupted while selecting hard words");
+            return Ok(());
+        }
+        print_hard_words(&hard, min_guesses);
+        hard_words = hard.into_iter().map(|h| h.word).collect();
+        &hard_words[..]
+    } else {
+        answer_words
+    };
+
+    let mut strategy = StrategyType::from_name(strategy_name);
+    options.tuning.apply(&mut strategy);
+    let solver = lists
+        .solver(strategy_name, strategy)
+        .with_soft_hard_mode(options.tuning.soft_hard_mode);
+    let stats = run_test_all(
+        &solver,
+        test_words,
+        options.limit,
+        forced_first,
+        &stop,
+        options.progress,
+    );
     print_test_all_statistics(&stats);
+    Ok(())
+}
+
+fn run_worst_case_command(
+    strategy_name: &str,
+    first_word: Option<&str>,
+    dot: Option<&Path>,
+    precompute: Option<&Path>,
+    lists: &WordLists,
+) -> Result<()> {
+    let index = WordIndex::new(&lists.all);
+    let forced_first = resolve_first_word(&index, first_word)?;
+
+    // Seeded so the tree matches what test-all would play, run after run
+    let solver = lists.solver(
+        strategy_name,
+        StrategyType::from_name(strategy_name).with_random_seed(0),
+    );
+    // The key holds everything the tree depends on besides the word lists,
+    // including the solver code, so a new revision rebuilds it
+    let opener = forced_first
+        .or_else(|| solver.first_guess())
+        .map_or("none", Word::text);
+    let commit = lists.commit.map_or(String::new(), |rule| {
+        format!(" commit={}/{}", rule.max_candidates, rule.min_probability)
+    });
+    let weights = lists
+        .answer_weights_hash
+        .map_or(String::new(), |hash| format!(" weights={hash:016x}"));
+    let settings =
+        format!("{strategy_name} opener={opener} revision={SOLVER_REVISION}{commit}{weights}");
+    let key = ArtifactKey::new(&lists.all, &lists.answers, settings);
+
+    let loaded = precompute.and_then(|path| {
+        let replayed = match artifact::load::<SolveTree>(path, &key) {
+            Ok(tree) => worst_case_from_tree(&solver, tree),
+            Err(ArtifactError::Io(e)) if e.kind() == io::ErrorKind::NotFound => return None,
+            Err(e) => Err(e.to_string()),
+        };
+        let report = replayed
+            .inspect_err(|e| eprintln!("Not using {}: {e}", path.display()))
+            .ok()?;
+        println!("Loaded the decision tree from {}", path.display());
+        Some(report)
+    });
+    let report = if let Some(report) = loaded {
+        report
+    } else {
+        println!(
+            "Building the {strategy_name} decision tree over {} answers...",
+            lists.answers.len()
+        );
+        let report = worst_case(&solver, forced_first);
+        if let Some(path) = precompute {
+            artifact::save(path, &key, &report.tree)
+                .map_err(|e| anyhow::anyhow!("Cannot write {}: {e}", path.display()))?;
+            println!("Saved the decision tree to {}", path.display());
+        }
+        report
+    };
+    print_worst_case(&report, strategy_name);
+
+    if let Some(path) = dot {
+        let file = File::create(path)
+            .map_err(|e| anyhow::anyhow!("Cannot create {}: {e}", path.display()))?;
+        write_tree_dot(BufWriter::new(file), &report.tree)?;
+        println!("\nWrote the decision tree to {}", path.display());
+    }
+    Ok(())
+}
+
+fn run_pattern_stats_command(guess: &str, top: usize, lists: &WordLists) -> Result<()> {
+    let stats = pattern_stats(guess, &lists.all, &lists.answers).map_err(|e| anyhow::anyhow!(e))?;
+    print_pattern_stats(&stats, top);
+    Ok(())
+}
+
+fn run_reconstruct_command(answer: &str, grid: &Path, top: usize, lists: &WordLists) -> Result<()> {
+    let text = std::fs::read_to_string(grid)
+        .map_err(|e| anyhow::anyhow!("Cannot read {}: {e}", grid.display()))?;
+    let rows = parse_grid(&text).map_err(|e| anyhow::anyhow!(e))?;
+    let reconstruction = reconstruct(answer, &rows, &lists.all).map_err(|e| anyhow::anyhow!(e))?;
+    print_reconstruction(&reconstruction, top);
+    Ok(())
 }
 
-fn run_simple_command(
+fn run_hard_opening_command(
     strategy_name: &str,
-    all_words: &[Word],
-    answer_words: &[Word],
+    first_word: Option<&str>,
+    lists: &WordLists,
+) -> Result<()> {
+    let index = WordIndex::new(&lists.all);
+    let first = resolve_first_word(&index, first_word)?;
+
+    // Seeded so the planned line is the same run after run
+    let solver = lists.solver(
+        strategy_name,
+        StrategyType::from_name(strategy_name).with_random_seed(0),
+    );
+    println!(
+        "Planning a hard mode line and playing it on {} answers...",
+        lists.answers.len()
+    );
+    let opening = hard_opening(&solver, first).map_err(|e| anyhow::anyhow!(e))?;
+    print_hard_opening(&opening, strategy_name);
+    Ok(())
+}
+
+fn run_traps_command(
+    opener: Option<&str>,
+    min_size: usize,
+    top: usize,
+    lists: &WordLists,
 ) -> Result<()> {
-    let strategy = StrategyType::from_name(strategy_name);
-    let solver = Solver::new(strategy, all_words, answer_words);
-    run_simple(&solver).map_err(|e| anyhow::anyhow!(e))
+    let index = WordIndex::new(&lists.all);
+    let opener = resolve_first_word(&index, opener)?;
+
+    // The strategy only matters for finding the default opener
+    let solver = lists.solver("adaptive", StrategyType::from_name("adaptive"));
+    let report = find_traps(&solver, opener, min_size).map_err(|e| anyhow::anyhow!(e))?;
+    print_traps(&report, top);
+    Ok(())
+}
+
+fn run_min_guaranteed_command(
+    history: &HistoryArgs,
+    max_candidates: usize,
+    lists: &WordLists,
+) -> Result<()> {
+    let history = history.parse()?;
+    let solver = lists.solver("adaptive", StrategyType::from_name("adaptive"));
+    let result =
+        min_guaranteed(&solver, &history, max_candidates).map_err(|e| anyhow::anyhow!(e))?;
+    print_min_guaranteed(&result);
+    Ok(())
+}
+
+fn run_first_guess_command(opener: Option<&str>, lists: &WordLists) -> Result<()> {
+    let index = WordIndex::new(&lists.all);
+    let opener = resolve_first_word(&index, opener)?;
+
+    // The strategy only matters for finding the default opener
+    let solver = lists.solver("adaptive", StrategyType::from_name("adaptive"));
+    let analysis = first_guess_analysis(&solver, opener).map_err(|e| anyhow::anyhow!(e))?;
+    print_first_guess_analysis(&analysis);
+    Ok(())
 }
 
-fn run_play_command(all_words: &[Word], answer_words: &[Word]) -> Result<()> {
+fn run_coach_command(history: &HistoryArgs, lists: &WordLists) -> Result<()> {
+    let game = history.parse()?;
+    let report = coach(&lists.all, &lists.answers, &game).map_err(|e| anyhow::anyhow!(e))?;
+    print_coach_report(&report);
+    Ok(())
+}
+
+/// List the answers selected for a hard-words run and why
+fn print_hard_words(hard: &[HardWord], min_guesses: usize) {
+    println!(
+        "Selected {} answers (default strategy took {min_guesses}+ guesses or failed):",
+        hard.len()
+    );
+    for h in hard {
+        if h.success {
+            println!("  {} ({} guesses)", word_label(&h.word), h.guesses);
+        } else {
+            println!("  {} (failed)", word_label(&h.word));
+        }
+    }
+    println!();
+}
+
+fn run_simple_command(strategy_name: &str, reveal_at: usize, lists: &WordLists) -> Result<()> {
+    let solver = lists.solver(strategy_name, StrategyType::from_name(strategy_name));
+    run_simple(&solver, reveal_at).map_err(|e| anyhow::anyhow!(e))
+}
+
+fn run_play_command(theme_name: &str, beam: Option<usize>, lists: &WordLists) -> Result<()> {
     use wordle_solver::interactive::{App, run_tui};
 
-    let app = App::new(all_words, answer_words);
+    let app = App::new(&lists.all, &lists.answers)
+        .with_opener(lists.opener_for("adaptive"))
+        .with_commit(lists.commit)
+        .with_answer_weight(lists.answer_weight.as_deref())
+        .with_beam_width(beam)
+        .with_theme(Theme::from_name(theme_name).unwrap_or_default());
     run_tui(app)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beam_width_must_be_positive() {
+        for args in [
+            ["wordle_solver", "play", "--beam"],
+            ["wordle_solver", "test-all", "--beam"],
+            ["wordle_solver", "benchmark", "--compare-beams"],
+        ] {
+            let parse = |width| Cli::try_parse_from(args.into_iter().chain([width]));
+            assert!(parse("0").is_err(), "{args:?}");
+            assert!(parse("1").is_ok(), "{args:?}");
+        }
+    }
+
+    #[test]
+    fn commit_probability_must_be_a_probability() {
+        assert_eq!(parse_probability("0.75"), Ok(0.75));
+        assert!(parse_probability("1.5").is_err());
+        assert!(parse_probability("often").is_err());
+    }
+
+    #[test]
+    fn endgame_threshold_lets_epsilon_act() {
+        let cli = Cli::try_parse_from([
+            "wordle_solver",
+            "test-all",
+            "--endgame-threshold",
+            "4",
+            "--minimax-epsilon",
+            "0.05",
+        ])
+        .unwrap();
+        let Some(Commands::TestAll { tuning, .. }) = cli.command else {
+            panic!("parsed as test-all");
+        };
+        let mut strategy = StrategyType::from_name("adaptive");
+        tuning.apply(&mut strategy);
+        let StrategyType::Adaptive(adaptive) = strategy else {
+            panic!("still adaptive");
+        };
+        assert_eq!(adaptive.endgame_threshold, 4);
+        assert!((adaptive.minimax_first_epsilon - 0.05).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn tournament_takes_only_known_strategies() {
+        let parse = |names: &[&str]| {
+            Cli::try_parse_from(["wordle_solver", "tournament"].iter().chain(names))
+        };
+        assert!(parse(&["entropy", "fast"]).is_ok());
+        assert!(parse(&["entropy", "entorpy"]).is_err());
+    }
+}
@@ -1,11 +1,26 @@
 //! Display functions for command results
 
-use super::formatters::{entropy_bar, pattern_to_emoji};
-use crate::commands::{AnalysisResult, BenchmarkResult, SolveResult};
+use super::formatters::{
+    beam_comparison_markdown, benchmark_markdown, create_progress_bar, endgame_risk_note,
+    entropy_bar, pattern_to_emoji, word_label,
+};
+use crate::commands::{
+    AnalysisResult, BeamRun, BenchmarkResult, CandidateSet, CoachReport, EntropyBreakdown,
+    FirstGuessAnalysis, Grade, GuaranteedSolve, GuessPath, GuessStep, HardOpening, OpenerEntropy,
+    OpenerPercentile, PatternStats, Reconstruction, SkillScore, SolveResult, StrategyDiff,
+    Tournament, TrapReport, VerifyReport, WorstCaseReport,
+};
+use crate::core::{Pattern, Word};
+use crate::solver::MAX_GUESSES;
 use colored::Colorize;
 
+/// Most remaining candidates listed after a failed solve
+const FAILED_CANDIDATES_SHOWN: usize = 30;
+
 /// Print the result of solving a word
-pub fn print_solve_result(result: &SolveResult, verbose: bool) {
+///
+/// With `share`, also prints the Wordle-style emoji share grid.
+pub fn print_solve_result(result: &SolveResult, verbose: bool, share: bool) {
     println!("\n{}", "─".repeat(60).cyan());
     println!(
         "Solving: {}",
@@ -14,38 +29,14 @@ pub fn print_solve_result(result: &SolveResult, verbose: bool) {
     println!("{}", "─".repeat(60).cyan());
 
     for (i, step) in result.guesses.iter().enumerate() {
-        let turn = i + 1;
+        print_guess_step(i + 1, step, verbose);
+    }
+
+    if verbose {
         println!(
-            "\nTurn {}: {} {}",
-            turn,
-            step.word.to_uppercase(),
-            pattern_to_emoji(step.pattern)
+            "\nTotal info gained: {:.3} bits",
+            result.information_gained()
         );
-
-        if verbose {
-            println!(
-                "  Candidates: {} → {}",
-                step.candidates_before, step.candidates_after
-            );
-
-            if let Some(entropy) = step.entropy {
-                println!("  Entropy:    {entropy:.3} bits");
-                if let Some(expected) = step.expected_remaining {
-                    println!("  Expected:   {expected:.1} candidates");
-                }
-
-                // Calculate information gained (reduction in uncertainty)
-                if step.candidates_after > 0 {
-                    let actual_reduction =
-                        (step.candidates_before as f64 / step.candidates_after as f64).log2();
-                    println!(
-                        "  Info gained: {:.3} bits ({:.1}x reduction)",
-                        actual_reduction,
-                        step.candidates_before as f64 / step.candidates_after as f64
-                    );
-                }
-            }
-        }
     }
 
     println!();
@@ -63,6 +54,90 @@ pub fn print_solve_result(result: &SolveResult, verbose: bool) {
                 .red()
                 .bold()
         );
+
+        let shown: Vec<String> = result
+            .remaining
+            .iter()
+            .take(FAILED_CANDIDATES_SHOWN)
+            .map(|w| w.to_uppercase())
+            .collect();
+        println!("   {} candidates remained:", result.remaining.len());
+        println!("   {}", shown.join(" "));
+        if result.remaining.len() > FAILED_CANDIDATES_SHOWN {
+            println!(
+                "   ... and {} more",
+                result.remaining.len() - FAILED_CANDIDATES_SHOWN
+            );
+        }
+    }
+
+    if share {
+        println!("\n{}", result.share_grid());
+    }
+}
+
+/// Print one turn of a solve, with its analysis when `verbose`
+fn print_guess_step(turn: usize, step: &GuessStep, verbose: bool) {
+    println!(
+        "\nTurn {}: {} {}",
+        turn,
+        step.word.to_uppercase(),
+        pattern_to_emoji(step.pattern)
+    );
+
+    if let Some(risk) = &step.endgame_risk {
+        let marker = if risk.guaranteed {
+            "✓".green()
+        } else {
+            "⚠".yellow()
+        };
+        println!("  {marker} {}", endgame_risk_note(risk));
+    }
+
+    if !step.alternatives.is_empty() {
+        let alternatives: Vec<String> = step
+            .alternatives
+            .iter()
+            .map(|(word, metrics)| format!("{} ({:.2}b)", word.to_uppercase(), metrics.entropy))
+            .collect();
+        println!(
+            "  Chose {} ({:.2}b); alternatives: {}",
+            step.word.to_uppercase(),
+            step.entropy.unwrap_or(0.0),
+            alternatives.join(", ")
+        );
+    }
+
+    if verbose {
+        println!(
+            "  Candidates: {} → {}",
+            step.candidates_before, step.candidates_after
+        );
+        if step.candidates_before == 1 {
+            println!("  Remaining:  1 guess (exact)");
+        } else {
+            println!(
+                "  Remaining:  ≈{:.1} guesses, including this one (estimate)",
+                step.expected_guesses()
+            );
+        }
+
+        if let Some(entropy) = step.entropy {
+            println!("  Entropy:    {entropy:.3} bits");
+            if let Some(expected) = step.expected_remaining {
+                println!("  Expected:   {expected:.1} candidates");
+            }
+
+            // Information actually gained (reduction in uncertainty)
+            if step.candidates_after > 0 {
+                let actual_reduction = step.information_gained();
+                println!(
+                    "  Info gained: {:.3} bits ({:.1}x reduction)",
+                    actual_reduction,
+                    actual_reduction.exp2()
+                );
+            }
+        }
     }
 }
 
@@ -78,7 +153,20 @@ pub fn print_analysis_result(result: &AnalysisResult) {
 
     let bar = entropy_bar(result.entropy, 30);
 
-    println!("\n📊 Against {} possible answers:", result.total_candidates);
+    let against = result.against.label();
+    if result.clues > 0 {
+        println!(
+            "\n📊 Against {} {against} (after {} clue{}):",
+            result.total_candidates,
+            result.clues,
+            if result.clues == 1 { "" } else { "s" }
+        );
+    } else {
+        println!("\n📊 Against {} {against}:", result.total_candidates);
+    }
+    if result.against == CandidateSet::Allowed {
+        println!("   (every allowed word counted as a candidate, not just the answer list)");
+    }
     println!(
         "   Entropy:     [{}] {}",
         bar.green(),
@@ -92,6 +180,316 @@ pub fn print_analysis_result(result: &AnalysisResult) {
         "   Expected:    {:.1} candidates remain",
         result.expected_remaining
     );
+    println!(
+        "   Efficiency:  {:.1}% of available information",
+        result.efficiency * 100.0
+    );
+}
+
+/// Print how much of a guess's entropy each position's feedback carries
+pub fn print_entropy_breakdown(breakdown: &EntropyBreakdown) {
+    println!(
+        "\n🔬 {} {:.3} bits against {} candidates",
+        format!("Breakdown of {}:", breakdown.word.to_uppercase())
+            .bright_cyan()
+            .bold(),
+        breakdown.entropy,
+        breakdown.total_candidates
+    );
+    println!("   Bits lost if a tile's color were hidden, most informative first:");
+    for (rank, contribution) in breakdown.positions.iter().enumerate() {
+        let label = format!(
+            "{} in position {}",
+            contribution.letter.to_ascii_uppercase(),
+            contribution.position + 1
+        );
+        let label = if rank == 0 {
+            label.bright_yellow().bold()
+        } else {
+            label.normal()
+        };
+        println!(
+            "   {}. {label}  {:.3} bits {}",
+            rank + 1,
+            contribution.bits.max(0.0),
+            entropy_bar(contribution.bits.max(0.0), 20)
+        );
+    }
+}
+
+/// Print a played guess's skill score against the best available guess
+pub fn print_skill_score(score: &SkillScore) {
+    println!(
+        "\n🎯 {} {}/100",
+        format!("Skill for {}:", score.word.to_uppercase())
+            .bright_cyan()
+            .bold(),
+        format!("{:.0}", score.skill).bright_yellow().bold()
+    );
+    println!(
+        "   Played:  {} {:.3} bits",
+        score.word.to_uppercase(),
+        score.entropy
+    );
+    println!(
+        "   Best:    {} {:.3} bits (against {} candidates)",
+        score.best.to_uppercase(),
+        score.best_entropy,
+        score.total_candidates
+    );
+    if !score.in_word_list {
+        println!(
+            "   {}",
+            format!(
+                "⚠ {} is not in the word list; scored anyway",
+                score.word.to_uppercase()
+            )
+            .yellow()
+        );
+    }
+}
+
+/// Print a game review: each turn's grade, then the overall skill
+pub fn print_coach_report(report: &CoachReport) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(" {} ", "Game Review".bright_cyan().bold());
+    println!("{}", "═".repeat(60).cyan());
+
+    for (i, turn) in report.turns.iter().enumerate() {
+        let score = &turn.score;
+        let grade = turn.grade();
+        let label = format!("{:<5}", grade.label());
+        let label = match grade {
+            Grade::Best | Grade::Great => label.green().bold(),
+            Grade::Good => label.bright_green(),
+            Grade::Fair => label.yellow(),
+            Grade::Poor => label.red().bold(),
+        };
+        let bits = if score.total_candidates == 1 {
+            "nothing left to learn".to_string()
+        } else {
+            // A guess that can't split the candidates scores -0.0 bits
+            format!(
+                "{:.3} of {:.3} bits",
+                score.entropy.abs(),
+                score.best_entropy
+            )
+        };
+        let outcome = if turn.pattern == Pattern::PERFECT {
+            "solved".to_string()
+        } else {
+            format!("{} → {} left", score.total_candidates, turn.remaining)
+        };
+        println!(
+            "  {}. {:<6} {}  {label} {:>3}/100  {bits}  {outcome}",
+            i + 1,
+            score.word.to_uppercase().bold(),
+            pattern_to_emoji(turn.pattern),
+            format!("{:.0}", score.skill.abs()),
+        );
+        if grade != Grade::Best {
+            println!(
+                "     Best was {} ({:.3} bits)",
+                score.best.to_uppercase().bright_yellow(),
+                score.best_entropy
+            );
+        }
+        if !score.in_word_list {
+            println!(
+                "     {}",
+                format!("⚠ {} is not in the word list", score.word.to_uppercase()).yellow()
+            );
+        } else if !turn.could_win && score.total_candidates > 1 {
+            println!("     (could not be the answer)");
+        }
+    }
+
+    println!(
+        "\n  {} {}/100, {:.3} bits left on the table",
+        "Overall skill:".bright_cyan().bold(),
+        format!("{:.0}", report.skill()).bright_yellow().bold(),
+        report.bits_lost()
+    );
+    if report.solved {
+        println!("  Solved in {}", report.turns.len());
+    } else {
+        println!(
+            "  {} after {} guesses",
+            "Not solved".red(),
+            report.turns.len()
+        );
+    }
+}
+
+/// Print how a guess partitions the answers, listing the `limit` largest groups
+pub fn print_pattern_stats(stats: &PatternStats, limit: usize) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(
+        " {} ",
+        format!("Pattern Distribution: {}", stats.guess.to_uppercase())
+            .bright_cyan()
+            .bold()
+    );
+    println!("{}", "═".repeat(60).cyan());
+
+    let percent = |count: usize| count as f64 / stats.total as f64 * 100.0;
+
+    println!(
+        "   Entropy:    {:.3} bits [{}]",
+        stats.entropy,
+        entropy_bar(stats.entropy, 20)
+    );
+    println!(
+        "   Patterns:   {} of 243 realized across {} answers",
+        stats.groups.len(),
+        stats.total
+    );
+    if let Some(largest) = stats.largest() {
+        println!(
+            "   {} {} leaves {} answers ({:.1}%)",
+            "Worst case:".red().bold(),
+            pattern_to_emoji(largest.pattern),
+            largest.count.to_string().red().bold(),
+            percent(largest.count)
+        );
+    }
+
+    println!(
+        "\n  {:>4}  {:<10}  {:>7}  {:>6}",
+        "Rank", "Pattern", "Answers", "Share"
+    );
+    let max = stats.largest().map_or(1, |g| g.count) as f64;
+    for (i, group) in stats.groups.iter().take(limit).enumerate() {
+        println!(
+            "  {:>4}  {}  {:>7}  {:>5.1}%  {}",
+            i + 1,
+            pattern_to_emoji(group.pattern),
+            group.count,
+            percent(group.count),
+            create_progress_bar(group.count as f64, max, 20).cyan()
+        );
+    }
+    if limit < stats.groups.len() {
+        println!("  ... and {} more", stats.groups.len() - limit);
+    }
+}
+
+/// Print the top `limit` openers from a ranking, best first
+pub fn print_opener_table(openers: &[OpenerEntropy], limit: usize) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(" {} ", "Opening Entropy Ranking".bright_cyan().bold());
+    println!("{}", "═".repeat(60).cyan());
+
+    println!("  {:>6}  {:<7}  Entropy", "Rank", "Word");
+    for (i, opener) in openers.iter().take(limit).enumerate() {
+        println!(
+            "  {:>6}  {:<7}  {:.4} bits",
+            i + 1,
+            opener.word.to_uppercase(),
+            opener.entropy
+        );
+    }
+    if limit < openers.len() {
+        println!("  ... and {} more", openers.len() - limit);
+    }
+}
+
+/// Print an entropy ranking's percentile summary
+///
+/// `highlight` is an opener to place among the percentiles, e.g. SALET.
+pub fn print_opener_percentiles(
+    percentiles: &[OpenerPercentile],
+    total: usize,
+    highlight: Option<&OpenerPercentile>,
+) {
+    println!(
+        "\n📊 {}",
+        format!("Entropy Distribution ({total} openers)")
+            .bright_cyan()
+            .bold()
+    );
+    println!(
+        "  {:<10}  {:>6}  {:<7}  Entropy",
+        "Percentile", "Rank", "Word"
+    );
+
+    let label = |p: &OpenerPercentile| match p.percentile {
+        x if x >= 100.0 => "max".to_string(),
+        x if x <= 0.0 => "min".to_string(),
+        x if (x - 50.0).abs() < f64::EPSILON => "median".to_string(),
+        x => format!("p{x}"),
+    };
+    for p in percentiles {
+        println!(
+            "  {:<10}  {:>6}  {:<7}  {:.4} bits",
+            label(p),
+            p.rank,
+            p.opener.word.to_uppercase(),
+            p.opener.entropy
+        );
+    }
+
+    if let Some(p) = highlight {
+        println!(
+            "\n  {} ranks {} of {total}, at or above {:.2}% of openers ({:.4} bits)",
+            p.opener.word.to_uppercase().bright_yellow().bold(),
+            p.rank,
+            p.percentile,
+            p.opener.entropy
+        );
+    }
+}
+
+/// Print benchmark results side by side for each beam width
+///
+/// The first run is the baseline the others are compared against.
+pub fn print_beam_comparison(runs: &[BeamRun]) {
+    let Some(baseline) = runs.first() else {
+        return;
+    };
+    let base_time = baseline.result.duration.as_secs_f64();
+
+    println!(
+        "\n📊 {}",
+        format!(
+            "Beam Width Comparison ({} words)",
+            baseline.result.total_words
+        )
+        .bright_cyan()
+        .bold()
+    );
+    println!(
+        "  {:<6}  {:>7}  {:>5}  {:>8}  {:>7}",
+        "Beam", "Average", "Worst", "Time", "Speedup"
+    );
+    for run in runs {
+        let width = run
+            .width
+            .map_or_else(|| "full".to_string(), |w| w.to_string());
+        let time = run.result.duration.as_secs_f64();
+        let average = format!("{:>7.3}", run.result.average_guesses);
+        let average = if run.result.average_guesses > baseline.result.average_guesses {
+            average.yellow()
+        } else {
+            average.green()
+        };
+        println!(
+            "  {width:<6}  {average}  {:>5}  {:>7.2}s  {:>6.1}x",
+            run.result.max_guesses,
+            time,
+            base_time / time
+        );
+    }
+}
+
+/// Print a beam width comparison as a Markdown table, for docs and issues
+pub fn print_beam_comparison_markdown(runs: &[BeamRun]) {
+    print!("{}", beam_comparison_markdown(runs));
+}
+
+/// Print the result of a benchmark as Markdown tables, for docs and issues
+pub fn print_benchmark_markdown(result: &BenchmarkResult) {
+    print!("{}", benchmark_markdown(result));
 }
 
 /// Print the result of a benchmark
@@ -116,9 +514,42 @@ pub fn print_benchmark_result(result: &BenchmarkResult) {
         "   Worst case:       {}",
         format!("{}", result.max_guesses).yellow()
     );
-    println!("   Time taken:       {:.2}s", result.duration.as_secs_f64());
+    if result.failures > 0 {
+        println!(
+            "   Failures:         {}",
+            result.failures.to_string().red().bold()
+        );
+    }
+    if let Some(timing) = &result.timing {
+        println!(
+            "   Time taken:       {:.3}s ± {:.3}s ({} iterations)",
+            timing.mean.as_secs_f64(),
+            timing.std_dev.as_secs_f64(),
+            timing.iterations
+        );
+    } else {
+        println!("   Time taken:       {:.2}s", result.duration.as_secs_f64());
+    }
     println!("   Words/second:     {:.1}", result.words_per_second);
 
+    println!("\n⏱  {}", "Timing:".bright_cyan().bold());
+    println!(
+        "   First guess:      {:.2}s",
+        result.first_guess_time().as_secs_f64()
+    );
+    println!(
+        "   Subsequent:       {:.2}s",
+        result.subsequent_time().as_secs_f64()
+    );
+    for (i, timing) in result.turn_timings.iter().enumerate() {
+        println!(
+            "   Turn {}:           {:8.2}ms avg ({} guesses)",
+            i + 1,
+            timing.average().as_secs_f64() * 1000.0,
+            timing.guesses
+        );
+    }
+
     println!("\n📈 {}", "Distribution:".bright_cyan().bold());
     for guess_count in 1..=6 {
         if let Some(&count) = result.distribution.get(&guess_count) {
@@ -135,3 +566,474 @@ pub fn print_benchmark_result(result: &BenchmarkResult) {
         }
     }
 }
+
+/// Print two strategies' guesses side by side, marking where they diverge
+pub fn print_strategy_diff(diff: &StrategyDiff, first_name: &str, second_name: &str) {
+    println!("\n{}", "─".repeat(60).cyan());
+    println!(
+        "Answer: {}",
+        diff.answer.to_uppercase().bright_yellow().bold()
+    );
+    println!("{}", "─".repeat(60).cyan());
+    println!(
+        "  {:<6}{:<16}{:<16}",
+        "Turn",
+        first_name.to_uppercase(),
+        second_name.to_uppercase()
+    );
+
+    let first: Vec<&str> = diff.first.guesses().map(crate::core::Word::text).collect();
+    let second: Vec<&str> = diff.second.guesses().map(crate::core::Word::text).collect();
+    for i in 0..first.len().max(second.len()) {
+        let turn = i + 1;
+        let a = first.get(i).map_or(String::new(), |w| w.to_uppercase());
+        let b = second.get(i).map_or(String::new(), |w| w.to_uppercase());
+        let line = format!("  {turn:<6}{a:<16}{b:<16}");
+        match diff.divergence {
+            Some(d) if d.turn == turn => println!(
+                "{} {}",
+                line.bright_yellow().bold(),
+                format!("← diverged with {} candidates", d.candidates).yellow()
+            ),
+            Some(d) if turn > d.turn => println!("{}", line.bright_black()),
+            _ => println!("{line}"),
+        }
+    }
+
+    let outcome = |record: &crate::solver::GameRecord| {
+        if record.success {
+            format!("{} guesses", record.num_guesses())
+        } else {
+            "failed".to_string()
+        }
+    };
+    println!(
+        "\n  {first_name}: {}  |  {second_name}: {}",
+        outcome(&diff.first),
+        outcome(&diff.second)
+    );
+    if diff.divergence.is_none() {
+        println!("  {}", "Identical guess sequences".green());
+    }
+}
+
+/// Print the answers on which two strategies diverge, across a whole answer list
+pub fn print_strategy_diff_summary(
+    diffs: &[StrategyDiff],
+    total: usize,
+    first_name: &str,
+    second_name: &str,
+) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(
+        " {} ",
+        format!("Strategy Diff: {first_name} vs {second_name}")
+            .bright_cyan()
+            .bold()
+    );
+    println!("{}", "═".repeat(60).cyan());
+
+    let guesses = |record: &crate::solver::GameRecord| {
+        if record.success {
+            record.num_guesses().to_string()
+        } else {
+            "X".to_string()
+        }
+    };
+    for diff in diffs {
+        let colored_delta = match diff.guess_difference() {
+            Some(d) if d < 0 => format!("{d:+}").green(),
+            Some(d) if d > 0 => format!("{d:+}").red(),
+            Some(d) => format!("{d:+}").normal(),
+            None => "failed".red(),
+        };
+        let divergence = diff
+            .divergence
+            .map(|d| format!("turn {} at {} candidates", d.turn, d.candidates))
+            .unwrap_or_default();
+        println!(
+            "  {}  {} → {} ({colored_delta})  diverged {divergence}",
+            diff.answer.to_uppercase(),
+            guesses(&diff.first),
+            guesses(&diff.second)
+        );
+    }
+
+    let compared = |keep: fn(isize) -> bool| {
+        diffs
+            .iter()
+            .filter(|d| d.guess_difference().is_some_and(keep))
+            .count()
+    };
+    let better = compared(|d| d < 0);
+    let worse = compared(|d| d > 0);
+    let tied = compared(|d| d == 0);
+    println!(
+        "\n  {} of {total} answers diverge: {second_name} is better on {better}, worse on {worse}, tied on {tied}",
+        diffs.len()
+    );
+    let first_failed = diffs.iter().filter(|d| !d.first.success).count();
+    let second_failed = diffs.iter().filter(|d| !d.second.success).count();
+    if first_failed + second_failed > 0 {
+        println!(
+            "  {} {first_name} fails {first_failed}, {second_name} fails {second_failed}",
+            "Failures:".red().bold()
+        );
+    }
+}
+
+/// Print a tournament's head-to-head matrix, each cell the row's wins-ties-losses
+/// against the column
+pub fn print_tournament(tournament: &Tournament, names: &[String]) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(
+        " {} ",
+        format!("Tournament: {} answers", tournament.answers())
+            .bright_cyan()
+            .bold()
+    );
+    println!("{}", "═".repeat(60).cyan());
+
+    let width = names.iter().map(String::len).max().unwrap_or(0).max(14);
+    print!("  {:width$}", "");
+    for name in names {
+        print!("  {name:>width$}");
+    }
+    println!();
+
+    for (a, name) in names.iter().enumerate() {
+        print!("  {name:width$}");
+        for b in 0..names.len() {
+            if a == b {
+                print!("  {:>width$}", "—");
+            } else {
+                let record = tournament.head_to_head(a, b);
+                let cell = format!("{}-{}-{}", record.wins, record.ties, record.losses);
+                let cell = format!("{cell:>width$}");
+                let colored_cell = match record.wins.cmp(&record.losses) {
+                    std::cmp::Ordering::Greater => cell.green(),
+                    std::cmp::Ordering::Less => cell.red(),
+                    std::cmp::Ordering::Equal => cell.normal(),
+                };
+                print!("  {colored_cell}");
+            }
+        }
+        println!();
+    }
+
+    println!();
+    for (a, name) in names.iter().enumerate() {
+        println!(
+            "  {name:width$}  {:.3} avg, {} failed",
+            tournament.mean_guesses(a),
+            tournament.failures(a)
+        );
+    }
+}
+
+/// Print the deepest paths through a solver's decision tree
+pub fn print_worst_case(report: &WorstCaseReport, strategy_name: &str) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(
+        " {} ",
+        format!("Worst Case: {strategy_name}").bright_cyan().bold()
+    );
+    println!("{}", "═".repeat(60).cyan());
+
+    let solved = report.total_answers - report.failed.len();
+    println!(
+        "  Worst case: {} guesses ({} of {solved} solved answers)",
+        report.max_guesses.to_string().bold(),
+        report.worst.len()
+    );
+    for path in &report.worst {
+        print_guess_path(path);
+    }
+
+    if !report.failed.is_empty() {
+        println!(
+            "\n  {} answers not solved within {MAX_GUESSES} guesses:",
+            report.failed.len().to_string().red()
+        );
+        for path in &report.failed {
+            print_guess_path(path);
+        }
+    }
+
+    println!(
+        "\n  Average: {:.4} guesses over the solved answers",
+        report.average_guesses()
+    );
+    println!(
+        "  Decision tree: {} guess selections for {} answers",
+        report.nodes, report.total_answers
+    );
+}
+
+/// One answer's guesses with their feedback
+fn print_guess_path(path: &GuessPath) {
+    let steps: Vec<String> = path
+        .guesses
+        .iter()
+        .zip(&path.patterns)
+        .map(|(guess, &pattern)| format!("{} {}", guess.to_uppercase(), pattern_to_emoji(pattern)))
+        .collect();
+    println!("  {:<7} {}", path.answer.to_uppercase(), steps.join(" → "));
+}
+
+/// Print a planned hard mode opening line and how it performs
+pub fn print_hard_opening(opening: &HardOpening, strategy_name: &str) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(
+        " {} ",
+        format!("Hard Mode Opening: {strategy_name}")
+            .bright_cyan()
+            .bold()
+    );
+    println!("{}", "═".repeat(60).cyan());
+
+    println!("  Planned for the most likely feedback:");
+    for (i, step) in opening.line.iter().enumerate() {
+        println!(
+            "  {}. {:<6} {}  → {} answers left",
+            i + 1,
+            step.guess.to_uppercase().bold(),
+            pattern_to_emoji(step.assumed),
+            step.remaining
+        );
+    }
+
+    let unsolved = opening.total_answers - opening.solved;
+    println!(
+        "\n  Average: {} guesses over {} answers",
+        format!("{:.3}", opening.average).bold(),
+        opening.solved
+    );
+    if unsolved > 0 {
+        println!(
+            "  {} answers not solved within {MAX_GUESSES} guesses",
+            unsolved.to_string().red()
+        );
+    }
+    println!(
+        "  Line stays legal for {} of {} answers ({:.1}%)",
+        opening.followed,
+        opening.total_answers,
+        opening.followed as f64 / opening.total_answers as f64 * 100.0
+    );
+
+    println!(
+        "\n  {} Hard mode has no universal fixed line: a planned guess is only",
+        "Note:".yellow()
+    );
+    println!("  legal for feedback that keeps every revealed letter in it. On other");
+    println!("  feedback the best legal guess is played instead.");
+}
+
+/// Answers listed per trap group before the rest are summarized
+const TRAP_ANSWERS_SHOWN: usize = 12;
+
+/// Print the trap groups an opener leaves, largest first
+///
+/// Lists at most `limit` groups.
+pub fn print_traps(report: &TrapReport, limit: usize) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(
+        " {} ",
+        format!("Trap Groups: {}", report.opener.to_uppercase())
+            .bright_cyan()
+            .bold()
+    );
+    println!("{}", "═".repeat(60).cyan());
+
+    let trapped = report.trapped();
+    println!(
+        "  {} groups of {}+ answers hold {} of {} answers ({:.1}%)",
+        report.groups.len(),
+        report.min_size,
+        trapped.to_string().bold(),
+        report.total,
+        trapped as f64 / report.total as f64 * 100.0
+    );
+
+    for (i, group) in report.groups.iter().take(limit).enumerate() {
+        println!(
+            "\n  {:>3}. {}  {} answers  {}",
+            i + 1,
+            pattern_to_emoji(group.pattern),
+            group.answers.len().to_string().red().bold(),
+            group.template().yellow()
+        );
+        let shown: Vec<String> = group
+            .answers
+            .iter()
+            .take(TRAP_ANSWERS_SHOWN)
+            .map(|a| a.to_uppercase())
+            .collect();
+        let mut line = shown.join(" ");
+        if group.answers.len() > TRAP_ANSWERS_SHOWN {
+            line = format!("{line} … {} more", group.answers.len() - TRAP_ANSWERS_SHOWN);
+        }
+        println!("       {line}");
+    }
+    if limit < report.groups.len() {
+        println!("\n  ... and {} more groups", report.groups.len() - limit);
+    }
+}
+
+/// Print the fewest guesses that guarantee a solve from a position
+pub fn print_min_guaranteed(result: &GuaranteedSolve) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(" {} ", "Guaranteed Solve".bright_cyan().bold());
+    println!("{}", "═".repeat(60).cyan());
+
+    let plural = |n: usize| if n == 1 { "" } else { "es" };
+    println!(
+        "   {} candidate{} remain after {} guess{}",
+        result.candidates,
+        if result.candidates == 1 { "" } else { "s" },
+        result.played,
+        plural(result.played)
+    );
+    println!(
+        "   {} {} more guess{} at most ({} in total)",
+        "Guaranteed:".green().bold(),
+        result.worst.to_string().green().bold(),
+        plural(result.worst),
+        result.total_worst()
+    );
+    let kind = if result.is_candidate {
+        "a candidate"
+    } else {
+        "not a candidate"
+    };
+    println!(
+        "   Play:       {} ({kind})",
+        result.guess.to_uppercase().bright_yellow().bold()
+    );
+    println!(
+        "   Worst case: {} of {} candidates need all {}",
+        result.at_worst, result.candidates, result.worst
+    );
+    println!("   Expected:   {:.2} guesses from here", result.expected);
+}
+
+/// Print how many candidates an opener leaves across the answers
+pub fn print_first_guess_analysis(analysis: &FirstGuessAnalysis) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(
+        " {} ",
+        format!("After Opening {}", analysis.opener.to_uppercase())
+            .bright_cyan()
+            .bold()
+    );
+    println!("{}", "═".repeat(60).cyan());
+
+    println!(
+        "   Candidates left across {} answers: mean {:.2}, median {:.1}, max {}",
+        analysis.total(),
+        analysis.mean(),
+        analysis.median(),
+        analysis.max().to_string().red().bold()
+    );
+
+    println!("\n  {:>9}  {:>7}  {:>6}", "Remaining", "Answers", "Share");
+    let buckets = analysis.histogram();
+    let max = buckets.iter().map(|b| b.answers).max().unwrap_or(1).max(1) as f64;
+    for bucket in &buckets {
+        let range = match bucket.high {
+            Some(high) if high == bucket.low => high.to_string(),
+            Some(high) => format!("{}-{high}", bucket.low),
+            None => format!("{}+", bucket.low),
+        };
+        println!(
+            "  {:>9}  {:>7}  {:>5.1}%  {}",
+            range,
+            bucket.answers,
+            bucket.answers as f64 / analysis.total() as f64 * 100.0,
+            create_progress_bar(bucket.answers as f64, max, 20).cyan()
+        );
+    }
+}
+
+/// Print the guesses that fit each row of a share grid
+///
+/// Lists at most `limit` guesses per row.
+pub fn print_reconstruction(reconstruction: &Reconstruction, limit: usize) {
+    println!("\n{}", "═".repeat(60).cyan());
+    println!(
+        " {} ",
+        format!("Reconstruction: {}", reconstruction.answer.to_uppercase())
+            .bright_cyan()
+            .bold()
+    );
+    println!("{}", "═".repeat(60).cyan());
+
+    for (i, row) in reconstruction.rows.iter().enumerate() {
+        let count = match row.guesses.len() {
+            0 => "no word fits".red().to_string(),
+            1 => "1 word".to_string(),
+            n => format!("{n} words"),
+        };
+        println!("\n  {}. {}  {count}", i + 1, pattern_to_emoji(row.pattern));
+
+        let shown: Vec<String> = row
+            .guesses
+            .iter()
+            .take(limit)
+            .map(|g| g.to_uppercase())
+            .collect();
+        if !shown.is_empty() {
+            println!("     {}", shown.join(" "));
+        }
+        if row.guesses.len() > limit {
+            println!("     ... and {} more", row.guesses.len() - limit);
+        }
+    }
+}
+
+/// Most violations of one kind listed by [`print_verify_report`]
+const VIOLATIONS_SHOWN: usize = 20;
+
+/// Print a word list verification report, listing the first violations of each kind
+pub fn print_verify_report(report: &VerifyReport) {
+    println!(
+        "Checked {} guesses and {} answers",
+        report.guesses, report.answers
+    );
+
+    let sections: [(&str, &[String]); 4] = [
+        ("invalid entries", &report.rejected),
+        ("answers not in the guess pool", &report.answers_not_in_pool),
+        ("duplicate answers", &report.duplicate_answers),
+        ("duplicate guesses", &report.duplicate_guesses),
+    ];
+    for (label, entries) in sections {
+        if entries.is_empty() {
+            println!("  {} no {label}", "✓".green());
+        } else {
+            println!("  {} {} {label}:", "✗".red(), entries.len());
+            for entry in entries.iter().take(VIOLATIONS_SHOWN) {
+                println!("      {entry}");
+            }
+            if entries.len() > VIOLATIONS_SHOWN {
+                println!("      ... and {} more", entries.len() - VIOLATIONS_SHOWN);
+            }
+        }
+    }
+}
+
+/// Print the answers a filter kept, at most `limit` of them, ten per line
+pub fn print_filter_result(matches: &[&Word], total: usize, limit: usize) {
+    println!(
+        "{} of {total} answers match",
+        matches.len().to_string().bold()
+    );
+    let shown: Vec<String> = matches.iter().take(limit).map(|w| word_label(w)).collect();
+    for row in shown.chunks(10) {
+        println!("  {}", row.join(" "));
+    }
+    if matches.len() > limit {
+        println!("  ... and {} more", matches.len() - limit);
+    }
+}
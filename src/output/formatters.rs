@@ -1,6 +1,31 @@
 //! Formatting utilities for terminal output
 
-use crate::core::Pattern;
+use std::fmt::Write;
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+use crate::commands::{BeamRun, BenchmarkResult};
+use crate::core::{Pattern, Word};
+use crate::solver::entropy::observed_information;
+use crate::solver::{EndgameRisk, MAX_GUESSES};
+
+/// Progress bar in the style every long-running command shares
+///
+/// Counts up to `len` and shows `msg` after the count; commands may update
+/// the message as they go.
+#[must_use]
+pub fn progress_bar(len: usize, msg: &str) -> ProgressBar {
+    let style = ProgressStyle::with_template(
+        "{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) | {msg}",
+    )
+    .map_or_else(
+        |_| ProgressStyle::default_bar(),
+        |style| style.progress_chars("█▓▒░"),
+    );
+    ProgressBar::new(len as u64)
+        .with_style(style)
+        .with_message(msg.to_string())
+}
 
 /// Format a pattern as emoji string
 #[must_use]
@@ -39,10 +64,233 @@ pub fn entropy_bar(entropy: f64, width: usize) -> String {
     create_progress_bar(entropy, max_entropy, width)
 }
 
+/// Pattern probability below which an outcome is called rare
+pub const RARE_OUTCOME: f64 = 0.05;
+
+/// Describe how surprising a pattern was, as `surprise: 4.2 bits (rare outcome!)`
+///
+/// The pattern's probability is the share of the `before` candidates that
+/// gave it, i.e. `after / before`, and its surprise is -log₂ of that: the
+/// information the outcome carried. Returns `None` when no candidate gives
+/// the pattern, since the feedback then conflicts with the earlier guesses.
+#[must_use]
+pub fn format_surprise(before: usize, after: usize) -> Option<String> {
+    if before == 0 || after == 0 {
+        return None;
+    }
+
+    let bits = observed_information(before, after);
+    let probability = after as f64 / before as f64;
+    let note = if probability < RARE_OUTCOME {
+        " (rare outcome!)"
+    } else if probability >= 0.5 {
+        " (expected outcome)"
+    } else {
+        ""
+    };
+    Some(format!("surprise: {bits:.1} bits{note}"))
+}
+
+/// Spell out counts up to five, as letter counts in a word go
+#[must_use]
+pub fn count_word(n: usize) -> String {
+    match n {
+        1 => "one".to_string(),
+        2 => "two".to_string(),
+        3 => "three".to_string(),
+        4 => "four".to_string(),
+        5 => "five".to_string(),
+        _ => n.to_string(),
+    }
+}
+
+/// A word as shown to the user
+///
+/// Uppercase, unless the word list kept a casing of its own (see
+/// [`Word::display_text`]), which is shown as written.
+#[must_use]
+pub fn word_label(word: &Word) -> String {
+    let display = word.display_text();
+    if display == word.text() {
+        display.to_uppercase()
+    } else {
+        display.to_string()
+    }
+}
+
+/// Explain how repeated letters in a guess are marked, or `None` if the
+/// guess has no repeated letters
+///
+/// Duplicates are the usual source of mis-entered feedback: each copy only
+/// comes back colored while the answer still has an unmatched copy of that
+/// letter.
+#[must_use]
+pub fn duplicate_letter_note(guess: &Word) -> Option<String> {
+    let counts = guess.char_counts();
+    let mut repeated: Vec<(u8, u8)> = Vec::new();
+    for &ch in guess.chars() {
+        let count = counts[&ch];
+        if count > 1 && !repeated.iter().any(|&(seen, _)| seen == ch) {
+            repeated.push((ch, count));
+        }
+    }
+
+    let word = word_label(guess);
+    let describe = |&(ch, count): &(u8, u8)| {
+        format!(
+            "{} {}'s",
+            count_word(usize::from(count)),
+            char::from(ch).to_ascii_uppercase()
+        )
+    };
+    match repeated.as_slice() {
+        [] => None,
+        [(ch, 2)] => {
+            let letter = char::from(*ch).to_ascii_uppercase();
+            Some(format!(
+                "{word} has two {letter}'s; if the answer has one {letter}, one comes back colored and the other grey"
+            ))
+        }
+        letters => {
+            let letters: Vec<String> = letters.iter().map(describe).collect();
+            Some(format!(
+                "{word} has {}; if the answer has fewer of a letter, only that many come back colored and the rest grey",
+                letters.join(" and ")
+            ))
+        }
+    }
+}
+
+/// Name the most likely answer, as `It's probably GRATE (62%)`
+///
+/// Returns `None` unless one candidate is strictly more likely than every
+/// other, so equally likely candidates get no readout.
+#[must_use]
+pub fn likely_answer_note(probabilities: &[(&Word, f64)]) -> Option<String> {
+    let (best, p) = probabilities
+        .iter()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+    let unique = probabilities
+        .iter()
+        .all(|(w, q)| std::ptr::eq(*w, *best) || q < p);
+    unique.then(|| format!("It's probably {} ({:.0}%)", word_label(best), p * 100.0))
+}
+
+/// Explain an endgame at risk, as `3 candidates remain with 1 guess left —
+/// a guaranteed win is impossible; best odds with GRATE (33% to win)`
+#[must_use]
+pub fn endgame_risk_note(risk: &EndgameRisk) -> String {
+    let left = format!(
+        "{} candidates remain with {} {} left",
+        risk.candidates,
+        risk.guesses_left,
+        if risk.guesses_left == 1 {
+            "guess"
+        } else {
+            "guesses"
+        }
+    );
+    let guess = word_label(&risk.guess);
+    if risk.guaranteed {
+        format!("{left} — a win is still guaranteed with {guess}")
+    } else {
+        format!(
+            "{left} — a guaranteed win is impossible; best odds with {guess} ({:.0}% to win)",
+            risk.win_probability * 100.0
+        )
+    }
+}
+
+/// Render a benchmark as two GitHub Markdown tables: summary statistics,
+/// then how many words took each guess count
+#[must_use]
+pub fn benchmark_markdown(result: &BenchmarkResult) -> String {
+    let mut out = String::from("| Metric | Value |\n|---|---:|\n");
+    let rows = [
+        ("Words tested", result.total_words.to_string()),
+        ("Average guesses", format!("{:.3}", result.average_guesses)),
+        ("Best case", result.min_guesses.to_string()),
+        ("Worst case", result.max_guesses.to_string()),
+        ("Failures", result.failures.to_string()),
+        ("Time", format!("{:.2}s", result.duration.as_secs_f64())),
+    ];
+    for (metric, value) in rows {
+        let _ = writeln!(out, "| {metric} | {value} |");
+    }
+
+    out.push_str("\n| Guesses | Words | Share |\n|---:|---:|---:|\n");
+    for guesses in 1..=MAX_GUESSES {
+        let count = solved_in(result, guesses);
+        let _ = writeln!(
+            out,
+            "| {guesses} | {count} | {:.1}% |",
+            share(count, result.total_words)
+        );
+    }
+    out
+}
+
+/// Render a beam width comparison as one GitHub Markdown table, a row per
+/// width with its summary statistics and guess distribution
+#[must_use]
+pub fn beam_comparison_markdown(runs: &[BeamRun]) -> String {
+    let Some(baseline) = runs.first() else {
+        return String::new();
+    };
+    let base_time = baseline.result.duration.as_secs_f64();
+
+    let mut out = String::from("| Beam | Average | Worst | Failures |");
+    for guesses in 1..=MAX_GUESSES {
+        let _ = write!(out, " {guesses} |");
+    }
+    out.push_str(" Time | Speedup |\n|---|---:|---:|---:|");
+    out.push_str(&"---:|".repeat(MAX_GUESSES));
+    out.push_str("---:|---:|\n");
+
+    for run in runs {
+        let result = &run.result;
+        let width = run
+            .width
+            .map_or_else(|| "full".to_string(), |w| w.to_string());
+        let _ = write!(
+            out,
+            "| {width} | {:.3} | {} | {} |",
+            result.average_guesses, result.max_guesses, result.failures
+        );
+        for guesses in 1..=MAX_GUESSES {
+            let _ = write!(out, " {} |", solved_in(result, guesses));
+        }
+        let time = result.duration.as_secs_f64();
+        let _ = writeln!(out, " {time:.2}s | {:.1}x |", base_time / time);
+    }
+    out
+}
+
+/// Words a benchmark solved in exactly `guesses` guesses
+fn solved_in(result: &BenchmarkResult, guesses: usize) -> usize {
+    result.distribution.get(&guesses).copied().unwrap_or(0)
+}
+
+/// `count` as a percentage of `total`, 0 when there is nothing to count
+fn share(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64 * 100.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn word_label_keeps_list_casing() {
+        assert_eq!(word_label(&Word::new("crane").unwrap()), "CRANE");
+        let proper = Word::new_preserving_case("Paris").unwrap();
+        assert_eq!(word_label(&proper), "Paris");
+    }
+
     #[test]
     fn pattern_to_emoji_all_gray() {
         let pattern = Pattern::new(0); // All gray
@@ -74,4 +322,148 @@ mod tests {
         let bar = create_progress_bar(50.0, 100.0, 10);
         assert_eq!(bar, "█████░░░░░");
     }
+
+    #[test]
+    fn format_surprise_labels_rare_outcomes() {
+        assert_eq!(
+            format_surprise(2315, 4).unwrap(),
+            "surprise: 9.2 bits (rare outcome!)"
+        );
+        assert_eq!(format_surprise(100, 25).unwrap(), "surprise: 2.0 bits");
+        assert_eq!(
+            format_surprise(10, 10).unwrap(),
+            "surprise: 0.0 bits (expected outcome)"
+        );
+        // Impossible feedback is a conflict, not infinite surprise
+        assert_eq!(format_surprise(100, 0), None);
+    }
+
+    #[test]
+    fn duplicate_letter_note_only_for_repeats() {
+        let note = |text: &str| duplicate_letter_note(&Word::new(text).unwrap());
+
+        assert_eq!(note("salet"), None);
+        assert_eq!(
+            note("speed").unwrap(),
+            "SPEED has two E's; if the answer has one E, one comes back colored and the other grey"
+        );
+        assert!(note("geese").unwrap().starts_with("GEESE has three E's;"));
+        assert!(
+            note("mamma")
+                .unwrap()
+                .starts_with("MAMMA has three M's and two A's;")
+        );
+    }
+
+    #[test]
+    fn endgame_risk_note_states_the_guarantee() {
+        let mut risk = EndgameRisk {
+            candidates: 3,
+            guesses_left: 1,
+            guess: Word::new("grate").unwrap(),
+            win_probability: 1.0 / 3.0,
+            guaranteed: false,
+        };
+        assert_eq!(
+            endgame_risk_note(&risk),
+            "3 candidates remain with 1 guess left — a guaranteed win is impossible; \
+             best odds with GRATE (33% to win)"
+        );
+
+        risk.guesses_left = 2;
+        risk.guess = Word::new("magic").unwrap();
+        risk.win_probability = 1.0;
+        risk.guaranteed = true;
+        assert_eq!(
+            endgame_risk_note(&risk),
+            "3 candidates remain with 2 guesses left — a win is still guaranteed with MAGIC"
+        );
+    }
+
+    #[test]
+    fn likely_answer_note_needs_a_clear_favorite() {
+        let grate = Word::new("grate").unwrap();
+        let crate_ = Word::new("crate").unwrap();
+
+        assert_eq!(
+            likely_answer_note(&[(&crate_, 0.38), (&grate, 0.62)]).unwrap(),
+            "It's probably GRATE (62%)"
+        );
+        assert_eq!(likely_answer_note(&[(&crate_, 0.5), (&grate, 0.5)]), None);
+        assert_eq!(likely_answer_note(&[]), None);
+    }
+
+    fn benchmark(distribution: &[(usize, usize)], failures: usize) -> BenchmarkResult {
+        let total_words = distribution.iter().map(|&(_, n)| n).sum::<usize>() + failures;
+        BenchmarkResult {
+            total_words,
+            total_guesses: 0,
+            average_guesses: 3.5,
+            min_guesses: 2,
+            max_guesses: 6,
+            distribution: distribution.iter().copied().collect(),
+            failures,
+            duration: std::time::Duration::from_secs(2),
+            words_per_second: total_words as f64 / 2.0,
+            turn_timings: Vec::new(),
+            timing: None,
+        }
+    }
+
+    /// Every line of a GitHub table has the header's column count
+    fn assert_table(table: &str) {
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines.len() >= 3, "{table}");
+        let columns = lines[0].matches('|').count();
+        for line in &lines {
+            assert!(line.starts_with('|') && line.ends_with('|'), "{line}");
+            assert_eq!(line.matches('|').count(), columns, "{line}");
+        }
+        assert!(
+            lines[1]
+                .trim_matches('|')
+                .split('|')
+                .all(|cell| cell.trim_end_matches(':').starts_with("---"))
+        );
+    }
+
+    #[test]
+    fn benchmark_markdown_has_summary_and_distribution() {
+        let markdown = benchmark_markdown(&benchmark(&[(2, 1), (3, 5), (4, 3)], 1));
+        let tables: Vec<&str> = markdown.split("\n\n").collect();
+        assert_eq!(tables.len(), 2);
+        for table in &tables {
+            assert_table(table);
+        }
+
+        assert!(tables[0].contains("| Average guesses | 3.500 |"));
+        assert!(tables[0].contains("| Failures | 1 |"));
+        // One row per guess count, empty ones included
+        assert_eq!(tables[1].lines().count(), 2 + MAX_GUESSES);
+        assert!(tables[1].contains("| 3 | 5 | 50.0% |"));
+        assert!(tables[1].contains("| 6 | 0 | 0.0% |"));
+        assert!(!markdown.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn beam_comparison_markdown_has_a_row_per_width() {
+        let runs = [
+            BeamRun {
+                width: None,
+                result: benchmark(&[(3, 4), (4, 1)], 0),
+            },
+            BeamRun {
+                width: Some(50),
+                result: benchmark(&[(3, 3), (4, 2)], 0),
+            },
+        ];
+        let markdown = beam_comparison_markdown(&runs);
+        assert_table(&markdown);
+        assert_eq!(markdown.lines().count(), 4);
+        assert!(
+            markdown.contains("| full | 3.500 | 6 | 0 | 0 | 0 | 4 | 1 | 0 | 0 | 2.00s | 1.0x |")
+        );
+        assert!(markdown.contains("| 50 |"));
+        assert!(beam_comparison_markdown(&[]).is_empty());
+    }
 }
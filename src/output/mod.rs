@@ -3,4 +3,11 @@
 pub mod display;
 pub mod formatters;
 
-pub use display::{print_analysis_result, print_benchmark_result, print_solve_result};
+pub use display::{
+    print_analysis_result, print_beam_comparison, print_beam_comparison_markdown,
+    print_benchmark_markdown, print_benchmark_result, print_coach_report, print_entropy_breakdown,
+    print_filter_result, print_first_guess_analysis, print_hard_opening, print_min_guaranteed,
+    print_opener_percentiles, print_opener_table, print_pattern_stats, print_reconstruction,
+    print_skill_score, print_solve_result, print_strategy_diff, print_strategy_diff_summary,
+    print_tournament, print_traps, print_verify_report, print_worst_case,
+};
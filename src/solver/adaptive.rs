@@ -2,8 +2,14 @@
 //!
 //! Adjusts tactics based on number of remaining candidates.
 
-use super::{selection, strategy::Strategy};
+use super::entropy::GuessMetrics;
+use super::selection;
+use super::strategy::{Strategy, find_in_pool};
 use crate::core::Word;
+use std::cmp::Ordering;
+
+/// Temperature that reproduces the default tuning (see [`AdaptiveStrategy::with_temperature`])
+pub const DEFAULT_TEMPERATURE: f64 = 0.5;
 
 /// Adaptive strategy with configurable tier thresholds
 ///
@@ -25,9 +31,31 @@ use crate::core::Word;
 /// - **101+ candidates**: `PureEntropy` - Pure entropy maximization
 /// - **22-100 candidates**: `EntropyMinimax` - Entropy + minimax tiebreakers
 /// - **10-21 candidates**: `Hybrid` - Hybrid scoring (entropy × 100) - (`max_partition` × 10)
-/// - **3-9 candidates**: `MinimaxFirst` - Minimax-first with 0.1 epsilon
+/// - **3-9 candidates**: `MinimaxFirst` - Exhaustive endgame search
 /// - **1-2 candidates**: `Random` - Random selection from candidates
-#[derive(Debug, Clone)]
+///
+/// The endgame search minimizes the worst case exactly rather than one guess
+/// at a time. It covers `MinimaxFirst` counts up to `endgame_threshold`
+/// (default 9, the whole tier); above that the tier plays minimax-first with
+/// `minimax_first_epsilon` (default 0.1). The strategy doesn't see how many
+/// guesses are left: on the last one [`Solver`](crate::solver::Solver)
+/// plays the likeliest candidate instead, so the search only decides with
+/// two or more to go.
+///
+/// In the `EntropyMinimax` tier, `minimax_penalty` (λ) scores guesses by
+/// entropy - λ × (`max_partition` / candidates). The default λ = 0 uses the
+/// worst case only as a tiebreaker. The `Hybrid` tier's worst-case weight
+/// (default 10) is `hybrid_weight`.
+///
+/// [`Self::with_temperature`] sets λ, `hybrid_weight` and
+/// `minimax_first_epsilon` together from a single dial, from safe
+/// (worst case first) to aggressive (entropy only). The endgame search
+/// ignores the dial, so by default it only tunes the 10-100 candidate tiers.
+///
+/// With `beam_width` set, the `PureEntropy` tier only computes entropy for
+/// the guesses with the best letter-frequency scores, which is faster but
+/// can miss the best split.
+#[derive(Debug, Clone, PartialEq)]
 pub struct AdaptiveStrategy {
     /// Candidates > this use `PureEntropy` (default: 100)
     pub pure_entropy_threshold: usize,
@@ -40,6 +68,27 @@ pub struct AdaptiveStrategy {
 
     /// Candidates > this use `MinimaxFirst` (default: 2)
     pub minimax_first_threshold: usize,
+
+    /// Worst-case penalty λ in the `EntropyMinimax` tier (default: 0.0)
+    pub minimax_penalty: f64,
+
+    /// Seed for the `Random` tier; `None` picks nondeterministically (default)
+    pub random_seed: Option<u64>,
+
+    /// `MinimaxFirst` counts at or below this use the endgame search (default: 9)
+    pub endgame_threshold: usize,
+
+    /// Entropy margin within which `MinimaxFirst` prefers a candidate over a
+    /// better-splitting non-candidate (default: 0.1). Only used above
+    /// `endgame_threshold`.
+    pub minimax_first_epsilon: f64,
+
+    /// Worst-case weight in the `Hybrid` tier's score (default: 10.0)
+    pub hybrid_weight: f64,
+
+    /// Guesses kept for the full entropy pass in `PureEntropy`; `None`
+    /// scores every guess (default)
+    pub beam_width: Option<usize>,
 }
 
 impl AdaptiveStrategy {
@@ -56,9 +105,92 @@ impl AdaptiveStrategy {
             entropy_minimax_threshold,
             hybrid_threshold,
             minimax_first_threshold,
+            minimax_penalty: 0.0,
+            random_seed: None,
+            endgame_threshold: selection::ENDGAME_MAX_CANDIDATES,
+            minimax_first_epsilon: 0.1,
+            hybrid_weight: selection::DEFAULT_HYBRID_WEIGHT,
+            beam_width: None,
         }
     }
 
+    /// Seed the `Random` tier so the same game state always yields the same guess
+    #[must_use]
+    pub const fn with_random_seed(mut self, seed: u64) -> Self {
+        self.random_seed = Some(seed);
+        self
+    }
+
+    /// Set the worst-case penalty λ used in the `EntropyMinimax` tier
+    #[must_use]
+    pub const fn with_minimax_penalty(mut self, penalty: f64) -> Self {
+        self.minimax_penalty = penalty;
+        self
+    }
+
+    /// Set the largest candidate count for the endgame search (0 disables it)
+    #[must_use]
+    pub const fn with_endgame_threshold(mut self, threshold: usize) -> Self {
+        self.endgame_threshold = threshold;
+        self
+    }
+
+    /// Set the candidate-preference epsilon used in the `MinimaxFirst` tier
+    #[must_use]
+    pub const fn with_minimax_first_epsilon(mut self, epsilon: f64) -> Self {
+        self.minimax_first_epsilon = epsilon;
+        self
+    }
+
+    /// Set the worst-case weight used in the `Hybrid` tier
+    #[must_use]
+    pub const fn with_hybrid_weight(mut self, weight: f64) -> Self {
+        self.hybrid_weight = weight;
+        self
+    }
+
+    /// Set the worst-case penalty, hybrid weight and candidate-preference
+    /// epsilon from one temperature in [0, 1] (values outside are clamped)
+    ///
+    /// | temperature | λ (22-100)  | hybrid weight | epsilon |
+    /// |-------------|-------------|---------------|---------|
+    /// | 0 (safe)    | 999         | 1000          | 0       |
+    /// | 0.5         | 0           | 10            | 0.1     |
+    /// | 1 (aggressive) | 0        | 0             | 0.2     |
+    ///
+    /// At 0 the worst case outweighs any entropy difference, so the middle
+    /// tiers play minimax. At 1 the middle tiers play pure entropy. The
+    /// default, [`DEFAULT_TEMPERATURE`], reproduces the tuned defaults. Below
+    /// 0.5 the penalty and weight grow geometrically; above it they shrink
+    /// linearly.
+    ///
+    /// Epsilon only applies to 3-9 candidates above `endgame_threshold`,
+    /// which by default covers the whole tier: the exact endgame search
+    /// decides it at every temperature. Lower the threshold (see
+    /// [`Self::with_endgame_threshold`]) for the dial to reach that tier,
+    /// where 0 never trades a split for a chance to win now.
+    #[must_use]
+    pub fn with_temperature(mut self, temperature: f64) -> Self {
+        let t = temperature.clamp(0.0, 1.0);
+        let safety = ((DEFAULT_TEMPERATURE - t) / DEFAULT_TEMPERATURE).max(0.0);
+        self.minimax_penalty = 1000_f64.powf(safety) - 1.0;
+        self.hybrid_weight = if t < DEFAULT_TEMPERATURE {
+            selection::DEFAULT_HYBRID_WEIGHT * 100_f64.powf(safety)
+        } else {
+            selection::DEFAULT_HYBRID_WEIGHT * (1.0 - t) / (1.0 - DEFAULT_TEMPERATURE)
+        };
+        self.minimax_first_epsilon = 0.2 * t;
+        self
+    }
+
+    /// Limit the `PureEntropy` tier's entropy pass to the `width` guesses with
+    /// the best letter-frequency scores
+    #[must_use]
+    pub const fn with_beam_width(mut self, width: usize) -> Self {
+        self.beam_width = Some(width);
+        self
+    }
+
     /// Get the current tier based on number of candidates
     #[must_use]
     pub const fn get_tier(&self, num_candidates: usize) -> AdaptiveTier {
@@ -74,6 +206,89 @@ impl AdaptiveStrategy {
             AdaptiveTier::Random
         }
     }
+
+    /// Get the candidate count range covered by a tier
+    ///
+    /// Returns `(min, max)` where `max` is `None` for the unbounded top tier.
+    /// A tier whose range is empty under custom thresholds returns `min > max`.
+    #[must_use]
+    pub const fn tier_range(&self, tier: AdaptiveTier) -> (usize, Option<usize>) {
+        match tier {
+            AdaptiveTier::PureEntropy => (self.pure_entropy_threshold + 1, None),
+            AdaptiveTier::EntropyMinimax => (
+                self.entropy_minimax_threshold + 1,
+                Some(self.pure_entropy_threshold),
+            ),
+            AdaptiveTier::Hybrid => (
+                self.hybrid_threshold + 1,
+                Some(self.entropy_minimax_threshold),
+            ),
+            AdaptiveTier::MinimaxFirst => (
+                self.minimax_first_threshold + 1,
+                Some(self.hybrid_threshold),
+            ),
+            AdaptiveTier::Random => (1, Some(self.minimax_first_threshold)),
+        }
+    }
+
+    /// Select a guess using a specific tier's tactic, ignoring the thresholds
+    ///
+    /// `select_among` calls this with the tier for the candidate count; calling
+    /// it directly forces a tier regardless of how many candidates there are.
+    #[must_use]
+    pub fn select_with_tier<'a>(
+        &self,
+        tier: AdaptiveTier,
+        guess_pool: &[&'a Word],
+        candidates: &[&Word],
+    ) -> Option<&'a Word> {
+        let from_pool = |word: &Word| find_in_pool(guess_pool, word);
+
+        match tier {
+            AdaptiveTier::PureEntropy => {
+                // 101+ candidates: Pure entropy maximization, optionally over a beam
+                let beam;
+                let pool = match self.beam_width {
+                    Some(width) => {
+                        beam = selection::beam_prefilter(guess_pool, candidates, width);
+                        &beam[..]
+                    }
+                    None => guess_pool,
+                };
+                let (best, _) = super::entropy::select_best_guess(pool, candidates)?;
+                from_pool(best)
+            }
+
+            AdaptiveTier::EntropyMinimax => {
+                // 22-100 candidates: Entropy + minimax tiebreakers (or penalty)
+                selection::select_with_minimax_penalty(guess_pool, candidates, self.minimax_penalty)
+                    .and_then(from_pool)
+            }
+
+            AdaptiveTier::Hybrid => {
+                // 10-21 candidates: Hybrid scoring
+                selection::select_with_hybrid_weight(guess_pool, candidates, self.hybrid_weight)
+                    .and_then(from_pool)
+            }
+
+            AdaptiveTier::MinimaxFirst if candidates.len() <= self.endgame_threshold => {
+                // 3-9 candidates: Exhaustive endgame search
+                selection::select_endgame(guess_pool, candidates).and_then(from_pool)
+            }
+
+            AdaptiveTier::MinimaxFirst => {
+                // Endgame search disabled: Minimax-first with candidate epsilon
+                selection::select_minimax_first(guess_pool, candidates, self.minimax_first_epsilon)
+                    .and_then(from_pool)
+            }
+
+            AdaptiveTier::Random => {
+                // 1-2 candidates: Random selection
+                super::strategy::RandomStrategy::with_seed(self.random_seed)
+                    .select_among(guess_pool, candidates)
+            }
+        }
+    }
 }
 
 impl Default for AdaptiveStrategy {
@@ -88,6 +303,96 @@ impl Default for AdaptiveStrategy {
     }
 }
 
+/// A named adaptive configuration with its measured performance
+///
+/// `average`, `worst` and `at_worst` come from playing every answer with the
+/// full word lists, opening with SALET and the `Random` tier seeded with 0
+/// (the `worst-case` command's setup). The default tuning measures 3.4333
+/// on average, with 2 answers needing 6 guesses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptivePreset {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Tier thresholds, as passed to [`AdaptiveStrategy::new`]
+    pub thresholds: [usize; 4],
+    pub minimax_penalty: f64,
+    pub hybrid_weight: f64,
+    pub endgame_threshold: usize,
+    pub minimax_first_epsilon: f64,
+    pub beam_width: Option<usize>,
+    /// Measured average guesses over all answers
+    pub average: f64,
+    /// Measured most guesses any answer needs
+    pub worst: usize,
+    /// Measured number of answers that need `worst` guesses
+    pub at_worst: usize,
+}
+
+/// Tested adaptive configurations, selectable by name
+pub const ADAPTIVE_PRESETS: [AdaptivePreset; 3] = [
+    AdaptivePreset {
+        name: "optimal-average",
+        description: "fewest guesses on average: exact endgame search up to 12 candidates (about 2x slower than the default)",
+        thresholds: [100, 21, 12, 2],
+        minimax_penalty: 0.0,
+        hybrid_weight: selection::DEFAULT_HYBRID_WEIGHT,
+        endgame_threshold: 12,
+        minimax_first_epsilon: 0.1,
+        beam_width: None,
+        average: 3.4328,
+        worst: 6,
+        at_worst: 2,
+    },
+    AdaptivePreset {
+        name: "optimal-worstcase",
+        description: "middle tiers play minimax (temperature 0): fewest answers at the worst case, at a cost to the average",
+        thresholds: [100, 21, 9, 2],
+        minimax_penalty: 999.0,
+        hybrid_weight: 1000.0,
+        endgame_threshold: selection::ENDGAME_MAX_CANDIDATES,
+        minimax_first_epsilon: 0.0,
+        beam_width: None,
+        average: 3.4635,
+        worst: 6,
+        at_worst: 1,
+    },
+    AdaptivePreset {
+        name: "fast",
+        description: "beam of 200 guesses and endgame search up to 5 candidates: about 5x faster than the default",
+        thresholds: [100, 21, 9, 2],
+        minimax_penalty: 0.0,
+        hybrid_weight: selection::DEFAULT_HYBRID_WEIGHT,
+        endgame_threshold: 5,
+        minimax_first_epsilon: 0.1,
+        beam_width: Some(200),
+        average: 3.4341,
+        worst: 6,
+        at_worst: 2,
+    },
+];
+
+impl AdaptivePreset {
+    /// Look up a preset by name
+    #[must_use]
+    pub fn find(name: &str) -> Option<&'static Self> {
+        ADAPTIVE_PRESETS.iter().find(|preset| preset.name == name)
+    }
+
+    /// Build the adaptive strategy this preset describes
+    #[must_use]
+    pub const fn strategy(&self) -> AdaptiveStrategy {
+        let [pure_entropy, entropy_minimax, hybrid, minimax_first] = self.thresholds;
+        let mut strategy =
+            AdaptiveStrategy::new(pure_entropy, entropy_minimax, hybrid, minimax_first)
+                .with_minimax_penalty(self.minimax_penalty)
+                .with_hybrid_weight(self.hybrid_weight)
+                .with_endgame_threshold(self.endgame_threshold)
+                .with_minimax_first_epsilon(self.minimax_first_epsilon);
+        strategy.beam_width = self.beam_width;
+        strategy
+    }
+}
+
 /// The current tier/phase of the adaptive strategy
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AdaptiveTier {
@@ -100,53 +405,53 @@ pub enum AdaptiveTier {
     /// Few candidates (10-21): Hybrid scoring
     Hybrid,
 
-    /// Very few (3-9): Minimax-first with candidate preference
+    /// Very few (3-9): Exhaustive endgame search, or minimax-first with
+    /// candidate preference above `endgame_threshold`
     MinimaxFirst,
 
     /// Endgame (1-2): Random selection from candidates
     Random,
 }
 
-impl Strategy for AdaptiveStrategy {
-    fn select_guess<'a>(&self, guess_pool: &'a [Word], candidates: &[Word]) -> Option<&'a Word> {
-        let tier = self.get_tier(candidates.len());
-
-        // Create reference vectors once
-        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
-        let candidate_refs: Vec<&Word> = candidates.iter().collect();
-
-        // Helper to find word in guess_pool by text comparison
-        let find_in_pool = |word: &Word| guess_pool.iter().find(|w| w.text() == word.text());
+impl AdaptiveTier {
+    /// Short display name for the tier
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::PureEntropy => "PureEntropy",
+            Self::EntropyMinimax => "EntropyMinimax",
+            Self::Hybrid => "Hybrid",
+            Self::MinimaxFirst => "MinimaxFirst",
+            Self::Random => "Random",
+        }
+    }
+}
 
-        match tier {
-            AdaptiveTier::PureEntropy => {
-                // 101+ candidates: Pure entropy maximization
-                let (best, _) = super::entropy::select_best_guess(&guess_refs, &candidate_refs)?;
-                find_in_pool(best)
-            }
+impl Strategy for AdaptiveStrategy {
+    fn select_among<'a>(&self, guess_pool: &[&'a Word], candidates: &[&Word]) -> Option<&'a Word> {
+        self.select_with_tier(self.get_tier(candidates.len()), guess_pool, candidates)
+    }
 
+    fn compare_guesses(
+        &self,
+        a: &GuessMetrics,
+        b: &GuessMetrics,
+        num_candidates: usize,
+    ) -> Ordering {
+        match self.get_tier(num_candidates) {
+            AdaptiveTier::PureEntropy | AdaptiveTier::Random => a.entropy.total_cmp(&b.entropy),
             AdaptiveTier::EntropyMinimax => {
-                // 22-100 candidates: Entropy + minimax tiebreakers
-                selection::select_with_expected_tiebreaker(&guess_refs, &candidate_refs)
-                    .and_then(find_in_pool)
-            }
-
-            AdaptiveTier::Hybrid => {
-                // 10-21 candidates: Hybrid scoring
-                selection::select_with_hybrid_scoring(&guess_refs, &candidate_refs)
-                    .and_then(find_in_pool)
-            }
-
-            AdaptiveTier::MinimaxFirst => {
-                // 3-9 candidates: Minimax-first with 0.1 epsilon
-                selection::select_minimax_first(&guess_refs, &candidate_refs, 0.1)
-                    .and_then(find_in_pool)
-            }
-
-            AdaptiveTier::Random => {
-                // 1-2 candidates: Random selection
-                super::strategy::RandomStrategy.select_guess(guess_pool, candidates)
+                let score =
+                    |m| selection::penalized_entropy(m, num_candidates, self.minimax_penalty);
+                score(a)
+                    .total_cmp(&score(b))
+                    .then(b.expected_remaining.total_cmp(&a.expected_remaining))
+                    .then(b.max_partition.cmp(&a.max_partition))
             }
+            AdaptiveTier::Hybrid => selection::weighted_hybrid_score(a, self.hybrid_weight)
+                .cmp(&selection::weighted_hybrid_score(b, self.hybrid_weight))
+                .then(b.expected_remaining.total_cmp(&a.expected_remaining)),
+            AdaptiveTier::MinimaxFirst => super::strategy::compare_minimax(a, b),
         }
     }
 }
@@ -154,6 +459,53 @@ impl Strategy for AdaptiveStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solver::StrategyType;
+
+    #[test]
+    fn presets_match_the_tunings_they_name() {
+        let names: Vec<&str> = ADAPTIVE_PRESETS.iter().map(|p| p.name).collect();
+        assert_eq!(names, ["optimal-average", "optimal-worstcase", "fast"]);
+        assert!(AdaptivePreset::find("slow").is_none());
+
+        let same = |a: &AdaptiveStrategy, b: &AdaptiveStrategy| {
+            assert_eq!(
+                (
+                    a.pure_entropy_threshold,
+                    a.entropy_minimax_threshold,
+                    a.hybrid_threshold,
+                    a.minimax_first_threshold,
+                    a.endgame_threshold,
+                    a.beam_width
+                ),
+                (
+                    b.pure_entropy_threshold,
+                    b.entropy_minimax_threshold,
+                    b.hybrid_threshold,
+                    b.minimax_first_threshold,
+                    b.endgame_threshold,
+                    b.beam_width
+                )
+            );
+            assert!((a.minimax_penalty - b.minimax_penalty).abs() < 1e-9);
+            assert!((a.hybrid_weight - b.hybrid_weight).abs() < 1e-9);
+            assert!((a.minimax_first_epsilon - b.minimax_first_epsilon).abs() < 1e-9);
+        };
+        let preset = |name| AdaptivePreset::find(name).unwrap().strategy();
+        same(
+            &preset("optimal-average"),
+            &AdaptiveStrategy::new(100, 21, 12, 2).with_endgame_threshold(12),
+        );
+        same(
+            &preset("optimal-worstcase"),
+            &AdaptiveStrategy::default().with_temperature(0.0),
+        );
+
+        // Preset names work wherever a strategy name does
+        let StrategyType::Adaptive(fast) = StrategyType::from_name("fast") else {
+            panic!("fast is an adaptive preset");
+        };
+        same(&fast, &preset("fast"));
+    }
 
     #[test]
     fn adaptive_tiers_correct() {
@@ -190,6 +542,81 @@ mod tests {
         assert_eq!(strategy.get_tier(5), AdaptiveTier::Random);
     }
 
+    #[test]
+    fn tier_range_matches_get_tier() {
+        let strategy = AdaptiveStrategy::default();
+
+        assert_eq!(strategy.tier_range(AdaptiveTier::PureEntropy), (101, None));
+        assert_eq!(
+            strategy.tier_range(AdaptiveTier::EntropyMinimax),
+            (22, Some(100))
+        );
+        assert_eq!(strategy.tier_range(AdaptiveTier::Hybrid), (10, Some(21)));
+        assert_eq!(
+            strategy.tier_range(AdaptiveTier::MinimaxFirst),
+            (3, Some(9))
+        );
+        assert_eq!(strategy.tier_range(AdaptiveTier::Random), (1, Some(2)));
+
+        // Every count maps to the tier whose range contains it
+        for n in 1..=150 {
+            let (min, max) = strategy.tier_range(strategy.get_tier(n));
+            assert!(n >= min && max.is_none_or(|max| n <= max), "count {n}");
+        }
+    }
+
+    #[test]
+    fn default_temperature_reproduces_defaults() {
+        let default = AdaptiveStrategy::default();
+        let tempered = AdaptiveStrategy::default().with_temperature(DEFAULT_TEMPERATURE);
+
+        assert!(tempered.minimax_penalty.abs() < f64::EPSILON);
+        assert!((tempered.hybrid_weight - default.hybrid_weight).abs() < 1e-9);
+        assert!((tempered.minimax_first_epsilon - default.minimax_first_epsilon).abs() < 1e-9);
+    }
+
+    #[test]
+    fn temperature_extremes_play_minimax_and_entropy() {
+        use crate::solver::entropy::calculate_metrics;
+        use crate::wordlists::loader::words_from_slice;
+        use crate::wordlists::{ALLOWED, ANSWERS};
+
+        let guess_pool = words_from_slice(&ALLOWED[..2000]);
+        let candidates = words_from_slice(&ANSWERS[..15]);
+        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+        let metrics: Vec<_> = guess_pool
+            .iter()
+            .map(|g| calculate_metrics(g, &candidate_refs))
+            .collect();
+        let pick = |temperature: f64| {
+            let strategy = AdaptiveStrategy::default().with_temperature(temperature);
+            let guess = strategy
+                .select_with_tier(AdaptiveTier::Hybrid, &guess_refs, &candidate_refs)
+                .unwrap();
+            calculate_metrics(guess, &candidate_refs)
+        };
+
+        let safe = pick(0.0);
+        let min_worst = metrics.iter().map(|m| m.max_partition).min().unwrap();
+        assert_eq!(safe.max_partition, min_worst);
+        // Out of range temperatures clamp to the extremes
+        let below = AdaptiveStrategy::default().with_temperature(-1.0);
+        assert!((below.hybrid_weight - 1000.0).abs() < 1e-9);
+
+        let aggressive = pick(1.0);
+        let max_entropy = metrics.iter().map(|m| m.entropy).fold(0.0, f64::max);
+        // The hybrid score keeps entropy to hundredths of a bit
+        assert!(aggressive.entropy > max_entropy - 0.01);
+        assert!(
+            AdaptiveStrategy::default()
+                .with_temperature(1.0)
+                .minimax_penalty
+                .abs()
+                < f64::EPSILON
+        );
+    }
+
     #[test]
     fn adaptive_selects_candidate_when_few_remain() {
         let guess_pool = vec![
@@ -209,4 +636,100 @@ mod tests {
         // With 1 candidate, should select it
         assert_eq!(guess.text(), "irate");
     }
+
+    #[test]
+    fn select_with_tier_forces_tier_on_small_sets() {
+        let guess_pool = [
+            Word::new("crane").unwrap(),
+            Word::new("slate").unwrap(),
+            Word::new("irate").unwrap(),
+            Word::new("crate").unwrap(),
+            Word::new("grate").unwrap(),
+        ];
+        let candidates = [
+            Word::new("irate").unwrap(),
+            Word::new("crate").unwrap(),
+            Word::new("grate").unwrap(),
+        ];
+        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+        let strategy = AdaptiveStrategy::default();
+
+        // Hybrid normally needs 10-21 candidates; forcing it uses hybrid scoring
+        let hybrid = strategy.select_with_tier(AdaptiveTier::Hybrid, &guess_refs, &candidate_refs);
+        let expected = selection::select_with_hybrid_scoring(&guess_refs, &candidate_refs);
+        assert_eq!(hybrid.map(Word::text), expected.map(Word::text));
+
+        // Random always picks one of the candidates
+        let random = strategy
+            .select_with_tier(AdaptiveTier::Random, &guess_refs, &candidate_refs)
+            .unwrap();
+        assert!(candidates.contains(random));
+
+        // Every tier produces a guess from the pool
+        for tier in [
+            AdaptiveTier::PureEntropy,
+            AdaptiveTier::EntropyMinimax,
+            AdaptiveTier::MinimaxFirst,
+        ] {
+            let guess = strategy.select_with_tier(tier, &guess_refs, &candidate_refs);
+            assert!(guess.is_some_and(|g| guess_pool.contains(g)), "{tier:?}");
+        }
+    }
+
+    #[test]
+    fn minimax_penalty_defaults_to_zero() {
+        assert!(AdaptiveStrategy::default().minimax_penalty.abs() < f64::EPSILON);
+
+        let strategy = AdaptiveStrategy::default().with_minimax_penalty(1.5);
+        assert!((strategy.minimax_penalty - 1.5).abs() < f64::EPSILON);
+        assert_eq!(strategy.get_tier(50), AdaptiveTier::EntropyMinimax);
+    }
+
+    #[test]
+    fn minimax_first_epsilon_reaches_selection() {
+        let strategy = AdaptiveStrategy::default();
+        assert!((strategy.minimax_first_epsilon - 0.1).abs() < f64::EPSILON);
+
+        let guess_pool: Vec<Word> = ["befog", "breed", "creed", "freed", "greed"]
+            .iter()
+            .map(|w| Word::new(*w).unwrap())
+            .collect();
+        let candidates = guess_pool[1..].to_vec();
+        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+
+        for epsilon in [0.0, 0.1, 5.0] {
+            let strategy = AdaptiveStrategy::default()
+                .with_endgame_threshold(0)
+                .with_minimax_first_epsilon(epsilon);
+            let guess =
+                strategy.select_with_tier(AdaptiveTier::MinimaxFirst, &guess_refs, &candidate_refs);
+            let expected = selection::select_minimax_first(&guess_refs, &candidate_refs, epsilon);
+            assert_eq!(guess.map(Word::text), expected.map(Word::text), "{epsilon}");
+        }
+    }
+
+    #[test]
+    fn minimax_first_epsilon_tunes_play_below_the_endgame() {
+        use crate::wordlists::loader::words_from_slice;
+
+        let guess_pool = words_from_slice(&["aarti", "acorn", "acrid", "actor", "acute"]);
+        let candidates = guess_pool[1..].to_vec();
+        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+        let pick = |epsilon| {
+            AdaptiveStrategy::default()
+                .with_endgame_threshold(0)
+                .with_minimax_first_epsilon(epsilon)
+                .select_among(&guess_refs, &candidate_refs)
+                .map(Word::text)
+        };
+
+        // Default tier thresholds with the endgame search lowered: four
+        // candidates play minimax-first, where epsilon decides whether a
+        // candidate that splits a little worse is worth a chance to win now
+        assert_eq!(pick(0.0), Some("aarti"));
+        assert_eq!(pick(0.2), Some("acorn"));
+    }
 }
@@ -1,15 +1,139 @@
 //! Main Wordle solver interface
 
+use super::entropy::{GuessMetrics, calculate_metrics};
+use super::estimate::candidate_probabilities;
+use super::risk::{EndgameRisk, assess_endgame};
 use super::strategy::Strategy;
-use crate::core::{Pattern, Word};
+use super::text_tiebreak;
+use crate::core::{Constraints, Pattern, Word};
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
+/// Number of guesses allowed in a standard Wordle game
+pub const MAX_GUESSES: usize = 6;
+
+/// Revision of the guess selection logic
+///
+/// Bump it with any change that can alter the guess a solver picks, so
+/// results saved by an older solver, such as decision trees, are rebuilt
+/// instead of reused.
+pub const SOLVER_REVISION: u32 = 3;
+
+/// Entropy (bits) soft hard mode gives up to avoid playing a letter known to
+/// be absent
+pub const SOFT_HARD_PENALTY: f64 = 0.05;
+
+/// Outcome of a game so far, derived from its guess history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    /// The last guess matched the answer
+    Won { guesses: usize },
+    /// The guess limit was reached without a match
+    Lost,
+    /// Guesses remain; `candidates` may be 0 if the feedback was inconsistent
+    InProgress { candidates: usize },
+}
+
+impl GameStatus {
+    /// Status after `history`, calling `candidates` for the candidate count
+    /// only while the game is in progress
+    ///
+    /// Lets callers that already filtered the candidates reuse the count
+    /// instead of filtering again, as [`Solver::status`] does.
+    pub fn new(
+        history: &[(Word, Pattern)],
+        max_guesses: usize,
+        candidates: impl FnOnce() -> usize,
+    ) -> Self {
+        if history
+            .last()
+            .is_some_and(|(_, pattern)| pattern.is_perfect())
+        {
+            Self::Won {
+                guesses: history.len(),
+            }
+        } else if history.len() >= max_guesses {
+            Self::Lost
+        } else {
+            Self::InProgress {
+                candidates: candidates(),
+            }
+        }
+    }
+
+    /// Whether the game has ended (won or lost)
+    #[must_use]
+    pub const fn is_finished(self) -> bool {
+        !matches!(self, Self::InProgress { .. })
+    }
+}
+
+/// When to guess the likeliest candidate outright instead of a discriminating guess
+///
+/// The solver commits when at most `max_candidates` remain, or when the
+/// likeliest candidate's probability (its weight normalized over the
+/// remaining set) is at least `min_probability`. The likeliest candidate is
+/// the first in answer-list order among those with the highest weight, so
+/// the choice is deterministic. With uniform weights only `max_candidates`
+/// matters once three or more candidates remain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CommitRule {
+    /// Commit whenever this many candidates or fewer remain
+    pub max_candidates: usize,
+    /// Commit when the likeliest candidate is at least this probable
+    pub min_probability: f64,
+}
+
+impl Default for CommitRule {
+    fn default() -> Self {
+        Self {
+            max_candidates: 2,
+            min_probability: 0.6,
+        }
+    }
+}
+
+impl CommitRule {
+    /// The candidate to commit to under this rule, or None to play on
+    ///
+    /// `weight` gives each candidate's relative likelihood, as in
+    /// [`candidate_probabilities`](super::candidate_probabilities).
+    pub fn choose<'a>(
+        &self,
+        candidates: &[&'a Word],
+        weight: impl Fn(&Word) -> f64,
+    ) -> Option<&'a Word> {
+        let probabilities = candidate_probabilities(candidates, weight);
+        let (top, probability) = probabilities
+            .into_iter()
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })?;
+        (candidates.len() <= self.max_candidates || probability >= self.min_probability)
+            .then_some(top)
+    }
+}
+
+/// Weight of each answer, for rules that favor likelier candidates
+pub type AnswerWeight<'a> = &'a (dyn Fn(&Word) -> f64 + Sync);
 
 /// Main Wordle solver
 ///
 /// Coordinates the solving process using a given strategy.
+///
+/// Besides the strategy it holds only settings: an opener (precomputed by
+/// the caller, see [`Solver::with_opener`]), a commit rule and answer
+/// weights ([`Solver::with_commit`], [`Solver::with_answer_weight`]) and
+/// soft hard mode. Its word lists are borrowed immutably for its whole
+/// lifetime, so they cannot change underneath it; to use different lists,
+/// build a new `Solver`. Any cache derived from the word lists should live
+/// in this struct so it is dropped with the lists it was built from.
 pub struct Solver<'a, S: Strategy> {
     strategy: S,
     all_words: &'a [Word],
     answer_words: &'a [Word],
+    opener: Option<&'a Word>,
+    commit: Option<CommitRule>,
+    answer_weight: Option<AnswerWeight<'a>>,
+    soft_hard_mode: bool,
 }
 
 impl<'a, S: Strategy> Solver<'a, S> {
@@ -19,26 +143,130 @@ impl<'a, S: Strategy> Solver<'a, S> {
     /// - `strategy`: The guess selection strategy to use
     /// - `all_words`: All valid guessable words
     /// - `answer_words`: Subset of words that can be answers
+    ///
+    /// The solver borrows both lists as given and does not merge them. An
+    /// answer missing from `all_words` can be narrowed down to but never
+    /// guessed, so callers must add the missing answers to the pool
+    /// themselves before building the solver, as the CLI does:
+    ///
+    /// ```
+    /// use wordle_solver::solver::{EntropyStrategy, Solver};
+    /// use wordle_solver::wordlists::loader::{add_missing_answers, words_from_slice};
+    ///
+    /// let mut all_words = words_from_slice(&["salet", "crane"]);
+    /// let answer_words = words_from_slice(&["crane", "zebra"]);
+    /// add_missing_answers(&mut all_words, &answer_words);
+    ///
+    /// let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+    /// assert!(solver.all_words().iter().any(|w| w.text() == "zebra"));
+    /// ```
     pub const fn new(strategy: S, all_words: &'a [Word], answer_words: &'a [Word]) -> Self {
         Self {
             strategy,
             all_words,
             answer_words,
+            opener: None,
+            commit: None,
+            answer_weight: None,
+            soft_hard_mode: false,
         }
     }
 
+    /// Create a solver with its first guess computed up front
+    ///
+    /// Unlike [`Self::new`] this does work: it runs [`Self::first_guess`]
+    /// once (the SALET scan, or the strategy when SALET isn't in the pool)
+    /// and keeps the result as the opener, so each game's opening
+    /// `next_guess(&[])` is a field read. Worth it when playing many games.
+    #[must_use]
+    pub fn prepared(strategy: S, all_words: &'a [Word], answer_words: &'a [Word]) -> Self {
+        let solver = Self::new(strategy, all_words, answer_words);
+        let opener = solver.first_guess();
+        solver.with_opener(opener)
+    }
+
+    /// Use a precomputed first guess instead of SALET or the strategy
+    ///
+    /// Typically the opener from a word list's manifest. `None` keeps the
+    /// default behavior.
+    #[must_use]
+    pub fn with_opener(mut self, opener: Option<&'a Word>) -> Self {
+        self.opener = opener;
+        self
+    }
+
+    /// Guess the likeliest candidate directly when `rule` says it is near-certain
+    ///
+    /// Checked before the strategy on every turn after the first. `None`
+    /// (the default) always defers to the strategy.
+    #[must_use]
+    pub const fn with_commit(mut self, rule: Option<CommitRule>) -> Self {
+        self.commit = rule;
+        self
+    }
+
+    /// Weight answers by likelihood for the commit rule (uniform if unset)
+    #[must_use]
+    pub fn with_answer_weight(mut self, weight: Option<AnswerWeight<'a>>) -> Self {
+        self.answer_weight = weight;
+        self
+    }
+
+    /// Prefer guesses that avoid letters already proven absent (soft hard mode)
+    ///
+    /// Unlike hard mode nothing is forbidden: a guess playing an absent
+    /// letter is replaced only by a near-equal one that doesn't, i.e. one
+    /// with no larger worst-case partition and at most [`SOFT_HARD_PENALTY`]
+    /// bits less entropy. Off by default.
+    #[must_use]
+    pub const fn with_soft_hard_mode(mut self, enabled: bool) -> Self {
+        self.soft_hard_mode = enabled;
+        self
+    }
+
+    /// Build a solver over the same word lists and opener with another strategy
+    #[must_use]
+    pub const fn with_strategy<T: Strategy>(&self, strategy: T) -> Solver<'a, T> {
+        Solver {
+            strategy,
+            all_words: self.all_words,
+            answer_words: self.answer_words,
+            opener: self.opener,
+            commit: self.commit,
+            answer_weight: self.answer_weight,
+            soft_hard_mode: self.soft_hard_mode,
+        }
+    }
+
+    /// Get the strategy used by this solver
+    pub const fn strategy(&self) -> &S {
+        &self.strategy
+    }
+
+    /// Get the guess pool this solver picks from
+    #[must_use]
+    pub const fn all_words(&self) -> &'a [Word] {
+        self.all_words
+    }
+
+    /// Get the words this solver considers possible answers
+    #[must_use]
+    pub const fn answer_words(&self) -> &'a [Word] {
+        self.answer_words
+    }
+
     /// Get the best first guess for a new game
     ///
-    /// Returns SALET if available (MIT-proven optimal), otherwise uses strategy.
+    /// Returns the opener set with [`Self::with_opener`], else SALET if
+    /// available (MIT-proven optimal), otherwise uses strategy.
     /// SALET achieves 3.421 average guesses (proven optimal via dynamic programming).
     ///
     /// Note: SALET has 5.835 bits entropy, which is not the maximum, but it's
     /// optimal for minimizing expected guesses across all possible answers.
     pub fn first_guess(&self) -> Option<&'a Word> {
         // Try to use SALET as the hardcoded optimal first guess
-        self.all_words
-            .iter()
-            .find(|w| w.text() == "salet")
+        self.opener
+            .or_else(|| self.all_words.iter().find(|w| w.text() == "salet"))
             .or_else(|| {
                 // SALET not available (e.g., answers-only mode), use strategy
                 self.strategy
@@ -51,60 +279,303 @@ impl<'a, S: Strategy> Solver<'a, S> {
     /// # Parameters
     /// - `history`: Slice of (guess, pattern) pairs from previous turns
     ///
-    /// Returns the best next guess, or None if no candidates remain.
+    /// Returns the best next guess, or None if no candidates remain. Words
+    /// already in `history` are never suggested again: their feedback is
+    /// known, so repeating one gains no information. With one of
+    /// [`MAX_GUESSES`] left, the likeliest candidate is played whatever the
+    /// strategy, as in [`Self::endgame_risk`].
     pub fn next_guess(&self, history: &[(Word, Pattern)]) -> Option<&'a Word> {
         // If this is the first guess, use the hardcoded optimal
         if history.is_empty() {
             return self.first_guess();
         }
 
-        let candidates = self.filter_candidates(history);
+        self.next_guess_from(history, &self.filter_candidates(history))
+    }
 
-        if candidates.is_empty() {
-            return None;
+    /// [`Self::next_guess`] for a history whose `candidates` are already
+    /// filtered, so game loops that also need the count filter once per turn
+    pub(crate) fn next_guess_from(
+        &self,
+        history: &[(Word, Pattern)],
+        candidates: &[&'a Word],
+    ) -> Option<&'a Word> {
+        if history.is_empty() {
+            return self.first_guess();
         }
+        // On the last guess only a candidate can win, so the strategy's
+        // search (which may prefer a better split) is skipped
+        if history.len() + 1 == MAX_GUESSES
+            && let Some(risk) = assess_endgame(self.all_words, candidates, 1, |w| self.weight(w))
+        {
+            return candidates.iter().copied().find(|&w| *w == risk.guess);
+        }
+
+        let guess = self.suggest_from_candidates(candidates)?;
+        let guessed = |word: &Word| history.iter().any(|(guess, _)| guess == word);
+        let guess = if guessed(guess) {
+            // Only narrow the pool in the rare case the strategy picked a repeat
+            self.select_from(candidates, |w| !guessed(w))?
+        } else {
+            guess
+        };
+        Some(self.soften(guess, history, candidates))
+    }
 
-        // If only one candidate remains, just guess it
-        if candidates.len() == 1 {
-            return Some(candidates[0]);
+    /// Let the strategy choose among the guess pool words that pass `keep`
+    fn select_from(
+        &self,
+        candidates: &[&'a Word],
+        keep: impl Fn(&Word) -> bool,
+    ) -> Option<&'a Word> {
+        let pool: Vec<&Word> = self.all_words.iter().filter(|w| keep(w)).collect();
+        self.strategy.select_among(&pool, candidates)
+    }
+
+    /// In soft hard mode, trade a guess that plays a letter known to be
+    /// absent for the best guess that doesn't, if that one is no worse in the
+    /// worst case and within [`SOFT_HARD_PENALTY`] bits of its entropy
+    fn soften(
+        &self,
+        guess: &'a Word,
+        history: &[(Word, Pattern)],
+        candidates: &[&'a Word],
+    ) -> &'a Word {
+        if !self.soft_hard_mode || candidates.len() <= 1 {
+            return guess;
+        }
+        let absent = Constraints::new(history.to_vec()).absent_letters();
+        let plays_absent = |word: &Word| word.chars().iter().any(|c| absent.contains(c));
+        if !plays_absent(guess) {
+            return guess;
         }
 
-        // Convert candidates to owned Vec<Word> to avoid lifetime issues
-        let candidate_words: Vec<Word> = candidates.into_iter().cloned().collect();
+        let fresh = |word: &Word| !history.iter().any(|(played, _)| played == word);
+        let Some(clean) = self.select_from(candidates, |w| !plays_absent(w) && fresh(w)) else {
+            return guess;
+        };
+        let played = calculate_metrics(guess, candidates);
+        let alternative = calculate_metrics(clean, candidates);
+        if alternative.max_partition <= played.max_partition
+            && alternative.entropy >= played.entropy - SOFT_HARD_PENALTY
+        {
+            clean
+        } else {
+            guess
+        }
+    }
 
-        self.strategy.select_guess(self.all_words, &candidate_words)
+    /// Suggest a guess for an explicit candidate set, skipping history filtering
+    ///
+    /// The primitive underneath [`Self::next_guess`]: returns None for no
+    /// candidates, the candidate itself when only one remains, and otherwise
+    /// the commit rule's pick or the strategy's choice from the guess pool.
+    /// Unlike `next_guess` it knows nothing about earlier guesses, so it never
+    /// applies the opener or excludes repeats.
+    pub fn suggest_from_candidates(&self, candidates: &[&'a Word]) -> Option<&'a Word> {
+        match candidates {
+            [] => None,
+            [only] => Some(only),
+            _ => {
+                let committed = self
+                    .commit
+                    .and_then(|rule| rule.choose(candidates, |w| self.weight(w)));
+                if committed.is_some() {
+                    return committed;
+                }
+
+                let pool: Vec<&Word> = self.all_words.iter().collect();
+                self.strategy.select_among(&pool, candidates)
+            }
+        }
+    }
+
+    /// Rank the top `count` guesses for the current state by the strategy's criterion
+    ///
+    /// Evaluates every word in the guess pool against the remaining candidates
+    /// in parallel and orders them with `Strategy::compare_guesses`, best
+    /// first; ties go to the word whose text sorts first, as in selection.
+    /// Each guess comes with its full metrics (entropy, expected remaining,
+    /// worst case), enough for a ranked table.
+    pub fn rank_guesses(
+        &self,
+        history: &[(Word, Pattern)],
+        count: usize,
+    ) -> Vec<(&'a Word, GuessMetrics)> {
+        let candidates = self.filter_candidates(history);
+        if candidates.is_empty() || count == 0 {
+            return Vec::new();
+        }
+
+        let mut ranked: Vec<(&'a Word, GuessMetrics)> = self
+            .all_words
+            .par_iter()
+            .map(|guess| (guess, calculate_metrics(guess, &candidates)))
+            .collect();
+
+        let num_candidates = candidates.len();
+        let best_first = |(w1, m1): &(&Word, GuessMetrics), (w2, m2): &(&Word, GuessMetrics)| {
+            self.strategy
+                .compare_guesses(m2, m1, num_candidates)
+                .then_with(|| text_tiebreak(w2, w1))
+        };
+        // Only the top `count` need sorting
+        if count < ranked.len() {
+            ranked.select_nth_unstable_by(count - 1, best_first);
+            ranked.truncate(count);
+        }
+        ranked.sort_unstable_by(best_first);
+        ranked
     }
 
     /// Filter answer words to those consistent with the guess history
     ///
     /// Returns candidates that would produce the observed patterns for all guesses.
     fn filter_candidates(&self, history: &[(Word, Pattern)]) -> Vec<&'a Word> {
+        self.candidates_iter(history).collect()
+    }
+
+    /// Lazily iterate over the candidates consistent with the history
+    ///
+    /// Yields the same words in the same order as [`Self::get_candidates`],
+    /// but filters on demand, so counting, `take(n)` or `any(..)` never
+    /// materialize the whole set. The words borrow from the answer list, not
+    /// the solver, so the iterator may outlive the borrow of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use wordle_solver::core::{Pattern, Word};
+    /// use wordle_solver::solver::{EntropyStrategy, Solver};
+    ///
+    /// let words: Vec<Word> = ["crate", "grate", "slate"]
+    ///     .iter()
+    ///     .map(|w| Word::new(*w).unwrap())
+    ///     .collect();
+    /// let solver = Solver::new(EntropyStrategy, &words, &words);
+    ///
+    /// let guess = Word::new("grate").unwrap();
+    /// let history = [(guess.clone(), Pattern::calculate(&guess, &words[0]))];
+    /// let mut remaining = solver.candidates_iter(&history);
+    /// assert_eq!(remaining.next().map(Word::text), Some("crate"));
+    /// assert!(remaining.next().is_none());
+    /// ```
+    pub fn candidates_iter<'h>(
+        &self,
+        history: &'h [(Word, Pattern)],
+    ) -> impl Iterator<Item = &'a Word> + use<'a, 'h, S> {
+        self.answer_words.iter().filter(move |&candidate| {
+            history.iter().all(|(guess, observed_pattern)| {
+                let pattern = Pattern::calculate(guess, candidate);
+                pattern == *observed_pattern
+            })
+        })
+    }
+
+    /// Filter answer words to those satisfying every constraint set
+    ///
+    /// Generalizes history filtering to several independent sources of
+    /// feedback (e.g. multiple boards sharing one answer list).
+    pub fn candidates_satisfying(&self, constraint_sets: &[Constraints]) -> Vec<&'a Word> {
         self.answer_words
             .iter()
-            .filter(|&candidate| {
-                history.iter().all(|(guess, observed_pattern)| {
-                    let pattern = Pattern::calculate(guess, candidate);
-                    pattern == *observed_pattern
-                })
-            })
+            .filter(|&candidate| constraint_sets.iter().all(|c| c.allows(candidate)))
             .collect()
     }
 
+    /// Determine whether the game is won, lost, or still in progress
+    ///
+    /// A perfect pattern on the last guess wins even when it is the final
+    /// allowed guess; otherwise the game is lost once `max_guesses` guesses
+    /// have been made.
+    pub fn status(&self, history: &[(Word, Pattern)], max_guesses: usize) -> GameStatus {
+        GameStatus::new(history, max_guesses, || self.count_candidates(history))
+    }
+
+    /// Assess whether the next guess risks running out of guesses
+    ///
+    /// Returns the [`assess_endgame`] verdict for the candidates left after
+    /// `history` with `max_guesses` in the game, weighting candidates like
+    /// the commit rule. `None` when the position is not at risk.
+    #[must_use]
+    pub fn endgame_risk(
+        &self,
+        history: &[(Word, Pattern)],
+        max_guesses: usize,
+    ) -> Option<EndgameRisk> {
+        let guesses_left = max_guesses.checked_sub(history.len())?;
+        let candidates = self.filter_candidates(history);
+        assess_endgame(self.all_words, &candidates, guesses_left, |w| {
+            self.weight(w)
+        })
+    }
+
+    /// Probability that each of `candidates` is the answer, under the
+    /// solver's answer weights
+    ///
+    /// Uniform unless weights were set with [`Self::with_answer_weight`].
+    /// Candidates keep their order; pass all of them, as from
+    /// [`Self::get_candidates`], since probabilities are normalized over the
+    /// slice.
+    #[must_use]
+    pub fn candidate_probabilities(&self, candidates: &[&'a Word]) -> Vec<(&'a Word, f64)> {
+        candidate_probabilities(candidates, |w| self.weight(w))
+    }
+
+    /// Relative likelihood of `word` being the answer (1 without weights)
+    fn weight(&self, word: &Word) -> f64 {
+        self.answer_weight.map_or(1.0, |weight| weight(word))
+    }
+
     /// Count how many candidates remain given the history
     pub fn count_candidates(&self, history: &[(Word, Pattern)]) -> usize {
-        self.filter_candidates(history).len()
+        self.candidates_iter(history).count()
     }
 
     /// Get the current candidates (public accessor)
     pub fn get_candidates(&self, history: &[(Word, Pattern)]) -> Vec<&'a Word> {
         self.filter_candidates(history)
     }
+
+    /// Convenience wrapper over [`Self::get_candidates`] returning owned
+    /// lowercase strings, for callers such as FFI bindings that cannot hold
+    /// borrowed words
+    ///
+    /// The order matches `get_candidates`.
+    #[must_use]
+    pub fn candidate_strings(&self, history: &[(Word, Pattern)]) -> Vec<String> {
+        self.get_candidates(history)
+            .into_iter()
+            .map(|w| w.text().to_string())
+            .collect()
+    }
+
+    /// Convenience wrapper over [`Self::next_guess`] returning an owned
+    /// lowercase string
+    #[must_use]
+    pub fn next_guess_string(&self, history: &[(Word, Pattern)]) -> Option<String> {
+        self.next_guess(history).map(|w| w.text().to_string())
+    }
+}
+
+/// Intersect two candidate sets, keeping the order of `first`
+///
+/// Uses a hash set on word text, so cost is linear in the combined size.
+#[must_use]
+pub fn intersect_candidates<'a>(first: &[&'a Word], second: &[&Word]) -> Vec<&'a Word> {
+    let keep: FxHashSet<&str> = second.iter().map(|w| w.text()).collect();
+    first
+        .iter()
+        .copied()
+        .filter(|w| keep.contains(w.text()))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::solver::strategy::EntropyStrategy;
+    use crate::solver::strategy::{EntropyStrategy, MinimaxStrategy, compare_minimax};
+    use crate::wordlists::loader::words_from_slice;
+    use crate::wordlists::{ALLOWED, ANSWERS};
 
     fn setup_solver() -> (Vec<Word>, Vec<Word>) {
         let all_words = vec![
@@ -122,6 +593,50 @@ mod tests {
         (all_words, answer_words)
     }
 
+    #[test]
+    fn next_guess_skips_earlier_guesses() {
+        // No word in the pool splits the candidates, so every guess ties at
+        // zero entropy, including the one already played
+        let all_words = vec![Word::new("fuzzy").unwrap(), Word::new("jumpy").unwrap()];
+        let answer_words = vec![Word::new("crate").unwrap(), Word::new("grate").unwrap()];
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        for played in &all_words {
+            let history = vec![(played.clone(), Pattern::calculate(played, &answer_words[0]))];
+            let guess = solver.next_guess(&history).unwrap();
+            assert_ne!(guess, played);
+        }
+
+        let both: Vec<_> = all_words
+            .iter()
+            .map(|w| (w.clone(), Pattern::calculate(w, &answer_words[0])))
+            .collect();
+        assert_eq!(solver.next_guess(&both), None);
+    }
+
+    #[test]
+    fn last_guess_plays_a_candidate() {
+        use crate::solver::AdaptiveStrategy;
+
+        let all_words = words_from_slice(&[
+            "grate", "crate", "irate", "cigar", "fuzzy", "jumpy", "bobby", "puppy", "mommy",
+        ]);
+        let answer_words = all_words[..3].to_vec();
+        let solver = Solver::new(AdaptiveStrategy::default(), &all_words, &answer_words);
+        let history: Vec<_> = all_words[4..]
+            .iter()
+            .map(|w| (w.clone(), Pattern::calculate(w, &answer_words[0])))
+            .collect();
+
+        // With two guesses left the endgame search splits the candidates apart
+        assert_eq!(
+            solver.next_guess(&history[..4]).map(Word::text),
+            Some("cigar")
+        );
+        // With one left only a candidate can win
+        assert_eq!(solver.next_guess(&history).map(Word::text), Some("crate"));
+    }
+
     #[test]
     fn first_guess_returns_valid_word() {
         let (all_words, answer_words) = setup_solver();
@@ -134,6 +649,27 @@ mod tests {
         assert!(all_words.iter().any(|w| w == guess));
     }
 
+    #[test]
+    fn first_guess_prefers_opener() {
+        let (all_words, answer_words) = setup_solver();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words)
+            .with_opener(Some(&all_words[4]));
+
+        assert_eq!(solver.first_guess().map(Word::text), Some("grate"));
+        assert_eq!(solver.next_guess(&[]).map(Word::text), Some("grate"));
+    }
+
+    #[test]
+    fn prepared_caches_first_guess() {
+        let (all_words, answer_words) = setup_solver();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let prepared = Solver::prepared(EntropyStrategy, &all_words, &answer_words);
+
+        assert!(prepared.opener.is_some());
+        assert_eq!(prepared.opener, solver.first_guess());
+        assert_eq!(prepared.next_guess(&[]), solver.next_guess(&[]));
+    }
+
     #[test]
     fn next_guess_with_empty_history() {
         let (all_words, answer_words) = setup_solver();
@@ -175,6 +711,116 @@ mod tests {
         assert!(next.is_none());
     }
 
+    #[test]
+    fn suggest_from_candidates_matches_next_guess() {
+        let (all_words, answer_words) = setup_solver();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        assert_eq!(solver.suggest_from_candidates(&[]), None);
+        assert_eq!(
+            solver.suggest_from_candidates(&[&answer_words[1]]),
+            Some(&answer_words[1])
+        );
+
+        let guess = Word::new("crane").unwrap();
+        let pattern = Pattern::calculate(&guess, &answer_words[0]);
+        let history = vec![(guess, pattern)];
+        let candidates = solver.get_candidates(&history);
+        assert_eq!(
+            solver.suggest_from_candidates(&candidates),
+            solver.next_guess(&history)
+        );
+    }
+
+    #[test]
+    fn commit_rule_picks_likeliest_candidate() {
+        let words: Vec<Word> = ["crate", "grate", "irate"]
+            .iter()
+            .map(|t| Word::new(*t).unwrap())
+            .collect();
+        let candidates: Vec<&Word> = words.iter().collect();
+        let rule = CommitRule::default();
+
+        // Three equally likely: play on; two: commit to the first
+        assert_eq!(rule.choose(&candidates, |_| 1.0), None);
+        assert_eq!(rule.choose(&candidates[1..], |_| 1.0), Some(&words[1]));
+
+        // A heavily weighted candidate is committed to even among three
+        let favored = |w: &Word| if w.text() == "irate" { 8.0 } else { 1.0 };
+        assert_eq!(rule.choose(&candidates, favored), Some(&words[2]));
+        assert_eq!(rule.choose(&[], favored), None);
+    }
+
+    #[test]
+    fn commit_rule_overrides_strategy() {
+        let (all_words, answer_words) = setup_solver();
+        let favored = |w: &Word| if w.text() == "grate" { 10.0 } else { 1.0 };
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words)
+            .with_commit(Some(CommitRule::default()))
+            .with_answer_weight(Some(&favored));
+
+        let candidates: Vec<&Word> = answer_words.iter().collect();
+        assert_eq!(
+            solver.suggest_from_candidates(&candidates).map(Word::text),
+            Some("grate")
+        );
+
+        // Without weights, three candidates are left to the strategy
+        let uniform = Solver::new(EntropyStrategy, &all_words, &answer_words)
+            .with_commit(Some(CommitRule::default()));
+        let plain = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        assert_eq!(
+            uniform.suggest_from_candidates(&candidates),
+            plain.suggest_from_candidates(&candidates)
+        );
+    }
+
+    #[test]
+    fn candidate_probabilities_follow_answer_weights() {
+        let (all_words, answer_words) = setup_solver();
+        let favored = |w: &Word| if w.text() == "grate" { 2.0 } else { 1.0 };
+        let candidates: Vec<&Word> = answer_words.iter().collect();
+
+        let plain = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let uniform = 1.0 / candidates.len() as f64;
+        assert!(
+            plain
+                .candidate_probabilities(&candidates)
+                .iter()
+                .all(|&(_, p)| (p - uniform).abs() < 1e-12)
+        );
+
+        let weighted = plain
+            .with_strategy(EntropyStrategy)
+            .with_answer_weight(Some(&favored));
+        let total = candidates.len() as f64 + 1.0;
+        for (word, p) in weighted.candidate_probabilities(&candidates) {
+            let expected = if word.text() == "grate" { 2.0 } else { 1.0 } / total;
+            assert!((p - expected).abs() < 1e-12, "{word}");
+        }
+    }
+
+    #[test]
+    fn soft_hard_mode_prefers_fresh_letters() {
+        let words = |texts: &[&str]| -> Vec<Word> {
+            texts.iter().map(|t| Word::new(*t).unwrap()).collect()
+        };
+        let answer_words = words(&["crate", "grate"]);
+        // FUZZY is all grey: F, U, Z and Y are absent
+        let fuzzy = Word::new("fuzzy").unwrap();
+        let history = vec![(fuzzy.clone(), Pattern::calculate(&fuzzy, &answer_words[0]))];
+
+        // JUMPY and BLOKE both gain nothing; BLOKE wastes no tiles
+        let tied = words(&["jumpy", "bloke"]);
+        let solver = Solver::new(EntropyStrategy, &tied, &answer_words).with_soft_hard_mode(true);
+        assert_eq!(solver.next_guess(&history).map(Word::text), Some("bloke"));
+
+        // GUSTY splits the answers, worth far more than the penalty
+        let better = words(&["jumpy", "bloke", "gusty"]);
+        let solver = Solver::new(EntropyStrategy, &better, &answer_words).with_soft_hard_mode(true);
+        assert_eq!(solver.next_guess(&history).map(Word::text), Some("gusty"));
+    }
+
     #[test]
     fn count_candidates_decreases() {
         let (all_words, answer_words) = setup_solver();
@@ -195,6 +841,156 @@ mod tests {
         assert!(remaining <= answer_words.len());
     }
 
+    #[test]
+    fn candidates_iter_matches_get_candidates() {
+        let (all_words, answer_words) = setup_solver();
+        let guess = Word::new("crane").unwrap();
+        let pattern = Pattern::calculate(&guess, &Word::new("grate").unwrap());
+        let history = vec![(guess, pattern)];
+
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let expected = solver.get_candidates(&history);
+        assert_eq!(solver.candidates_iter(&history).count(), expected.len());
+
+        // The iterator borrows the word lists, not the solver
+        let lazy = {
+            let scoped = Solver::new(EntropyStrategy, &all_words, &answer_words);
+            scoped.candidates_iter(&history)
+        };
+        assert_eq!(lazy.collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn status_tracks_game_outcome() {
+        let (all_words, answer_words) = setup_solver();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let answer = Word::new("grate").unwrap();
+        let miss = Word::new("crane").unwrap();
+        let miss_entry = (miss.clone(), Pattern::calculate(&miss, &answer));
+        let win_entry = (answer.clone(), Pattern::PERFECT);
+
+        assert_eq!(
+            solver.status(&[], MAX_GUESSES),
+            GameStatus::InProgress {
+                candidates: answer_words.len()
+            }
+        );
+        assert_eq!(
+            solver.status(&[miss_entry.clone(), win_entry.clone()], MAX_GUESSES),
+            GameStatus::Won { guesses: 2 }
+        );
+
+        // Winning on the last allowed guess is still a win
+        assert_eq!(
+            solver.status(&[miss_entry.clone(), win_entry.clone()], 2),
+            GameStatus::Won { guesses: 2 }
+        );
+        assert_eq!(
+            solver.status(&[miss_entry.clone(), miss_entry.clone()], 2),
+            GameStatus::Lost
+        );
+        assert!(GameStatus::Lost.is_finished());
+        assert!(!GameStatus::InProgress { candidates: 0 }.is_finished());
+
+        // A finished game never asks for the candidate count
+        let won = GameStatus::new(&[win_entry], MAX_GUESSES, || unreachable!());
+        assert_eq!(won, GameStatus::Won { guesses: 1 });
+        assert_eq!(
+            GameStatus::new(&[miss_entry], MAX_GUESSES, || 3),
+            GameStatus::InProgress { candidates: 3 }
+        );
+    }
+
+    #[test]
+    fn rank_guesses_sorted_best_first() {
+        let (all_words, answer_words) = setup_solver();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+
+        let ranked = solver.rank_guesses(&[], 3);
+        assert_eq!(ranked.len(), 3);
+        for pair in ranked.windows(2) {
+            assert!(pair[0].1.entropy >= pair[1].1.entropy);
+        }
+
+        // Requesting more than the pool returns the whole pool
+        assert_eq!(solver.rank_guesses(&[], 100).len(), all_words.len());
+        assert!(solver.rank_guesses(&[], 0).is_empty());
+    }
+
+    #[test]
+    fn rank_guesses_follows_the_strategy() {
+        let all_words = words_from_slice(&ALLOWED[..3000]);
+        let answer_words = words_from_slice(&ANSWERS[..400]);
+        let entropy = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let minimax = Solver::new(MinimaxStrategy, &all_words, &answer_words);
+
+        let by_entropy = entropy.rank_guesses(&[], 10);
+        let by_minimax = minimax.rank_guesses(&[], 10);
+        for pair in by_minimax.windows(2) {
+            assert!(compare_minimax(&pair[0].1, &pair[1].1).is_ge());
+        }
+        // Each ranking leads with the strategy's own pick
+        assert_eq!(
+            EntropyStrategy.select_guess(&all_words, &answer_words),
+            Some(by_entropy[0].0)
+        );
+        assert_eq!(
+            MinimaxStrategy.select_guess(&all_words, &answer_words),
+            Some(by_minimax[0].0)
+        );
+        assert_ne!(by_entropy[0].0, by_minimax[0].0);
+
+        // The top N is the head of the full ranking, and ties read in text order
+        let full = minimax.rank_guesses(&[], all_words.len());
+        let head: Vec<&Word> = full[..10].iter().map(|&(w, _)| w).collect();
+        let top: Vec<&Word> = by_minimax.iter().map(|&(w, _)| w).collect();
+        assert_eq!(head, top);
+        for pair in full.windows(2) {
+            if compare_minimax(&pair[0].1, &pair[1].1).is_eq() {
+                assert!(pair[0].0.text() < pair[1].0.text());
+            }
+        }
+    }
+
+    #[test]
+    fn candidates_satisfying_matches_combined_history() {
+        let (all_words, answer_words) = setup_solver();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let answer = Word::new("grate").unwrap();
+
+        let guess1 = Word::new("crane").unwrap();
+        let guess2 = Word::new("irate").unwrap();
+        let entry1 = (guess1.clone(), Pattern::calculate(&guess1, &answer));
+        let entry2 = (guess2.clone(), Pattern::calculate(&guess2, &answer));
+
+        let separate = [
+            Constraints::new(vec![entry1.clone()]),
+            Constraints::new(vec![entry2.clone()]),
+        ];
+        let combined = solver.filter_candidates(&[entry1, entry2]);
+
+        assert_eq!(solver.candidates_satisfying(&separate), combined);
+        assert_eq!(solver.candidates_satisfying(&[]).len(), answer_words.len());
+    }
+
+    #[test]
+    fn intersect_candidates_keeps_common_words() {
+        let words = [
+            Word::new("crane").unwrap(),
+            Word::new("slate").unwrap(),
+            Word::new("irate").unwrap(),
+        ];
+        let other = [Word::new("irate").unwrap(), Word::new("crane").unwrap()];
+
+        let first: Vec<&Word> = words.iter().collect();
+        let second: Vec<&Word> = other.iter().collect();
+
+        let common = intersect_candidates(&first, &second);
+        let texts: Vec<&str> = common.iter().map(|w| w.text()).collect();
+        assert_eq!(texts, ["crane", "irate"]);
+        assert!(intersect_candidates(&first, &[]).is_empty());
+    }
+
     #[test]
     fn filter_candidates_exact_match() {
         let (all_words, answer_words) = setup_solver();
@@ -235,4 +1031,26 @@ mod tests {
         // GRATE should be in the candidates
         assert!(candidates.iter().any(|&w| w.text() == "grate"));
     }
+
+    #[test]
+    fn string_wrappers_match_borrowed_results() {
+        let (all_words, answer_words) = setup_solver();
+        let solver = Solver::new(EntropyStrategy, &all_words, &answer_words);
+        let guess = Word::new("crane").unwrap();
+        let history = vec![(
+            guess.clone(),
+            Pattern::calculate(&guess, &Word::new("irate").unwrap()),
+        )];
+
+        let borrowed: Vec<&str> = solver
+            .get_candidates(&history)
+            .into_iter()
+            .map(Word::text)
+            .collect();
+        assert_eq!(solver.candidate_strings(&history), borrowed);
+        assert_eq!(
+            solver.next_guess_string(&history).as_deref(),
+            solver.next_guess(&history).map(Word::text)
+        );
+    }
 }
@@ -1,7 +1,7 @@
 //! Entropy calculation for Wordle patterns
 
-use crate::core::{Pattern, Word};
-use rustc_hash::FxHashMap;
+use super::math::log2;
+use crate::core::{FxHashMap, HashMap, Pattern, Word};
 
 /// Metrics for evaluating a guess
 #[derive(Debug, Clone, Copy)]
@@ -12,6 +12,8 @@ pub struct GuessMetrics {
     pub expected_remaining: f64,
     /// Maximum partition size (worst-case remaining candidates)
     pub max_partition: usize,
+    /// Number of distinct patterns the guess can produce
+    pub distinct_patterns: usize,
 }
 
 /// Calculate Shannon entropy for a guess against candidates
@@ -52,15 +54,12 @@ pub fn calculate_entropy(guess: &Word, candidates: &[&Word]) -> f64 {
 }
 
 /// Group candidates by the pattern they produce with the guess
-fn group_by_pattern(guess: &Word, candidates: &[&Word]) -> FxHashMap<Pattern, usize> {
-    let mut counts = FxHashMap::default();
-
-    for &candidate in candidates {
-        let pattern = Pattern::calculate(guess, candidate);
-        *counts.entry(pattern).or_insert(0) += 1;
-    }
-
-    counts
+///
+/// Returns the number of candidates behind each pattern, i.e. the partition
+/// sizes the guess splits the candidates into. Same as [`Pattern::partition`].
+#[must_use]
+pub fn group_by_pattern(guess: &Word, candidates: &[&Word]) -> FxHashMap<Pattern, usize> {
+    Pattern::partition(guess, candidates)
 }
 
 /// Calculate Shannon entropy from pattern distribution
@@ -83,9 +82,9 @@ fn group_by_pattern(guess: &Word, candidates: &[&Word]) -> FxHashMap<Pattern, us
 /// assert!((entropy - 2.0).abs() < 0.001); // log2(4) = 2 bits
 /// ```
 #[must_use]
-pub fn shannon_entropy<S>(pattern_counts: &std::collections::HashMap<Pattern, usize, S>) -> f64
+pub fn shannon_entropy<S>(pattern_counts: &HashMap<Pattern, usize, S>) -> f64
 where
-    S: std::hash::BuildHasher,
+    S: core::hash::BuildHasher,
 {
     let total = pattern_counts.values().sum::<usize>() as f64;
 
@@ -98,64 +97,89 @@ where
         .filter(|&&count| count > 0)
         .map(|&count| {
             let p = count as f64 / total;
-            -p * p.log2()
+            -p * log2(p)
         })
         .sum()
 }
 
-/// Calculate comprehensive metrics for a guess
+/// Calculate the information actually gained from an observed pattern
 ///
-/// Returns entropy, expected remaining candidates, and max partition size.
-/// This enables sophisticated tiebreaking strategies.
-pub fn calculate_metrics(guess: &Word, candidates: &[&Word]) -> GuessMetrics {
-    if candidates.is_empty() {
-        return GuessMetrics {
-            entropy: 0.0,
-            expected_remaining: 0.0,
-            max_partition: 0,
-        };
-    }
-
-    let mut pattern_groups: FxHashMap<Pattern, Vec<&Word>> = FxHashMap::default();
-
-    // Group candidates by pattern
-    for &candidate in candidates {
-        let pattern = Pattern::calculate(guess, candidate);
-        pattern_groups.entry(pattern).or_default().push(candidate);
+/// Unlike entropy (the *expected* gain before guessing), this is the realized
+/// gain: log₂(before / after) bits. Returns 0.0 if either count is zero.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::solver::entropy::observed_information;
+///
+/// // 100 candidates narrowed to 25 = 2 bits
+/// assert!((observed_information(100, 25) - 2.0).abs() < 0.001);
+/// assert_eq!(observed_information(10, 0), 0.0);
+/// ```
+#[must_use]
+pub fn observed_information(before: usize, after: usize) -> f64 {
+    if before == 0 || after == 0 {
+        return 0.0;
     }
 
-    let total = candidates.len() as f64;
-
-    // Calculate entropy
-    let entropy: f64 = pattern_groups
-        .values()
-        .map(|group| {
-            let p = group.len() as f64 / total;
-            -p * p.log2()
-        })
-        .sum();
+    log2(before as f64 / after as f64)
+}
 
-    // Calculate expected remaining candidates
-    let expected_remaining: f64 = pattern_groups
-        .values()
-        .map(|group| {
-            let p = group.len() as f64 / total;
-            p * group.len() as f64
-        })
-        .sum();
+/// Calculate comprehensive metrics for a guess
+///
+/// Returns entropy, expected remaining candidates, max partition size, and
+/// distinct pattern count. This enables sophisticated tiebreaking strategies.
+///
+/// The candidates are partitioned once and every metric is read from that
+/// partition, so callers needing several metrics should call this rather
+/// than `calculate_entropy` alongside it. The entropy is identical to
+/// `calculate_entropy`'s.
+#[must_use]
+pub fn calculate_metrics(guess: &Word, candidates: &[&Word]) -> GuessMetrics {
+    metrics_from_partition(&group_by_pattern(guess, candidates))
+}
 
-    // Find max partition size (minimax worst-case)
-    let max_partition = pattern_groups
-        .values()
-        .map(std::vec::Vec::len)
-        .max()
-        .unwrap_or(0);
-
-    GuessMetrics {
-        entropy,
-        expected_remaining,
-        max_partition,
+/// Calculate guess metrics from the partition sizes a guess produces
+///
+/// Takes the output of `group_by_pattern` and computes every metric in one
+/// pass over it.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::core::Word;
+/// use wordle_solver::solver::entropy::{group_by_pattern, metrics_from_partition};
+///
+/// let guess = Word::new("crane").unwrap();
+/// let candidates: Vec<Word> = ["grate", "irate", "slate"]
+///     .iter()
+///     .map(|w| Word::new(*w).unwrap())
+///     .collect();
+/// let refs: Vec<&Word> = candidates.iter().collect();
+///
+/// let metrics = metrics_from_partition(&group_by_pattern(&guess, &refs));
+/// assert_eq!(metrics.distinct_patterns, 2); // GRATE and IRATE look alike
+/// assert_eq!(metrics.max_partition, 2);
+/// ```
+#[must_use]
+pub fn metrics_from_partition<S>(pattern_counts: &HashMap<Pattern, usize, S>) -> GuessMetrics
+where
+    S: core::hash::BuildHasher,
+{
+    let total = pattern_counts.values().sum::<usize>() as f64;
+    let mut metrics = GuessMetrics {
+        entropy: 0.0,
+        expected_remaining: 0.0,
+        max_partition: 0,
+        distinct_patterns: pattern_counts.len(),
+    };
+
+    for &count in pattern_counts.values() {
+        let p = count as f64 / total;
+        metrics.entropy += -p * log2(p);
+        metrics.expected_remaining += p * count as f64;
+        metrics.max_partition = metrics.max_partition.max(count);
     }
+
+    metrics
 }
 
 #[cfg(test)]
@@ -226,7 +250,7 @@ mod tests {
     #[test]
     fn calculate_entropy_real_words() {
         let guess = Word::new("crane").unwrap();
-        let candidates = vec![
+        let candidates = [
             Word::new("slate").unwrap(),
             Word::new("irate").unwrap(),
             Word::new("trace").unwrap(),
@@ -282,6 +306,14 @@ mod tests {
         assert!((entropy - 0.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn observed_information_values() {
+        assert!((observed_information(8, 1) - 3.0).abs() < 0.001);
+        assert!(observed_information(5, 5).abs() < 0.001);
+        assert!(observed_information(0, 0).abs() < f64::EPSILON);
+        assert!(observed_information(10, 0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn group_by_pattern_works() {
         let guess = Word::new("crane").unwrap();
@@ -297,4 +329,30 @@ mod tests {
         assert_eq!(groups.len(), 2);
         assert_eq!(groups.values().sum::<usize>(), 2);
     }
+
+    #[test]
+    fn metrics_match_separate_calculations() {
+        use crate::wordlists::ANSWERS;
+        use crate::wordlists::loader::words_from_slice;
+
+        let candidates = words_from_slice(&ANSWERS[..300]);
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+
+        for guess in &candidates[..20] {
+            let metrics = calculate_metrics(guess, &candidate_refs);
+            let groups = group_by_pattern(guess, &candidate_refs);
+
+            // Bit-identical, so selections can't change
+            assert_eq!(
+                metrics.entropy.to_bits(),
+                calculate_entropy(guess, &candidate_refs).to_bits()
+            );
+            assert_eq!(metrics.distinct_patterns, groups.len());
+            assert_eq!(metrics.max_partition, *groups.values().max().unwrap());
+        }
+
+        let empty = calculate_metrics(&candidates[0], &[]);
+        assert_eq!((empty.max_partition, empty.distinct_patterns), (0, 0));
+        assert!(empty.entropy.abs() < f64::EPSILON);
+    }
 }
@@ -0,0 +1,97 @@
+//! Floating-point helpers that work without `std`
+//!
+//! `f64::log2` lives in `std`, so `no_std` builds use a software fallback.
+
+/// Mask for the 52 explicit mantissa bits of an `f64`
+#[cfg(any(not(feature = "std"), test))]
+const MANTISSA_MASK: u64 = (1 << 52) - 1;
+
+#[cfg(feature = "std")]
+pub fn log2(x: f64) -> f64 {
+    x.log2()
+}
+
+#[cfg(not(feature = "std"))]
+pub use soft_log2 as log2;
+
+/// Base-2 logarithm without `std`
+///
+/// Splits `x` into exponent and mantissa from its bit pattern, then evaluates
+/// ln(m) = 2·atanh((m - 1) / (m + 1)) as a series. With the mantissa folded
+/// into [√½, √2] the series converges to full `f64` precision in 12 terms.
+#[cfg(any(not(feature = "std"), test))]
+pub fn soft_log2(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let mut mantissa_bits = bits & MANTISSA_MASK;
+    if exponent == -1023 {
+        // Subnormal: shift the leading one up into the implicit bit
+        let shift = mantissa_bits.leading_zeros() - 11;
+        mantissa_bits = (mantissa_bits << shift) & MANTISSA_MASK;
+        exponent = -1022 - shift.cast_signed();
+    }
+
+    let mut mantissa = f64::from_bits(mantissa_bits | (1023 << 52));
+    if mantissa > core::f64::consts::SQRT_2 {
+        mantissa /= 2.0;
+        exponent += 1;
+    }
+
+    let t = (mantissa - 1.0) / (mantissa + 1.0);
+    let t2 = t * t;
+    let mut power = t;
+    let mut series = 0.0;
+    for k in 0..12 {
+        series += power / f64::from(2 * k + 1);
+        power *= t2;
+    }
+
+    f64::from(exponent) + 2.0 * series * core::f64::consts::LOG2_E
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soft_log2_matches_std() {
+        for x in [
+            1e-300_f64, 1e-10, 0.001, 0.3, 0.5, 0.7, 1.0, 1.5, 2.0, 3.0, 1e10, 1e300,
+        ] {
+            let expected = x.log2();
+            assert!(
+                (soft_log2(x) - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+                "log2({x}): {} != {expected}",
+                soft_log2(x)
+            );
+        }
+    }
+
+    #[test]
+    fn soft_log2_exact_powers_of_two() {
+        for e in -1022..=1023 {
+            let x = 2f64.powi(e);
+            assert!((soft_log2(x) - f64::from(e)).abs() < 1e-9, "2^{e}");
+        }
+        // Smallest subnormal
+        assert!((soft_log2(f64::from_bits(1)) + 1074.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn soft_log2_edge_cases() {
+        assert!(soft_log2(0.0).is_infinite() && soft_log2(0.0) < 0.0);
+        assert!(soft_log2(f64::INFINITY).is_infinite() && soft_log2(f64::INFINITY) > 0.0);
+        assert!(soft_log2(-1.0).is_nan());
+        assert!(soft_log2(f64::NAN).is_nan());
+    }
+}
@@ -3,7 +3,13 @@
 //! Calculates entropy for Wordle pattern distributions.
 
 mod calculator;
+mod math;
+#[cfg(feature = "std")]
 mod selector;
 
-pub use calculator::{GuessMetrics, calculate_entropy, calculate_metrics, shannon_entropy};
+pub use calculator::{
+    GuessMetrics, calculate_entropy, calculate_metrics, group_by_pattern, metrics_from_partition,
+    observed_information, shannon_entropy,
+};
+#[cfg(feature = "std")]
 pub use selector::select_best_guess;
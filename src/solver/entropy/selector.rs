@@ -4,12 +4,14 @@
 
 use super::calculator::calculate_entropy;
 use crate::core::Word;
+use crate::solver::text_tiebreak;
 use rayon::prelude::*;
 
 /// Select best guess by maximizing entropy
 ///
 /// Returns the word with highest entropy and its entropy value,
-/// or `None` if the guess pool is empty.
+/// or `None` if the guess pool is empty. Ties go to the word whose text
+/// sorts first, whatever the pool order or thread count.
 ///
 /// # Examples
 /// ```
@@ -45,12 +47,15 @@ pub fn select_best_guess<'a>(
             let entropy = calculate_entropy(guess, candidates);
             (guess, entropy)
         })
-        .max_by(|(_, e1), (_, e2)| e1.total_cmp(e2))
+        // A total order, so rayon's reduction order can't change the winner
+        .max_by(|(w1, e1), (w2, e2)| e1.total_cmp(e2).then_with(|| text_tiebreak(w1, w2)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::wordlists::ALLOWED;
+    use crate::wordlists::loader::words_from_slice;
 
     #[test]
     fn selects_highest_entropy() {
@@ -58,7 +63,7 @@ mod tests {
             Word::new("aaaaa").unwrap(), // Low entropy (all same letter)
             Word::new("aeros").unwrap(), // Higher entropy (diverse letters)
         ];
-        let candidates = vec![
+        let candidates = [
             Word::new("slate").unwrap(),
             Word::new("irate").unwrap(),
             Word::new("crate").unwrap(),
@@ -94,26 +99,26 @@ mod tests {
     }
 
     #[test]
-    fn ties_resolved_consistently() {
-        // If multiple guesses have same entropy, should pick one consistently
-        let guesses = [Word::new("aaaaa").unwrap(), Word::new("bbbbb").unwrap()];
-        let candidates = [Word::new("ccccc").unwrap()];
+    fn ties_go_to_the_first_word_for_any_thread_count() {
+        // Against one candidate every guess has zero entropy
+        let guesses = words_from_slice(&ALLOWED[..2000]);
+        let candidates = [Word::new("zzzzz").unwrap()];
 
-        let guess_refs: Vec<&Word> = guesses.iter().collect();
+        // Reversed, so the winner can't just be the first in the pool
+        let guess_refs: Vec<&Word> = guesses.iter().rev().collect();
         let candidate_refs: Vec<&Word> = candidates.iter().collect();
 
-        let result1 = select_best_guess(&guess_refs, &candidate_refs);
-        let result2 = select_best_guess(&guess_refs, &candidate_refs);
-
-        assert!(result1.is_some());
-        assert!(result2.is_some());
-
-        let (best1, entropy1) = result1.unwrap();
-        let (best2, entropy2) = result2.unwrap();
-
-        // Should be deterministic
-        assert_eq!(best1.text(), best2.text());
-        assert!((entropy1 - entropy2).abs() < 0.001);
+        for threads in [1, 2, 3, 8] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let (best, entropy) = pool
+                .install(|| select_best_guess(&guess_refs, &candidate_refs))
+                .unwrap();
+            assert_eq!(best.text(), "aahed", "{threads} threads");
+            assert!(entropy.abs() < f64::EPSILON);
+        }
     }
 
     #[test]
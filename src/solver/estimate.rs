@@ -0,0 +1,120 @@
+//! Expected guesses to finish and candidate probabilities
+//!
+//! Cheap estimates of how many more guesses a game needs and how likely
+//! each remaining candidate is, for display rather than guess selection.
+
+use crate::core::Word;
+
+/// Average guesses the adaptive solver needs from the full answer list
+const FULL_LIST_AVERAGE: f64 = 3.42;
+
+/// Size of the full answer list the curve is fitted to
+const FULL_LIST_SIZE: f64 = 2315.0;
+
+/// Estimate the guesses still needed (including the next one) to finish
+///
+/// Uses `2 - 1/n + a·log₂(n/2)` for two or more candidates: exact for two
+/// (1.5 guesses, guessing one of them), with `a` fitted so the full answer
+/// list gives the solver's 3.42 average. One candidate needs exactly one
+/// guess and none needs zero, so the estimate is strictly increasing in
+/// `num_candidates`.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::solver::expected_guesses;
+///
+/// assert_eq!(expected_guesses(1), 1.0);
+/// assert_eq!(expected_guesses(2), 1.5);
+/// assert!(expected_guesses(100) < expected_guesses(101));
+/// ```
+#[must_use]
+pub fn expected_guesses(num_candidates: usize) -> f64 {
+    if num_candidates <= 1 {
+        return num_candidates as f64;
+    }
+
+    let n = num_candidates as f64;
+    let slope = (FULL_LIST_AVERAGE - 2.0 + 1.0 / FULL_LIST_SIZE) / (FULL_LIST_SIZE / 2.0).log2();
+    2.0 - 1.0 / n + slope * (n / 2.0).log2()
+}
+
+/// Probability that each remaining candidate is the answer
+///
+/// Each candidate's `weight` is normalized over the remaining set, so the
+/// probabilities sum to 1 and grow as the set narrows. Candidates keep their
+/// order. Weights that are all zero (or not finite) fall back to uniform
+/// 1/n; pass `|_| 1.0` when answers are equally likely.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::core::Word;
+/// use wordle_solver::solver::candidate_probabilities;
+///
+/// let words = [Word::new("grate").unwrap(), Word::new("crate").unwrap()];
+/// let candidates: Vec<&Word> = words.iter().collect();
+///
+/// let probabilities = candidate_probabilities(&candidates, |_| 1.0);
+/// assert_eq!(probabilities[0].1, 0.5);
+/// ```
+#[must_use]
+pub fn candidate_probabilities<'a>(
+    candidates: &[&'a Word],
+    weight: impl Fn(&Word) -> f64,
+) -> Vec<(&'a Word, f64)> {
+    let weights: Vec<f64> = candidates.iter().map(|&w| weight(w).max(0.0)).collect();
+    let total: f64 = weights.iter().sum();
+    if total > 0.0 && total.is_finite() {
+        candidates
+            .iter()
+            .zip(weights)
+            .map(|(&w, weight)| (w, weight / total))
+            .collect()
+    } else {
+        let uniform = 1.0 / candidates.len() as f64;
+        candidates.iter().map(|&w| (w, uniform)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_guesses_anchors() {
+        assert!(expected_guesses(0).abs() < f64::EPSILON);
+        assert!((expected_guesses(1) - 1.0).abs() < 1e-9);
+        assert!((expected_guesses(2) - 1.5).abs() < 1e-9);
+        assert!((expected_guesses(2315) - FULL_LIST_AVERAGE).abs() < 1e-9);
+    }
+
+    #[test]
+    fn expected_guesses_monotonic() {
+        for n in 1..3000 {
+            assert!(expected_guesses(n) < expected_guesses(n + 1), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn candidate_probabilities_sum_to_one() {
+        let words: Vec<Word> = ["grate", "crate", "irate", "plate"]
+            .iter()
+            .map(|t| Word::new(*t).unwrap())
+            .collect();
+        let candidates: Vec<&Word> = words.iter().collect();
+
+        let weighted =
+            candidate_probabilities(&candidates, |w| if w.text() == "grate" { 5.0 } else { 1.0 });
+        assert_eq!(weighted[0].0.text(), "grate");
+        assert!((weighted[0].1 - 5.0 / 8.0).abs() < 1e-9);
+        let total: f64 = weighted.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // Narrowing the set renormalizes over what is left
+        let narrowed = candidate_probabilities(&candidates[1..], |_| 1.0);
+        assert!(narrowed.iter().all(|(_, p)| (p - 1.0 / 3.0).abs() < 1e-9));
+
+        let zero = candidate_probabilities(&candidates, |_| 0.0);
+        assert!(zero.iter().all(|(_, p)| (p - 0.25).abs() < 1e-9));
+        assert!(candidate_probabilities(&[], |_| 1.0).is_empty());
+    }
+}
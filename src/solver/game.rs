@@ -0,0 +1,206 @@
+//! Full game simulation
+//!
+//! Plays the solver against a known answer without any output, so commands
+//! and library users share one canonical game loop.
+
+use super::engine::{GameStatus, MAX_GUESSES, Solver};
+use super::strategy::Strategy;
+use crate::core::{Pattern, Word};
+use std::time::{Duration, Instant};
+
+/// Record of one simulated game
+#[derive(Debug, Clone)]
+pub struct GameRecord {
+    /// Each guess with the pattern it produced, in order
+    pub history: Vec<(Word, Pattern)>,
+    /// Whether the answer was guessed within the limit
+    pub success: bool,
+    /// Time spent selecting each guess (parallel to `history`)
+    pub selection_times: Vec<Duration>,
+}
+
+impl GameRecord {
+    /// Number of guesses made
+    #[must_use]
+    pub fn num_guesses(&self) -> usize {
+        self.history.len()
+    }
+
+    /// The guesses made, in order
+    pub fn guesses(&self) -> impl Iterator<Item = &Word> {
+        self.history.iter().map(|(guess, _)| guess)
+    }
+}
+
+/// Play a full game against a known answer
+///
+/// If `forced_first` is provided, it is used as the first guess instead of
+/// letting the solver choose. The game ends when the answer is guessed,
+/// `max_guesses` is reached, or the solver has no candidates left (only
+/// possible if `answer` is not in the solver's answer list).
+///
+/// # Examples
+/// ```
+/// use wordle_solver::core::Word;
+/// use wordle_solver::solver::{EntropyStrategy, Solver, simulate_game};
+///
+/// let words = vec![Word::new("crane").unwrap(), Word::new("slate").unwrap()];
+/// let solver = Solver::new(EntropyStrategy, &words, &words);
+///
+/// let record = simulate_game(&solver, &words[1], 6, None);
+/// assert!(record.success);
+/// assert_eq!(record.guesses().last(), Some(&words[1]));
+/// ```
+pub fn simulate_game<S: Strategy>(
+    solver: &Solver<'_, S>,
+    answer: &Word,
+    max_guesses: usize,
+    forced_first: Option<&Word>,
+) -> GameRecord {
+    let mut history: Vec<(Word, Pattern)> = Vec::new();
+    let mut selection_times = Vec::new();
+
+    let success = loop {
+        // Filtered once per turn, for both the status and the next guess
+        let mut candidates = Vec::new();
+        let status = GameStatus::new(&history, max_guesses, || {
+            candidates = solver.get_candidates(&history);
+            candidates.len()
+        });
+        match status {
+            GameStatus::Won { .. } => break true,
+            GameStatus::Lost => break false,
+            GameStatus::InProgress { .. } => {}
+        }
+
+        let select_start = Instant::now();
+        let guess = match (history.is_empty(), forced_first) {
+            (true, Some(forced)) => forced,
+            _ => match solver.next_guess_from(&history, &candidates) {
+                Some(guess) => guess,
+                None => break false,
+            },
+        };
+        selection_times.push(select_start.elapsed());
+
+        let pattern = Pattern::calculate(guess, answer);
+        history.push((guess.clone(), pattern));
+    };
+
+    GameRecord {
+        history,
+        success,
+        selection_times,
+    }
+}
+
+/// Replay the solver's game against `answer` and return its guess sequence
+///
+/// Plays up to `MAX_GUESSES` with the solver's own first guess. The sequence
+/// is deterministic as long as the strategy is (seed `AdaptiveStrategy` with
+/// `with_random_seed` for a stable endgame), which makes it suitable for
+/// regression fixtures.
+pub fn replay<S: Strategy>(solver: &Solver<'_, S>, answer: &Word) -> Vec<Word> {
+    simulate_game(solver, answer, MAX_GUESSES, None)
+        .guesses()
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::EntropyStrategy;
+
+    fn setup_words() -> Vec<Word> {
+        vec![
+            Word::new("crane").unwrap(),
+            Word::new("irate").unwrap(),
+            Word::new("crate").unwrap(),
+            Word::new("grate").unwrap(),
+        ]
+    }
+
+    #[test]
+    fn simulate_game_solves_answer() {
+        let words = setup_words();
+        let solver = Solver::new(EntropyStrategy, &words, &words);
+        let answer = Word::new("grate").unwrap();
+
+        let record = simulate_game(&solver, &answer, 6, None);
+
+        assert!(record.success);
+        assert_eq!(record.history.last().unwrap().1, Pattern::PERFECT);
+        assert_eq!(record.selection_times.len(), record.num_guesses());
+    }
+
+    #[test]
+    fn simulate_game_uses_forced_first() {
+        let words = setup_words();
+        let solver = Solver::new(EntropyStrategy, &words, &words);
+        let answer = Word::new("grate").unwrap();
+        let forced = Word::new("crane").unwrap();
+
+        let record = simulate_game(&solver, &answer, 6, Some(&forced));
+
+        assert_eq!(record.guesses().next(), Some(&forced));
+        assert!(record.success);
+    }
+
+    #[test]
+    fn simulate_game_respects_limit() {
+        let words = setup_words();
+        let solver = Solver::new(EntropyStrategy, &words, &words);
+        let answer = Word::new("grate").unwrap();
+        let forced = Word::new("crane").unwrap();
+
+        let record = simulate_game(&solver, &answer, 1, Some(&forced));
+        assert!(!record.success);
+        assert_eq!(record.num_guesses(), 1);
+
+        assert_eq!(simulate_game(&solver, &answer, 0, None).num_guesses(), 0);
+    }
+
+    #[test]
+    fn simulate_game_stops_without_candidates() {
+        let words = setup_words();
+        let solver = Solver::new(EntropyStrategy, &words, &words);
+        let answer = Word::new("zzzzz").unwrap();
+
+        let record = simulate_game(&solver, &answer, 6, None);
+
+        assert!(!record.success);
+        assert!(record.num_guesses() < 6);
+    }
+
+    /// Seeded adaptive replays on the full word lists; AGILE/ANGLE pass
+    /// through the two-candidate `Random` tier
+    const REPLAY_FIXTURES: [(&str, &[&str]); 8] = [
+        ("turbo", &["salet", "north", "turbo"]),
+        ("crane", &["salet", "beard", "cezve", "crane"]),
+        ("epoxy", &["salet", "drone", "epoxy"]),
+        ("ninja", &["salet", "brond", "again", "ninja"]),
+        ("abuse", &["salet", "brash", "abuse"]),
+        ("amuse", &["salet", "brash", "amuse"]),
+        ("agile", &["salet", "glare", "agile"]),
+        ("angle", &["salet", "glare", "agile", "angle"]),
+    ];
+
+    #[test]
+    fn replay_matches_fixtures() {
+        use crate::solver::AdaptiveStrategy;
+        use crate::wordlists::loader::words_from_slice;
+        use crate::wordlists::{ALLOWED, ANSWERS};
+
+        let all_words = words_from_slice(ALLOWED);
+        let answer_words = words_from_slice(ANSWERS);
+        let strategy = AdaptiveStrategy::default().with_random_seed(0);
+        let solver = Solver::new(strategy, &all_words, &answer_words);
+
+        for (answer, expected) in REPLAY_FIXTURES {
+            let guesses = replay(&solver, &Word::new(answer).unwrap());
+            let texts: Vec<&str> = guesses.iter().map(Word::text).collect();
+            assert_eq!(texts, expected, "replay for {answer}");
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Letter-frequency heuristics
+//!
+//! Cheap proxies for entropy that look at letters instead of feedback
+//! patterns. Scoring a guess is one pass over the candidates, so these suit
+//! pre-filtering large guess pools and explaining which letters are good.
+
+use crate::core::Word;
+
+/// Weight of a letter the guess already used at an earlier position
+///
+/// A repeated letter reveals little that the first copy did not, so it only
+/// earns part of its positional frequency.
+pub const REPEAT_WEIGHT: f64 = 0.5;
+
+/// Score a guess by how often its letters appear in the same position among
+/// the candidates
+///
+/// Sums, over the five positions, the fraction of candidates with the guess's
+/// letter at that position. Each repeat of a letter counts at
+/// [`REPEAT_WEIGHT`], so words testing five different letters rank above
+/// repetitive ones. Ranges from 0.0 (no letter ever in place) to 5.0, and is
+/// 0.0 for no candidates.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::core::Word;
+/// use wordle_solver::solver::heuristic::positional_score;
+///
+/// let candidates = vec![Word::new("crate").unwrap(), Word::new("slate").unwrap()];
+/// let candidate_refs: Vec<&Word> = candidates.iter().collect();
+///
+/// // A, T and E are in place for both candidates, L for one
+/// let score = positional_score(&Word::new("plate").unwrap(), &candidate_refs);
+/// assert!((score - 3.5).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn positional_score(word: &Word, candidates: &[&Word]) -> f64 {
+    PositionalFrequencies::new(candidates).score(word)
+}
+
+/// Positional letter counts over a set of candidates
+///
+/// Counting once makes each score constant time, so a whole guess pool can
+/// be ranked in one pass over the candidates.
+#[derive(Debug, Clone)]
+pub struct PositionalFrequencies {
+    /// Candidates with each letter at each position
+    counts: [[usize; 26]; 5],
+    /// Candidates counted
+    total: usize,
+}
+
+impl PositionalFrequencies {
+    /// Count the letters at each position of `candidates`
+    #[must_use]
+    pub fn new(candidates: &[&Word]) -> Self {
+        let mut counts = [[0; 26]; 5];
+        for candidate in candidates {
+            for (position, &letter) in candidate.chars().iter().enumerate() {
+                counts[position][usize::from(letter - b'a')] += 1;
+            }
+        }
+        Self {
+            counts,
+            total: candidates.len(),
+        }
+    }
+
+    /// Score `word` against the counted candidates, as [`positional_score`]
+    #[must_use]
+    pub fn score(&self, word: &Word) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        let letters = word.chars();
+        let mut score = 0.0;
+        for (position, &letter) in letters.iter().enumerate() {
+            let matches = self.counts[position][usize::from(letter - b'a')];
+            let frequency = matches as f64 / self.total as f64;
+            let weight = if letters[..position].contains(&letter) {
+                REPEAT_WEIGHT
+            } else {
+                1.0
+            };
+            score += weight * frequency;
+        }
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::loader::words_from_slice;
+
+    #[test]
+    fn repeated_letters_are_penalized() {
+        let candidates = words_from_slice(&["geese"]);
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+
+        // G, S and the first E in full; the second and third E at half weight
+        let score = positional_score(&candidates[0], &candidate_refs);
+        assert!((score - 4.0).abs() < 1e-9, "{score}");
+
+        let crane = Word::new("crane").unwrap();
+        assert!((positional_score(&crane, &[&crane]) - 5.0).abs() < 1e-9);
+        assert!(positional_score(&crane, &[]).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn diverse_letters_rank_above_repetitive() {
+        let candidates = words_from_slice(&["sassy", "slate", "stare", "share", "spare", "tease"]);
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+        let score = |text: &str| positional_score(&Word::new(text).unwrap(), &candidate_refs);
+
+        // Both start with S and end with E, but SASSE repeats S where the
+        // candidates mostly have other letters
+        assert!(score("stare") > score("sasse"));
+        assert!(score("slate") > score("essse"));
+        assert!(score("share") > score("sssss"));
+    }
+}
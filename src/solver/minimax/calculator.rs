@@ -3,8 +3,7 @@
 //! Given a guess and set of candidates, computes the maximum remaining candidates
 //! for any possible pattern.
 
-use crate::core::{Pattern, Word};
-use rustc_hash::FxHashMap;
+use crate::core::{FxHashMap, Pattern, Word};
 
 /// Calculate the maximum remaining candidates for a guess
 ///
@@ -92,7 +91,7 @@ mod tests {
     fn max_remaining_skewed_distribution() {
         // Skewed distribution - worst case is the largest group
         let guess = Word::new("crane").unwrap();
-        let candidates = vec![
+        let candidates = [
             Word::new("slate").unwrap(), // Pattern A
             Word::new("irate").unwrap(), // Pattern B
             Word::new("crate").unwrap(), // Pattern C
@@ -3,7 +3,9 @@
 //! Minimizes worst-case remaining candidates.
 
 mod calculator;
+#[cfg(feature = "std")]
 mod selector;
 
 pub use calculator::calculate_max_remaining;
+#[cfg(feature = "std")]
 pub use selector::select_best_guess;
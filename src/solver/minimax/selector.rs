@@ -4,12 +4,14 @@
 
 use super::calculator::calculate_max_remaining;
 use crate::core::Word;
+use crate::solver::text_tiebreak;
 use rayon::prelude::*;
 
 /// Select best guess by minimizing worst-case remaining candidates
 ///
 /// Returns the word with the lowest maximum remaining candidates and that value,
-/// or `None` if the guess pool is empty.
+/// or `None` if the guess pool is empty. Ties go to the word whose text
+/// sorts first, whatever the pool order or thread count.
 ///
 /// # Examples
 /// ```
@@ -45,12 +47,15 @@ pub fn select_best_guess<'a>(
             let max_remaining = calculate_max_remaining(guess, candidates);
             (guess, max_remaining)
         })
-        .min_by_key(|(_, max)| *max)
+        // A total order, so rayon's reduction order can't change the winner
+        .min_by(|(w1, m1), (w2, m2)| m1.cmp(m2).then_with(|| text_tiebreak(w2, w1)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::wordlists::ALLOWED;
+    use crate::wordlists::loader::words_from_slice;
 
     #[test]
     fn selects_lowest_max_remaining() {
@@ -58,7 +63,7 @@ mod tests {
             Word::new("zzzzz").unwrap(), // Bad guess - doesn't split well
             Word::new("crane").unwrap(), // Better guess - diverse letters
         ];
-        let candidates = vec![
+        let candidates = [
             Word::new("slate").unwrap(),
             Word::new("irate").unwrap(),
             Word::new("crate").unwrap(),
@@ -94,26 +99,26 @@ mod tests {
     }
 
     #[test]
-    fn ties_resolved_consistently() {
-        // If multiple guesses have same max_remaining, should pick one consistently
-        let guesses = [Word::new("aaaaa").unwrap(), Word::new("bbbbb").unwrap()];
-        let candidates = [Word::new("ccccc").unwrap()];
+    fn ties_go_to_the_first_word_for_any_thread_count() {
+        // Against one candidate every guess leaves at most one
+        let guesses = words_from_slice(&ALLOWED[..2000]);
+        let candidates = [Word::new("zzzzz").unwrap()];
 
-        let guess_refs: Vec<&Word> = guesses.iter().collect();
+        // Reversed, so the winner can't just be the first in the pool
+        let guess_refs: Vec<&Word> = guesses.iter().rev().collect();
         let candidate_refs: Vec<&Word> = candidates.iter().collect();
 
-        let result1 = select_best_guess(&guess_refs, &candidate_refs);
-        let result2 = select_best_guess(&guess_refs, &candidate_refs);
-
-        assert!(result1.is_some());
-        assert!(result2.is_some());
-
-        let (best1, max1) = result1.unwrap();
-        let (best2, max2) = result2.unwrap();
-
-        // Should be deterministic
-        assert_eq!(best1.text(), best2.text());
-        assert_eq!(max1, max2);
+        for threads in [1, 2, 3, 8] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let (best, max_remaining) = pool
+                .install(|| select_best_guess(&guess_refs, &candidate_refs))
+                .unwrap();
+            assert_eq!(best.text(), "aahed", "{threads} threads");
+            assert_eq!(max_remaining, 1);
+        }
     }
 
     #[test]
@@ -1,14 +1,135 @@
 //! Wordle solving algorithms
 //!
 //! This module contains different solving strategies for Wordle.
+//!
+//! Without the `std` feature only the `entropy` and `minimax` calculators and
+//! the letter-frequency `heuristic` are available.
+//!
+//! Every selector breaks ties the same way, in favor of the guess whose text
+//! sorts first, so a selection depends only on its inputs: not on guess pool
+//! order or on how rayon splits the work.
 
+#[cfg(feature = "std")]
 pub mod adaptive;
+#[cfg(feature = "std")]
 mod engine;
 pub mod entropy;
+#[cfg(feature = "std")]
+mod estimate;
+#[cfg(feature = "std")]
+mod game;
+pub mod heuristic;
 pub mod minimax;
+#[cfg(feature = "std")]
+mod risk;
+#[cfg(feature = "std")]
 pub mod selection;
+#[cfg(feature = "std")]
 pub mod strategy;
 
-pub use adaptive::{AdaptiveStrategy, AdaptiveTier};
-pub use engine::Solver;
-pub use strategy::{EntropyStrategy, HybridStrategy, MinimaxStrategy, Strategy, StrategyType};
+#[cfg(feature = "std")]
+pub use adaptive::{
+    ADAPTIVE_PRESETS, AdaptivePreset, AdaptiveStrategy, AdaptiveTier, DEFAULT_TEMPERATURE,
+};
+#[cfg(feature = "std")]
+pub use engine::{
+    AnswerWeight, CommitRule, GameStatus, MAX_GUESSES, SOLVER_REVISION, Solver,
+    intersect_candidates,
+};
+#[cfg(feature = "std")]
+pub use estimate::{candidate_probabilities, expected_guesses};
+#[cfg(feature = "std")]
+pub use game::{GameRecord, replay, simulate_game};
+#[cfg(feature = "std")]
+pub use risk::{EndgameRisk, assess_endgame};
+#[cfg(feature = "std")]
+pub use strategy::{
+    EntropyStrategy, HybridStrategy, MinimaxStrategy, NaiveStrategy, RandomStrategy, Strategy,
+    StrategyType,
+};
+
+/// Order two tied guesses so the one whose text sorts first is greater
+///
+/// The shared last tie-break for guess selection: chain it after the real
+/// criteria in a `max_by`, or with the arguments swapped in a `min_by`.
+#[cfg(feature = "std")]
+#[must_use]
+pub(crate) fn text_tiebreak(a: &crate::core::Word, b: &crate::core::Word) -> core::cmp::Ordering {
+    b.text().cmp(a.text())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::selection::{
+        DEFAULT_HYBRID_WEIGHT, beam_prefilter, select_endgame, select_minimax_first,
+        select_with_candidate_preference, select_with_hybrid_weight, select_with_minimax_penalty,
+    };
+    use super::{entropy, minimax};
+    use crate::core::Word;
+    use crate::wordlists::ALLOWED;
+    use crate::wordlists::loader::words_from_slice;
+
+    type Selector = for<'a> fn(&'a [&'a Word], &[&Word]) -> Option<&'a Word>;
+
+    /// Every selector picks the same guess under any thread count and either
+    /// pool order, on a set where many guesses tie
+    #[test]
+    fn selectors_are_reproducible() {
+        let mut words: Vec<&str> = ALLOWED.iter().copied().step_by(4).collect();
+        let family = [
+            "batch", "catch", "hatch", "latch", "match", "patch", "watch",
+        ];
+        words.extend(family);
+        let pool = words_from_slice(&words);
+        let candidates = words_from_slice(&family);
+        let candidates: Vec<&Word> = candidates.iter().collect();
+
+        let selectors: [(&str, Selector); 7] = [
+            ("entropy", |p, c| {
+                entropy::select_best_guess(p, c).map(|(w, _)| w)
+            }),
+            ("minimax", |p, c| {
+                minimax::select_best_guess(p, c).map(|(w, _)| w)
+            }),
+            ("minimax-first", |p, c| select_minimax_first(p, c, 0.1)),
+            ("candidate-preference", |p, c| {
+                select_with_candidate_preference(p, c, 0.1)
+            }),
+            ("hybrid", |p, c| {
+                select_with_hybrid_weight(p, c, DEFAULT_HYBRID_WEIGHT)
+            }),
+            ("penalty", |p, c| select_with_minimax_penalty(p, c, 0.5)),
+            ("endgame", select_endgame),
+        ];
+
+        let forward: Vec<&Word> = pool.iter().collect();
+        let backward: Vec<&Word> = pool.iter().rev().collect();
+        for (name, select) in selectors {
+            let expected = select(&forward, &candidates).unwrap().text();
+            for threads in [1, 2, 3, 8] {
+                let threads_pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap();
+                for order in [&forward, &backward] {
+                    let chosen = threads_pool.install(|| select(order, &candidates));
+                    assert_eq!(
+                        chosen.unwrap().text(),
+                        expected,
+                        "{name}, {threads} threads"
+                    );
+                }
+            }
+        }
+
+        let beam = |order: &[&Word]| -> Vec<String> {
+            let mut kept: Vec<String> = beam_prefilter(order, &candidates, 50)
+                .iter()
+                .map(|w| w.text().to_string())
+                .collect();
+            kept.sort_unstable();
+            kept
+        };
+        assert_eq!(beam(&forward), beam(&backward));
+    }
+}
@@ -0,0 +1,182 @@
+//! Endgame risk
+//!
+//! Spots positions where more candidates remain than guesses, so trying the
+//! candidates one by one can run out of guesses, and works out whether some
+//! guess still guarantees the win.
+
+use std::cmp::Ordering;
+
+use crate::core::{FxHashMap, Pattern, Word};
+
+use super::{candidate_probabilities, text_tiebreak};
+
+/// A position with more candidates than guesses left, and how to play it
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndgameRisk {
+    /// Candidates consistent with the history
+    pub candidates: usize,
+    /// Guesses left, including the next one
+    pub guesses_left: usize,
+    /// A guess that guarantees the win if any does, else the one with the
+    /// best chance of winning in time
+    pub guess: Word,
+    /// Chance `guess` wins within the guesses left, playing the likeliest
+    /// candidate whenever only one guess remains
+    pub win_probability: f64,
+    /// Whether `guess` wins whichever candidate is the answer
+    pub guaranteed: bool,
+}
+
+/// Assess a position where more candidates remain than guesses
+///
+/// With `n` candidates and at least `n` guesses left a win is certain
+/// (each wrong candidate guess rules one out), so only `n > guesses_left`
+/// is assessed. With one guess left the best play is the likeliest
+/// candidate. With two, a guess guarantees the win exactly when every
+/// feedback it can get, other than its own all-green, singles out one
+/// candidate; otherwise the guess with the highest chance of winning is
+/// picked, given the likeliest candidate is played last. Ties go to
+/// candidates (which may win at once), then to the guess whose text sorts
+/// first, as in guess selection.
+///
+/// Returns `None` if the position is not at risk, or if more than two
+/// guesses are left: a guarantee is then the norm and proving it needs the
+/// exact endgame search.
+#[must_use]
+pub fn assess_endgame(
+    guess_pool: &[Word],
+    candidates: &[&Word],
+    guesses_left: usize,
+    weight: impl Fn(&Word) -> f64,
+) -> Option<EndgameRisk> {
+    if candidates.len() <= guesses_left || !(1..=2).contains(&guesses_left) {
+        return None;
+    }
+    let probabilities = candidate_probabilities(candidates, weight);
+
+    // Each guess with its odds, whether it is guaranteed and whether it is a candidate
+    let mut best: Option<(&Word, f64, bool, bool)> = None;
+    let guesses = candidates
+        .iter()
+        .map(|&candidate| (candidate, true))
+        .chain(guess_pool.iter().map(|guess| (guess, false)));
+    for (guess, is_candidate) in guesses {
+        let (win_probability, guaranteed) = if guesses_left == 1 {
+            let p = probabilities
+                .iter()
+                .find(|(w, _)| *w == guess)
+                .map_or(0.0, |&(_, p)| p);
+            (p, false)
+        } else {
+            two_guess_odds(guess, &probabilities)
+        };
+        let better = best.is_none_or(|(best_guess, p, g, c)| {
+            match (guaranteed, win_probability, is_candidate).partial_cmp(&(g, p, c)) {
+                Some(Ordering::Equal) => text_tiebreak(guess, best_guess).is_gt(),
+                order => order == Some(Ordering::Greater),
+            }
+        });
+        if better {
+            best = Some((guess, win_probability, guaranteed, is_candidate));
+        }
+    }
+
+    best.map(|(guess, win_probability, guaranteed, _)| EndgameRisk {
+        candidates: candidates.len(),
+        guesses_left,
+        guess: guess.clone(),
+        win_probability,
+        guaranteed,
+    })
+}
+
+/// Chance `guess` wins within two guesses, and whether it always does
+///
+/// Each feedback group other than all-green is won by playing its
+/// likeliest member next, so the guess always wins when every such group
+/// is a single candidate.
+fn two_guess_odds(guess: &Word, probabilities: &[(&Word, f64)]) -> (f64, bool) {
+    // Per pattern: members and the likeliest member's probability
+    let mut groups: FxHashMap<Pattern, (usize, f64)> = FxHashMap::default();
+    let mut win = 0.0;
+    for &(candidate, p) in probabilities {
+        let pattern = Pattern::calculate(guess, candidate);
+        if pattern == Pattern::PERFECT {
+            win += p;
+            continue;
+        }
+        let group = groups.entry(pattern).or_insert((0, 0.0));
+        group.0 += 1;
+        group.1 = group.1.max(p);
+    }
+    win += groups.values().map(|&(_, p)| p).sum::<f64>();
+    (win, groups.values().all(|&(members, _)| members == 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::loader::words_from_slice;
+
+    fn refs(words: &[Word]) -> Vec<&Word> {
+        words.iter().collect()
+    }
+
+    #[test]
+    fn no_risk_with_enough_guesses() {
+        let words = words_from_slice(&["grate", "crate", "irate"]);
+        let candidates = refs(&words);
+        assert_eq!(assess_endgame(&words, &candidates, 3, |_| 1.0), None);
+        assert_eq!(assess_endgame(&words, &candidates[..2], 2, |_| 1.0), None);
+        // Too early to call with three or more guesses left
+        let many = words_from_slice(&["grate", "crate", "irate", "prate", "orate"]);
+        assert_eq!(assess_endgame(&many, &refs(&many), 3, |_| 1.0), None);
+    }
+
+    #[test]
+    fn last_guess_plays_the_likeliest_candidate() {
+        let words = words_from_slice(&["grate", "crate", "irate"]);
+        let candidates = refs(&words);
+
+        // Equally likely candidates tie; the text that sorts first wins,
+        // whatever the list order
+        let risk = assess_endgame(&words, &candidates, 1, |_| 1.0).unwrap();
+        assert_eq!(risk.guess.text(), "crate");
+        assert!(!risk.guaranteed);
+        assert!((risk.win_probability - 1.0 / 3.0).abs() < 1e-12);
+        let reversed: Vec<&Word> = candidates.iter().rev().copied().collect();
+        let risk = assess_endgame(&words, &reversed, 1, |_| 1.0).unwrap();
+        assert_eq!(risk.guess.text(), "crate");
+
+        let favored = |w: &Word| if w.text() == "irate" { 3.0 } else { 1.0 };
+        let risk = assess_endgame(&words, &candidates, 1, favored).unwrap();
+        assert_eq!(risk.guess.text(), "irate");
+        assert!((risk.win_probability - 0.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn two_guesses_can_still_guarantee() {
+        // GRATE, CRATE and IRATE differ only in the first letter; a guess
+        // holding G and C tells all three apart
+        let candidates_words = words_from_slice(&["grate", "crate", "irate"]);
+        let pool = words_from_slice(&["grate", "crate", "irate", "magic"]);
+        let candidates = refs(&candidates_words);
+
+        let risk = assess_endgame(&pool, &candidates, 2, |_| 1.0).unwrap();
+        assert!(risk.guaranteed);
+        assert!((risk.win_probability - 1.0).abs() < 1e-12);
+        assert_eq!(risk.guess.text(), "magic");
+    }
+
+    #[test]
+    fn two_guesses_without_a_splitter_pick_the_best_odds() {
+        let words = words_from_slice(&["batch", "catch", "hatch", "latch", "match"]);
+        let candidates = refs(&words);
+
+        let risk = assess_endgame(&words, &candidates, 2, |_| 1.0).unwrap();
+        assert!(!risk.guaranteed);
+        // A candidate wins now with 1/5 and leaves four tied: 2/5 in all
+        assert_eq!(risk.guess.text(), "batch");
+        assert!((risk.win_probability - 0.4).abs() < 1e-12);
+    }
+}
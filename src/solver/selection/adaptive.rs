@@ -4,7 +4,8 @@
 //! These combine minimax with entropy and candidate preference.
 
 use crate::core::Word;
-use crate::solver::entropy::{calculate_entropy, calculate_metrics};
+use crate::solver::entropy::calculate_metrics;
+use crate::solver::text_tiebreak;
 use rayon::prelude::*;
 
 /// Select best guess with `minimax+entropy` tiebreaker
@@ -59,7 +60,11 @@ pub fn select_minimax_first<'a>(
     if let Some((word, _, _)) = tied_minimax
         .iter()
         .filter(|(_, m, is_cand)| *is_cand && (max_entropy - m.entropy) < epsilon)
-        .max_by(|(_, m1, _), (_, m2, _)| m1.entropy.total_cmp(&m2.entropy))
+        .max_by(|(w1, m1, _), (w2, m2, _)| {
+            m1.entropy
+                .total_cmp(&m2.entropy)
+                .then_with(|| text_tiebreak(w1, w2))
+        })
     {
         return Some(word);
     }
@@ -67,7 +72,11 @@ pub fn select_minimax_first<'a>(
     // Otherwise just pick highest entropy
     tied_minimax
         .into_iter()
-        .max_by(|(_, m1, _), (_, m2, _)| m1.entropy.total_cmp(&m2.entropy))
+        .max_by(|(w1, m1, _), (w2, m2, _)| {
+            m1.entropy
+                .total_cmp(&m2.entropy)
+                .then_with(|| text_tiebreak(w1, w2))
+        })
         .map(|(word, _, _)| word)
 }
 
@@ -83,35 +92,31 @@ pub fn select_with_candidate_preference<'a>(
     candidates: &[&Word],
     epsilon: f64,
 ) -> Option<&'a Word> {
-    // First pass: just entropy (parallelized)
-    let entropies: Vec<_> = guess_pool
+    // One partition per guess gives both entropy and max_partition (parallelized)
+    let metrics: Vec<_> = guess_pool
         .par_iter()
-        .map(|&guess| {
-            let ent = calculate_entropy(guess, candidates);
-            (guess, ent)
-        })
+        .map(|&guess| (guess, calculate_metrics(guess, candidates)))
         .collect();
 
     // Return None if empty
-    if entropies.is_empty() {
+    if metrics.is_empty() {
         return None;
     }
 
     // Find max entropy
-    let max_entropy = entropies
+    let max_entropy = metrics
         .iter()
-        .map(|(_, e)| *e)
+        .map(|(_, m)| m.entropy)
         .max_by(f64::total_cmp)
         .unwrap_or(0.0);
 
-    // Second pass: only compute max_partition for top candidates (parallelized)
-    let top_candidates: Vec<_> = entropies
-        .into_par_iter()
-        .filter(|(_, e)| (max_entropy - e) < epsilon)
-        .map(|(guess, ent)| {
+    // Keep guesses within epsilon of the max
+    let top_candidates: Vec<_> = metrics
+        .into_iter()
+        .filter(|(_, m)| (max_entropy - m.entropy) < epsilon)
+        .map(|(guess, m)| {
             let is_candidate = candidates.iter().any(|c| c.text() == guess.text());
-            let m = calculate_metrics(guess, candidates);
-            (guess, ent, m.max_partition, is_candidate)
+            (guess, m.entropy, m.max_partition, is_candidate)
         })
         .collect();
 
@@ -119,7 +124,9 @@ pub fn select_with_candidate_preference<'a>(
     if let Some((word, _, _, _)) = top_candidates
         .iter()
         .filter(|(_, _, _, is_cand)| *is_cand)
-        .min_by(|(_, _, max1, _), (_, _, max2, _)| max1.cmp(max2))
+        .min_by(|(w1, _, max1, _), (w2, _, max2, _)| {
+            max1.cmp(max2).then_with(|| text_tiebreak(w2, w1))
+        })
     {
         return Some(word);
     }
@@ -127,7 +134,9 @@ pub fn select_with_candidate_preference<'a>(
     // No candidate within epsilon, use minimax-first among all
     top_candidates
         .into_iter()
-        .min_by(|(_, _, max1, _), (_, _, max2, _)| max1.cmp(max2))
+        .min_by(|(w1, _, max1, _), (w2, _, max2, _)| {
+            max1.cmp(max2).then_with(|| text_tiebreak(w2, w1))
+        })
         .map(|(word, _, _, _)| word)
 }
 
@@ -0,0 +1,56 @@
+//! Beam prefilter
+//!
+//! Full entropy scores every guess against every candidate, which dominates
+//! the cost of the `PureEntropy` tier. A beam first ranks guesses with a
+//! cheap letter-frequency score and keeps only the best few for the full
+//! entropy pass, trading some guess quality for speed.
+
+use crate::core::Word;
+use crate::solver::heuristic::PositionalFrequencies;
+use crate::solver::text_tiebreak;
+
+/// Keep the `width` guesses with the best letter-frequency score
+///
+/// Guesses are ranked by [`positional_score`](crate::solver::heuristic::positional_score),
+/// which discounts repeated letters, so guesses that test five different
+/// common letters rank first. Ties go to the word whose text sorts first.
+/// Returns the whole pool when it already fits.
+#[must_use]
+pub fn beam_prefilter<'a>(
+    guess_pool: &[&'a Word],
+    candidates: &[&Word],
+    width: usize,
+) -> Vec<&'a Word> {
+    if guess_pool.len() <= width {
+        return guess_pool.to_vec();
+    }
+
+    let frequencies = PositionalFrequencies::new(candidates);
+    let mut scored: Vec<(f64, &'a Word)> = guess_pool
+        .iter()
+        .map(|&guess| (frequencies.score(guess), guess))
+        .collect();
+    scored.sort_by(|(s1, w1), (s2, w2)| s2.total_cmp(s1).then_with(|| text_tiebreak(w2, w1)));
+    scored.truncate(width);
+    scored.into_iter().map(|(_, guess)| guess).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::loader::words_from_slice;
+
+    #[test]
+    fn keeps_common_distinct_letters() {
+        let pool = words_from_slice(&["fuzzy", "crane", "eerie", "slate", "jazzy"]);
+        let candidates = words_from_slice(&["crate", "slant", "trace", "least"]);
+        let pool_refs: Vec<&Word> = pool.iter().collect();
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+
+        let beam = beam_prefilter(&pool_refs, &candidate_refs, 2);
+        let texts: Vec<&str> = beam.iter().map(|w| w.text()).collect();
+        assert_eq!(texts, ["crane", "slate"]);
+
+        assert_eq!(beam_prefilter(&pool_refs, &candidate_refs, 10).len(), 5);
+    }
+}
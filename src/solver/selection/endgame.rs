@@ -0,0 +1,298 @@
+//! Endgame selection
+//!
+//! Exhaustive search for small candidate sets. Every subset of candidates a
+//! guess can leave is solved exactly (memoized by bitmask), so the chosen
+//! guess has the smallest worst case, the fewest answers that hit it, and
+//! then the fewest expected guesses to finish.
+
+use crate::core::{FxHashMap, Pattern, Word};
+use crate::solver::text_tiebreak;
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+use std::cmp::Ordering;
+
+/// Largest candidate count the adaptive strategy runs the endgame search for
+pub const ENDGAME_MAX_CANDIDATES: usize = 9;
+
+/// Cost of finishing once a group of candidates is known
+#[derive(Debug, Clone, Copy)]
+struct GroupCost {
+    /// Guesses needed in the worst case, including the one that solves
+    worst: usize,
+    /// Candidates that take the worst case to solve
+    at_worst: usize,
+    /// Expected guesses, including the one that solves
+    expected: f64,
+}
+
+impl GroupCost {
+    /// Cost of a group no guess in the pool can make progress on
+    const UNSOLVABLE: Self = Self {
+        worst: usize::MAX,
+        at_worst: usize::MAX,
+        expected: f64::INFINITY,
+    };
+
+    /// Order by worst case, then how many candidates hit it, then expected
+    /// guesses
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.worst
+            .cmp(&other.worst)
+            .then(self.at_worst.cmp(&other.at_worst))
+            .then(self.expected.total_cmp(&other.expected))
+    }
+}
+
+/// How a guess splits a set of candidates
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Split {
+    /// Bitmask of each group left unsolved, sorted so equal splits compare equal
+    groups: Vec<u64>,
+    /// Whether the guess is itself one of the candidates
+    solves: bool,
+}
+
+/// Best endgame guess and what it guarantees
+#[derive(Debug, Clone, Copy)]
+pub struct EndgamePlan<'a> {
+    pub guess: &'a Word,
+    /// Guesses needed in the worst case, including `guess` and the one that
+    /// solves
+    pub worst: usize,
+    /// Candidates that take `worst` guesses to solve
+    pub at_worst: usize,
+    /// Expected guesses over the candidates, including `guess`
+    pub expected: f64,
+}
+
+/// Select the guess that finishes in the fewest guesses
+///
+/// Guesses are ranked by the worst case over all candidates, then by how
+/// many candidates would take that many guesses, then by the expected
+/// number of guesses, assuming each later guess is also chosen by
+/// this search. Remaining ties go to candidates (which may win outright),
+/// then to the word whose text sorts first. A guess that splits the candidates into
+/// singletons therefore always beats gambling on a candidate that might
+/// leave two or more words tied.
+///
+/// Candidates are tracked as a bitmask, so at most 64 are supported; the
+/// search visits every subset a guess can leave, so callers should keep to
+/// small counts like [`ENDGAME_MAX_CANDIDATES`]. Returns `None` if the guess
+/// pool or candidate list is empty, or no guess makes progress.
+#[must_use]
+pub fn select_endgame<'a>(guess_pool: &'a [&'a Word], candidates: &[&Word]) -> Option<&'a Word> {
+    plan_endgame(guess_pool, candidates).map(|plan| plan.guess)
+}
+
+/// Select the endgame guess like [`select_endgame`], with its exact cost
+///
+/// The plan's `worst` is the minimum number of guesses that guarantees a
+/// solve from these candidates with this guess pool.
+#[must_use]
+pub fn plan_endgame<'a>(
+    guess_pool: &'a [&'a Word],
+    candidates: &[&Word],
+) -> Option<EndgamePlan<'a>> {
+    if candidates.is_empty() || candidates.len() > 64 {
+        return None;
+    }
+
+    let mut search = Search {
+        guess_pool,
+        candidates,
+        costs: FxHashMap::default(),
+    };
+    let all = u64::MAX >> (64 - candidates.len());
+
+    let scored: Vec<(&Word, GroupCost, bool)> = guess_pool
+        .iter()
+        .zip(search.splits(all))
+        .filter_map(|(&guess, split)| {
+            let split = split?;
+            let cost = search.cost_after(&split, all);
+            Some((guess, cost, split.solves))
+        })
+        .collect();
+
+    scored
+        .into_iter()
+        .min_by(|a, b| {
+            a.1.cmp(&b.1)
+                .then(b.2.cmp(&a.2))
+                .then_with(|| text_tiebreak(b.0, a.0))
+        })
+        .map(|(guess, cost, _)| EndgamePlan {
+            guess,
+            worst: cost.worst,
+            at_worst: cost.at_worst,
+            expected: cost.expected,
+        })
+}
+
+/// Memoized exact search over subsets of the candidates
+struct Search<'p, 'c> {
+    guess_pool: &'p [&'p Word],
+    candidates: &'c [&'c Word],
+    costs: FxHashMap<u64, GroupCost>,
+}
+
+impl Search<'_, '_> {
+    /// How each pool guess splits `mask`, or `None` for a guess that leaves
+    /// the whole set together without solving
+    fn splits(&self, mask: u64) -> Vec<Option<Split>> {
+        let members: Vec<(usize, &Word)> = self
+            .candidates
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| mask & (1 << i) != 0)
+            .map(|(i, &word)| (i, word))
+            .collect();
+
+        self.guess_pool
+            .par_iter()
+            .map(|&guess| {
+                let split = split(guess, &members);
+                (split.solves || split.groups.len() > 1).then_some(split)
+            })
+            .collect()
+    }
+
+    /// Cost of finishing from `mask` before any guess is made
+    fn cost(&mut self, mask: u64) -> GroupCost {
+        if mask.is_power_of_two() {
+            return GroupCost {
+                worst: 1,
+                at_worst: 1,
+                expected: 1.0,
+            };
+        }
+        if let Some(&cost) = self.costs.get(&mask) {
+            return cost;
+        }
+
+        // Many guesses split a small set identically; evaluate each split once
+        let unique: FxHashSet<Split> = self.splits(mask).into_iter().flatten().collect();
+        let best = unique
+            .iter()
+            .map(|split| self.cost_after(split, mask))
+            .min_by(GroupCost::cmp)
+            .unwrap_or(GroupCost::UNSOLVABLE);
+
+        self.costs.insert(mask, best);
+        best
+    }
+
+    /// Cost of finishing from `mask` when the next guess produces `split`
+    fn cost_after(&mut self, split: &Split, mask: u64) -> GroupCost {
+        let size = f64::from(mask.count_ones());
+        let mut cost = GroupCost {
+            worst: 1,
+            at_worst: usize::from(split.solves),
+            expected: 1.0,
+        };
+        for &group in &split.groups {
+            let rest = self.cost(group);
+            let worst = rest.worst.saturating_add(1);
+            match worst.cmp(&cost.worst) {
+                Ordering::Greater => {
+                    cost.worst = worst;
+                    cost.at_worst = rest.at_worst;
+                }
+                Ordering::Equal => cost.at_worst = cost.at_worst.saturating_add(rest.at_worst),
+                Ordering::Less => {}
+            }
+            cost.expected += f64::from(group.count_ones()) / size * rest.expected;
+        }
+        cost
+    }
+}
+
+/// Group `members` (candidate index and word) by the pattern `guess` produces
+fn split(guess: &Word, members: &[(usize, &Word)]) -> Split {
+    let mut groups: FxHashMap<Pattern, u64> = FxHashMap::default();
+    let mut solves = false;
+    for &(i, candidate) in members {
+        let pattern = Pattern::calculate(guess, candidate);
+        if pattern.is_perfect() {
+            solves = true;
+        } else {
+            *groups.entry(pattern).or_insert(0) |= 1 << i;
+        }
+    }
+    let mut groups: Vec<u64> = groups.into_values().collect();
+    groups.sort_unstable();
+    Split { groups, solves }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(texts: &[&str]) -> Vec<Word> {
+        texts.iter().map(|t| Word::new(*t).unwrap()).collect()
+    }
+
+    #[test]
+    fn prefers_guaranteed_split_over_gamble() {
+        let candidates = words(&["grate", "crate", "irate"]);
+        let pool = words(&["grate", "crate", "irate", "cigar"]);
+        let pool_refs: Vec<&Word> = pool.iter().collect();
+        let cand_refs: Vec<&Word> = candidates.iter().collect();
+
+        // Every candidate differs only in the first letter, so each leaves the
+        // other two tied; CIGAR tells all three apart and guarantees 2 guesses
+        let best = select_endgame(&pool_refs, &cand_refs).unwrap();
+        assert_eq!(best.text(), "cigar");
+
+        let plan = plan_endgame(&pool_refs, &cand_refs).unwrap();
+        assert_eq!(plan.guess.text(), "cigar");
+        assert_eq!((plan.worst, plan.at_worst), (2, 3));
+        assert!((plan.expected - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn candidate_wins_when_it_also_splits() {
+        let candidates = words(&["crane", "slate", "pious"]);
+        let pool = words(&["pious", "crane", "slate"]);
+        let pool_refs: Vec<&Word> = pool.iter().collect();
+        let cand_refs: Vec<&Word> = candidates.iter().collect();
+
+        let best = select_endgame(&pool_refs, &cand_refs).unwrap();
+        assert!(candidates.contains(best));
+    }
+
+    #[test]
+    fn gambles_when_nothing_splits() {
+        let candidates = words(&["batty", "catty", "fatty", "patty"]);
+        let pool_refs: Vec<&Word> = candidates.iter().collect();
+
+        // Only the candidates themselves are available, so one must be tried
+        let best = select_endgame(&pool_refs, &pool_refs).unwrap();
+        assert!(candidates.contains(best));
+
+        // One at a time: the last one tried takes four guesses
+        let plan = plan_endgame(&pool_refs, &pool_refs).unwrap();
+        assert_eq!((plan.worst, plan.at_worst), (4, 1));
+        assert!((plan.expected - 2.5).abs() < 1e-12);
+
+        // A pool that cannot tell them apart makes no progress at all
+        let pool = words(&["drone"]);
+        let pool_refs: Vec<&Word> = pool.iter().collect();
+        let cand_refs: Vec<&Word> = candidates.iter().collect();
+        assert!(select_endgame(&pool_refs, &cand_refs).is_none());
+    }
+
+    #[test]
+    fn single_candidate_is_guessed() {
+        let candidates = words(&["irate"]);
+        let pool = words(&["crane", "irate"]);
+        let pool_refs: Vec<&Word> = pool.iter().collect();
+        let cand_refs: Vec<&Word> = candidates.iter().collect();
+
+        assert_eq!(
+            select_endgame(&pool_refs, &cand_refs).unwrap().text(),
+            "irate"
+        );
+        assert!(select_endgame(&pool_refs, &[]).is_none());
+    }
+}
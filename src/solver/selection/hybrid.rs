@@ -3,9 +3,41 @@
 //! Combines entropy with other metrics (`expected_remaining`, minimax) for improved performance.
 
 use crate::core::Word;
-use crate::solver::entropy::calculate_metrics;
+use crate::solver::entropy::{GuessMetrics, calculate_metrics};
+use crate::solver::text_tiebreak;
 use rayon::prelude::*;
 
+/// Worst-case weight in the default hybrid score
+pub const DEFAULT_HYBRID_WEIGHT: f64 = 10.0;
+
+/// Hybrid score for a guess: (entropy × 100) - (`max_partition` × 10)
+///
+/// Higher is better. Used by `select_with_hybrid_scoring`.
+#[must_use]
+pub fn hybrid_score(metrics: &GuessMetrics) -> i32 {
+    weighted_hybrid_score(metrics, DEFAULT_HYBRID_WEIGHT)
+}
+
+/// Hybrid score with a custom worst-case weight: (entropy × 100) - (`max_partition` × `weight`)
+///
+/// A weight of 0 is plain entropy; a large weight makes the worst case
+/// dominate.
+#[must_use]
+pub fn weighted_hybrid_score(metrics: &GuessMetrics, weight: f64) -> i32 {
+    (metrics.entropy * 100.0) as i32 - (metrics.max_partition as f64 * weight).round() as i32
+}
+
+/// Entropy softly penalized by the worst case: entropy - λ × (`max_partition` / candidates)
+///
+/// Higher is better. With `penalty` = 0 this is plain entropy.
+#[must_use]
+pub fn penalized_entropy(metrics: &GuessMetrics, num_candidates: usize, penalty: f64) -> f64 {
+    if num_candidates == 0 {
+        return metrics.entropy;
+    }
+    metrics.entropy - penalty * (metrics.max_partition as f64 / num_candidates as f64)
+}
+
 /// Select best guess with `entropy+expected_size+minimax` tiebreakers
 ///
 /// For medium candidate counts (21-100), this provides better performance than pure entropy.
@@ -16,6 +48,22 @@ use rayon::prelude::*;
 pub fn select_with_expected_tiebreaker<'a>(
     guess_pool: &'a [&'a Word],
     candidates: &[&Word],
+) -> Option<&'a Word> {
+    select_with_minimax_penalty(guess_pool, candidates, 0.0)
+}
+
+/// Select best guess by `penalized_entropy`, with the same tiebreakers
+///
+/// Like `select_with_expected_tiebreaker`, but the worst-case partition
+/// lowers the primary score by λ × (`max_partition` / candidates) instead of
+/// only breaking exact ties. `penalty` = 0 reproduces the tiebreaker behavior.
+///
+/// Returns `None` if the guess pool is empty.
+#[must_use]
+pub fn select_with_minimax_penalty<'a>(
+    guess_pool: &'a [&'a Word],
+    candidates: &[&Word],
+    penalty: f64,
 ) -> Option<&'a Word> {
     // Compute all metrics (parallelized)
     let metrics: Vec<_> = guess_pool
@@ -26,14 +74,17 @@ pub fn select_with_expected_tiebreaker<'a>(
         })
         .collect();
 
-    // Select by: entropy (primary), expected_remaining (secondary), max_partition (tertiary)
+    let num_candidates = candidates.len();
+
+    // Select by: penalized entropy (primary), expected_remaining (secondary), max_partition (tertiary)
     metrics
         .into_iter()
-        .max_by(|(_, m1), (_, m2)| {
-            m1.entropy
-                .total_cmp(&m2.entropy)
+        .max_by(|(w1, m1), (w2, m2)| {
+            penalized_entropy(m1, num_candidates, penalty)
+                .total_cmp(&penalized_entropy(m2, num_candidates, penalty))
                 .then(m2.expected_remaining.total_cmp(&m1.expected_remaining))
                 .then(m2.max_partition.cmp(&m1.max_partition))
+                .then_with(|| text_tiebreak(w1, w2))
         })
         .map(|(word, _)| word)
 }
@@ -48,6 +99,20 @@ pub fn select_with_expected_tiebreaker<'a>(
 pub fn select_with_hybrid_scoring<'a>(
     guess_pool: &'a [&'a Word],
     candidates: &[&Word],
+) -> Option<&'a Word> {
+    select_with_hybrid_weight(guess_pool, candidates, DEFAULT_HYBRID_WEIGHT)
+}
+
+/// Select best guess by `weighted_hybrid_score`
+///
+/// `weight` = `DEFAULT_HYBRID_WEIGHT` reproduces `select_with_hybrid_scoring`.
+///
+/// Returns `None` if the guess pool is empty.
+#[must_use]
+pub fn select_with_hybrid_weight<'a>(
+    guess_pool: &'a [&'a Word],
+    candidates: &[&Word],
+    weight: f64,
 ) -> Option<&'a Word> {
     // Compute all metrics (parallelized)
     let metrics: Vec<_> = guess_pool
@@ -61,16 +126,12 @@ pub fn select_with_hybrid_scoring<'a>(
     // Find best hybrid score
     metrics
         .into_iter()
-        .max_by(|(_, m1), (_, m2)| {
-            // Hybrid score: entropy (×100) minus worst-case penalty (×10)
-            let score1 = (m1.entropy * 100.0) as i32
-                - i32::try_from(m1.max_partition * 10).unwrap_or(i32::MAX);
-            let score2 = (m2.entropy * 100.0) as i32
-                - i32::try_from(m2.max_partition * 10).unwrap_or(i32::MAX);
+        .max_by(|(w1, m1), (w2, m2)| {
             // Higher score is better
-            score1
-                .cmp(&score2)
+            weighted_hybrid_score(m1, weight)
+                .cmp(&weighted_hybrid_score(m2, weight))
                 .then(m2.expected_remaining.total_cmp(&m1.expected_remaining))
+                .then_with(|| text_tiebreak(w1, w2))
         })
         .map(|(word, _)| word)
 }
@@ -78,6 +139,7 @@ pub fn select_with_hybrid_scoring<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::wordlists::loader::words_from_slice;
 
     #[test]
     fn select_with_expected_tiebreaker_works() {
@@ -170,4 +232,84 @@ mod tests {
         let result = select_with_hybrid_scoring(&guesses, &candidate_refs);
         assert!(result.is_none());
     }
+
+    #[test]
+    fn penalized_entropy_subtracts_scaled_worst_case() {
+        let metrics = GuessMetrics {
+            entropy: 3.0,
+            max_partition: 5,
+            expected_remaining: 2.0,
+            distinct_patterns: 2,
+        };
+
+        assert!((penalized_entropy(&metrics, 20, 0.0) - 3.0).abs() < 1e-9);
+        assert!((penalized_entropy(&metrics, 20, 2.0) - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn minimax_penalty_trades_entropy_for_worst_case() {
+        let guesses = words_from_slice(&["arose", "ashen"]);
+        let candidates = words_from_slice(&[
+            "boozy", "borax", "borne", "bosom", "bossy", "botch", "bough", "boule", "bound",
+            "bowel", "boxer", "brace",
+        ]);
+        let guess_refs: Vec<&Word> = guesses.iter().collect();
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+
+        // AROSE has more entropy (3.19 vs 3.08 bits) but leaves up to 3
+        // candidates where ASHEN leaves at most 2
+        let pick = |penalty| {
+            select_with_minimax_penalty(&guess_refs, &candidate_refs, penalty).map(Word::text)
+        };
+        assert_eq!(pick(0.0), Some("arose"));
+        assert_eq!(
+            select_with_expected_tiebreaker(&guess_refs, &candidate_refs).map(Word::text),
+            Some("arose")
+        );
+        assert_eq!(pick(2.0), Some("ashen"));
+    }
+
+    #[test]
+    fn huge_minimax_penalty_minimizes_worst_case() {
+        let guesses = [
+            Word::new("crane").unwrap(),
+            Word::new("slate").unwrap(),
+            Word::new("pilot").unwrap(),
+        ];
+        let candidates = [
+            Word::new("irate").unwrap(),
+            Word::new("crate").unwrap(),
+            Word::new("grate").unwrap(),
+            Word::new("plate").unwrap(),
+        ];
+        let guess_refs: Vec<&Word> = guesses.iter().collect();
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+
+        // A huge penalty makes the worst case dominate
+        let best = select_with_minimax_penalty(&guess_refs, &candidate_refs, 1000.0).unwrap();
+        let best_worst = calculate_metrics(best, &candidate_refs).max_partition;
+        let min_worst = guess_refs
+            .iter()
+            .map(|g| calculate_metrics(g, &candidate_refs).max_partition)
+            .min()
+            .unwrap();
+        assert_eq!(best_worst, min_worst);
+    }
+
+    #[test]
+    fn hybrid_weight_trades_entropy_for_worst_case() {
+        let metrics = GuessMetrics {
+            entropy: 2.5,
+            max_partition: 4,
+            expected_remaining: 2.0,
+            distinct_patterns: 2,
+        };
+
+        assert_eq!(weighted_hybrid_score(&metrics, 0.0), 250);
+        assert_eq!(
+            weighted_hybrid_score(&metrics, DEFAULT_HYBRID_WEIGHT),
+            hybrid_score(&metrics)
+        );
+        assert_eq!(weighted_hybrid_score(&metrics, 100.0), -150);
+    }
 }
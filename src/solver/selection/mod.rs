@@ -10,7 +10,15 @@
 //! This module provides composite strategies used by `AdaptiveStrategy`.
 
 pub mod adaptive;
+pub mod beam;
+pub mod endgame;
 pub mod hybrid;
 
 pub use adaptive::{select_minimax_first, select_with_candidate_preference};
-pub use hybrid::{select_with_expected_tiebreaker, select_with_hybrid_scoring};
+pub use beam::beam_prefilter;
+pub use endgame::{ENDGAME_MAX_CANDIDATES, EndgamePlan, plan_endgame, select_endgame};
+pub use hybrid::{
+    DEFAULT_HYBRID_WEIGHT, hybrid_score, penalized_entropy, select_with_expected_tiebreaker,
+    select_with_hybrid_scoring, select_with_hybrid_weight, select_with_minimax_penalty,
+    weighted_hybrid_score,
+};
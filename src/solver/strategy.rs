@@ -2,20 +2,65 @@
 //!
 //! Defines the Strategy trait and concrete implementations.
 
-use super::AdaptiveStrategy;
+use super::entropy::GuessMetrics;
+use super::{AdaptivePreset, AdaptiveStrategy};
 use crate::core::Word;
+use rustc_hash::FxHashSet;
+use std::cmp::Ordering;
 
 /// A strategy for selecting the best guess from a pool of candidates
 pub trait Strategy {
     /// Select the best guess from the guess pool given the current candidates
     ///
     /// Returns the best guess, or `None` if the guess pool is empty.
-    fn select_guess<'a>(&self, guess_pool: &'a [Word], candidates: &[Word]) -> Option<&'a Word>;
+    fn select_guess<'a>(&self, guess_pool: &'a [Word], candidates: &[Word]) -> Option<&'a Word> {
+        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
+        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+        self.select_among(&guess_refs, &candidate_refs)
+    }
+
+    /// [`Self::select_guess`] over borrowed words
+    ///
+    /// Lets callers narrow a large word list (or gather candidates) without
+    /// copying any words.
+    fn select_among<'a>(&self, guess_pool: &[&'a Word], candidates: &[&Word]) -> Option<&'a Word>;
+
+    /// Compare two guesses by this strategy's metric criterion
+    ///
+    /// Returns `Ordering::Greater` if `a` is preferred over `b` when
+    /// `num_candidates` remain. Used to rank alternatives; candidate-preference
+    /// tiebreaks applied by `select_guess` are not reflected.
+    ///
+    /// Defaults to higher entropy.
+    fn compare_guesses(
+        &self,
+        a: &GuessMetrics,
+        b: &GuessMetrics,
+        _num_candidates: usize,
+    ) -> Ordering {
+        a.entropy.total_cmp(&b.entropy)
+    }
+}
+
+/// The entry of `guess_pool` with the same text as `word`
+///
+/// Selectors return words borrowed for as long as the pool slice; this
+/// recovers the longer-lived reference.
+pub(crate) fn find_in_pool<'a>(guess_pool: &[&'a Word], word: &Word) -> Option<&'a Word> {
+    guess_pool.iter().copied().find(|w| w.text() == word.text())
+}
+
+/// Prefer the guess with the smaller worst case, then higher entropy
+pub(crate) fn compare_minimax(a: &GuessMetrics, b: &GuessMetrics) -> Ordering {
+    b.max_partition
+        .cmp(&a.max_partition)
+        .then(a.entropy.total_cmp(&b.entropy))
 }
 
 /// Enum wrapper for all strategy types
 ///
 /// Allows runtime selection of strategy while maintaining static dispatch.
+#[derive(Debug, PartialEq)]
 pub enum StrategyType {
     /// Adaptive strategy (default, best performance)
     Adaptive(AdaptiveStrategy),
@@ -27,24 +72,55 @@ pub enum StrategyType {
     Hybrid(HybridStrategy),
     /// Random selection from candidates
     Random(RandomStrategy),
+    /// First candidate alphabetically (baseline)
+    Naive(NaiveStrategy),
 }
 
 impl Strategy for StrategyType {
-    fn select_guess<'a>(&self, guess_pool: &'a [Word], candidates: &[Word]) -> Option<&'a Word> {
+    fn select_among<'a>(&self, guess_pool: &[&'a Word], candidates: &[&Word]) -> Option<&'a Word> {
+        match self {
+            Self::Adaptive(s) => s.select_among(guess_pool, candidates),
+            Self::Entropy(s) => s.select_among(guess_pool, candidates),
+            Self::Minimax(s) => s.select_among(guess_pool, candidates),
+            Self::Hybrid(s) => s.select_among(guess_pool, candidates),
+            Self::Random(s) => s.select_among(guess_pool, candidates),
+            Self::Naive(s) => s.select_among(guess_pool, candidates),
+        }
+    }
+
+    fn compare_guesses(
+        &self,
+        a: &GuessMetrics,
+        b: &GuessMetrics,
+        num_candidates: usize,
+    ) -> Ordering {
         match self {
-            Self::Adaptive(s) => s.select_guess(guess_pool, candidates),
-            Self::Entropy(s) => s.select_guess(guess_pool, candidates),
-            Self::Minimax(s) => s.select_guess(guess_pool, candidates),
-            Self::Hybrid(s) => s.select_guess(guess_pool, candidates),
-            Self::Random(s) => s.select_guess(guess_pool, candidates),
+            Self::Adaptive(s) => s.compare_guesses(a, b, num_candidates),
+            Self::Entropy(s) => s.compare_guesses(a, b, num_candidates),
+            Self::Minimax(s) => s.compare_guesses(a, b, num_candidates),
+            Self::Hybrid(s) => s.compare_guesses(a, b, num_candidates),
+            Self::Random(s) => s.compare_guesses(a, b, num_candidates),
+            Self::Naive(s) => s.compare_guesses(a, b, num_candidates),
         }
     }
 }
 
 impl StrategyType {
+    /// Names [`Self::from_name`] recognizes besides the adaptive presets
+    pub const NAMES: [&'static str; 7] = [
+        "adaptive",
+        "entropy",
+        "pure-entropy",
+        "minimax",
+        "hybrid",
+        "random",
+        "naive",
+    ];
+
     /// Create strategy from name string
     ///
-    /// Supported names: "adaptive", "entropy", "pure-entropy", "minimax", "hybrid", "random"
+    /// Supported names: "adaptive", "entropy", "pure-entropy", "minimax", "hybrid", "random",
+    /// "naive", plus the adaptive preset names (see [`AdaptivePreset`])
     /// Defaults to adaptive if name is unrecognized.
     #[must_use]
     pub fn from_name(name: &str) -> Self {
@@ -52,8 +128,25 @@ impl StrategyType {
             "entropy" | "pure-entropy" => Self::Entropy(EntropyStrategy),
             "minimax" => Self::Minimax(MinimaxStrategy),
             "hybrid" => Self::Hybrid(HybridStrategy::default()),
-            "random" => Self::Random(RandomStrategy),
-            _ => Self::Adaptive(AdaptiveStrategy::default()),
+            "random" => Self::Random(RandomStrategy::new()),
+            "naive" => Self::Naive(NaiveStrategy),
+            preset => Self::Adaptive(
+                AdaptivePreset::find(preset)
+                    .map_or_else(AdaptiveStrategy::default, AdaptivePreset::strategy),
+            ),
+        }
+    }
+
+    /// Seed any random choices so the same game state always yields the same guess
+    ///
+    /// Affects the adaptive strategy's `Random` tier and the random strategy;
+    /// the other strategies are already deterministic.
+    #[must_use]
+    pub const fn with_random_seed(self, seed: u64) -> Self {
+        match self {
+            Self::Adaptive(adaptive) => Self::Adaptive(adaptive.with_random_seed(seed)),
+            Self::Random(_) => Self::Random(RandomStrategy::seeded(seed)),
+            other => other,
         }
     }
 }
@@ -61,36 +154,42 @@ impl StrategyType {
 /// Pure entropy maximization strategy
 ///
 /// Always selects the guess with the highest Shannon entropy.
+#[derive(Debug, PartialEq, Eq)]
 pub struct EntropyStrategy;
 
 impl Strategy for EntropyStrategy {
-    fn select_guess<'a>(&self, guess_pool: &'a [Word], candidates: &[Word]) -> Option<&'a Word> {
-        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
-        let candidate_refs: Vec<&Word> = candidates.iter().collect();
-
-        super::entropy::select_best_guess(&guess_refs, &candidate_refs)
-            .and_then(|(best, _)| guess_pool.iter().find(|w| w.text() == best.text()))
+    fn select_among<'a>(&self, guess_pool: &[&'a Word], candidates: &[&Word]) -> Option<&'a Word> {
+        super::entropy::select_best_guess(guess_pool, candidates)
+            .and_then(|(best, _)| find_in_pool(guess_pool, best))
     }
 }
 
 /// Pure minimax strategy
 ///
 /// Always selects the guess that minimizes worst-case remaining candidates.
+#[derive(Debug, PartialEq, Eq)]
 pub struct MinimaxStrategy;
 
 impl Strategy for MinimaxStrategy {
-    fn select_guess<'a>(&self, guess_pool: &'a [Word], candidates: &[Word]) -> Option<&'a Word> {
-        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
-        let candidate_refs: Vec<&Word> = candidates.iter().collect();
+    fn select_among<'a>(&self, guess_pool: &[&'a Word], candidates: &[&Word]) -> Option<&'a Word> {
+        super::minimax::select_best_guess(guess_pool, candidates)
+            .and_then(|(best, _)| find_in_pool(guess_pool, best))
+    }
 
-        super::minimax::select_best_guess(&guess_refs, &candidate_refs)
-            .and_then(|(best, _)| guess_pool.iter().find(|w| w.text() == best.text()))
+    fn compare_guesses(
+        &self,
+        a: &GuessMetrics,
+        b: &GuessMetrics,
+        _num_candidates: usize,
+    ) -> Ordering {
+        compare_minimax(a, b)
     }
 }
 
 /// Hybrid strategy combining entropy and minimax
 ///
 /// Uses entropy when many candidates remain, switches to minimax near the end.
+#[derive(Debug, PartialEq, Eq)]
 pub struct HybridStrategy {
     /// Switch to minimax when candidates <= this threshold
     pub minimax_threshold: usize,
@@ -114,46 +213,114 @@ impl Default for HybridStrategy {
 }
 
 impl Strategy for HybridStrategy {
-    fn select_guess<'a>(&self, guess_pool: &'a [Word], candidates: &[Word]) -> Option<&'a Word> {
-        let guess_refs: Vec<&Word> = guess_pool.iter().collect();
-        let candidate_refs: Vec<&Word> = candidates.iter().collect();
-
+    fn select_among<'a>(&self, guess_pool: &[&'a Word], candidates: &[&Word]) -> Option<&'a Word> {
         let best = if candidates.len() <= self.minimax_threshold {
-            super::minimax::select_best_guess(&guess_refs, &candidate_refs)?.0
+            super::minimax::select_best_guess(guess_pool, candidates)?.0
         } else {
-            super::entropy::select_best_guess(&guess_refs, &candidate_refs)?.0
+            super::entropy::select_best_guess(guess_pool, candidates)?.0
         };
 
-        guess_pool.iter().find(|w| w.text() == best.text())
+        find_in_pool(guess_pool, best)
+    }
+
+    fn compare_guesses(
+        &self,
+        a: &GuessMetrics,
+        b: &GuessMetrics,
+        num_candidates: usize,
+    ) -> Ordering {
+        if num_candidates <= self.minimax_threshold {
+            compare_minimax(a, b)
+        } else {
+            a.entropy.total_cmp(&b.entropy)
+        }
     }
 }
 
 /// Random strategy
 ///
 /// Randomly selects from remaining candidates. Useful for endgame when only 1-2 candidates remain.
-pub struct RandomStrategy;
+///
+/// When seeded, the choice is derived from the seed and the candidate set, so
+/// the same game state always yields the same guess.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RandomStrategy {
+    seed: Option<u64>,
+}
+
+impl RandomStrategy {
+    /// Create an unseeded (nondeterministic) random strategy
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { seed: None }
+    }
+
+    /// Create a random strategy whose choices are reproducible for a given seed
+    #[must_use]
+    pub const fn seeded(seed: u64) -> Self {
+        Self { seed: Some(seed) }
+    }
+
+    /// Create a random strategy from an optional seed
+    #[must_use]
+    pub const fn with_seed(seed: Option<u64>) -> Self {
+        Self { seed }
+    }
+}
 
 impl Strategy for RandomStrategy {
-    fn select_guess<'a>(&self, guess_pool: &'a [Word], candidates: &[Word]) -> Option<&'a Word> {
+    fn select_among<'a>(&self, guess_pool: &[&'a Word], candidates: &[&Word]) -> Option<&'a Word> {
+        use rand::SeedableRng;
         use rand::prelude::IndexedRandom;
+        use rand::rngs::StdRng;
+        use std::hash::{Hash, Hasher};
 
         // Prefer candidates from the guess pool
         let valid_candidates: Vec<&Word> = candidates
             .iter()
+            .copied()
             .filter(|c| guess_pool.iter().any(|g| g.text() == c.text()))
             .collect();
 
-        if let Some(candidate) = valid_candidates.choose(&mut rand::rng()) {
-            guess_pool.iter().find(|w| w.text() == candidate.text())
+        let choice = if let Some(seed) = self.seed {
+            let mut hasher = rustc_hash::FxHasher::default();
+            seed.hash(&mut hasher);
+            for candidate in &valid_candidates {
+                candidate.text().hash(&mut hasher);
+            }
+            valid_candidates.choose(&mut StdRng::seed_from_u64(hasher.finish()))
+        } else {
+            valid_candidates.choose(&mut rand::rng())
+        };
+
+        if let Some(candidate) = choice {
+            find_in_pool(guess_pool, candidate)
         } else {
             // Fallback: pick first candidate if none are in guess pool
-            candidates
-                .first()
-                .and_then(|c| guess_pool.iter().find(|w| w.text() == c.text()))
+            candidates.first().and_then(|c| find_in_pool(guess_pool, c))
         }
     }
 }
 
+/// Naive strategy
+///
+/// Always guesses the first remaining candidate alphabetically, using no
+/// information theory at all. A floor to compare the other strategies against.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NaiveStrategy;
+
+impl Strategy for NaiveStrategy {
+    fn select_among<'a>(&self, guess_pool: &[&'a Word], candidates: &[&Word]) -> Option<&'a Word> {
+        let remaining: FxHashSet<&str> = candidates.iter().map(|w| w.text()).collect();
+
+        guess_pool
+            .iter()
+            .copied()
+            .filter(|w| remaining.contains(w.text()))
+            .min_by(|a, b| a.text().cmp(b.text()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -232,6 +399,42 @@ mod tests {
         assert_eq!(strategy.minimax_threshold, 5);
     }
 
+    #[test]
+    fn compare_guesses_follows_criterion() {
+        let high_entropy = GuessMetrics {
+            entropy: 3.0,
+            expected_remaining: 4.0,
+            max_partition: 8,
+            distinct_patterns: 2,
+        };
+        let low_worst_case = GuessMetrics {
+            entropy: 2.5,
+            expected_remaining: 5.0,
+            max_partition: 3,
+            distinct_patterns: 2,
+        };
+
+        assert_eq!(
+            EntropyStrategy.compare_guesses(&high_entropy, &low_worst_case, 50),
+            Ordering::Greater
+        );
+        assert_eq!(
+            MinimaxStrategy.compare_guesses(&high_entropy, &low_worst_case, 50),
+            Ordering::Less
+        );
+
+        // Hybrid switches criterion at its threshold
+        let hybrid = HybridStrategy::new(5);
+        assert_eq!(
+            hybrid.compare_guesses(&high_entropy, &low_worst_case, 50),
+            Ordering::Greater
+        );
+        assert_eq!(
+            hybrid.compare_guesses(&high_entropy, &low_worst_case, 5),
+            Ordering::Less
+        );
+    }
+
     #[test]
     fn random_strategy_selects_from_candidates() {
         let guesses = vec![
@@ -241,7 +444,7 @@ mod tests {
         ];
         let candidates = vec![Word::new("irate").unwrap()];
 
-        let strategy = RandomStrategy;
+        let strategy = RandomStrategy::new();
         let result = strategy.select_guess(&guesses, &candidates);
 
         assert!(result.is_some());
@@ -250,4 +453,51 @@ mod tests {
         // Should select the only candidate
         assert_eq!(guess.text(), "irate");
     }
+
+    #[test]
+    fn seeded_random_strategy_is_reproducible() {
+        let guesses = vec![
+            Word::new("irate").unwrap(),
+            Word::new("crate").unwrap(),
+            Word::new("grate").unwrap(),
+        ];
+
+        let strategy = RandomStrategy::seeded(7);
+        let first = strategy.select_guess(&guesses, &guesses).unwrap();
+        for _ in 0..10 {
+            assert_eq!(strategy.select_guess(&guesses, &guesses), Some(first));
+        }
+        assert!(guesses.contains(first));
+    }
+
+    #[test]
+    fn aliases_parse_to_the_same_strategy() {
+        let parse = |name| StrategyType::from_name(name).with_random_seed(0);
+        assert_eq!(parse("entropy"), parse("pure-entropy"));
+        assert_ne!(parse("adaptive"), parse("fast"));
+        assert_ne!(parse("entropy"), parse("minimax"));
+    }
+
+    #[test]
+    fn naive_strategy_picks_first_candidate_alphabetically() {
+        let guesses = vec![
+            Word::new("slate").unwrap(),
+            Word::new("irate").unwrap(),
+            Word::new("crate").unwrap(),
+            Word::new("grate").unwrap(),
+        ];
+        let candidates = vec![Word::new("irate").unwrap(), Word::new("grate").unwrap()];
+
+        let guess = NaiveStrategy.select_guess(&guesses, &candidates);
+        assert_eq!(guess.map(Word::text), Some("grate"));
+
+        // Only candidates are ever guessed
+        let outside = vec![Word::new("pious").unwrap()];
+        assert!(NaiveStrategy.select_guess(&guesses, &outside).is_none());
+
+        assert!(matches!(
+            StrategyType::from_name("naive"),
+            StrategyType::Naive(_)
+        ));
+    }
 }
@@ -2,14 +2,39 @@
 //!
 //! Provides functions to load word lists from files or use embedded constants.
 
-use crate::core::Word;
+use crate::core::{FxHashMap, Word, WordError, WordIndex};
+use std::fmt;
 use std::fs;
 use std::io;
 use std::path::Path;
 
+/// Rejected entries a strict load names before summarizing the rest
+const STRICT_SHOWN: usize = 5;
+
+/// A word list entry that failed validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedEntry {
+    /// 1-based line number in the source
+    pub line: usize,
+    /// The entry as written (trimmed)
+    pub text: String,
+    pub error: WordError,
+}
+
+impl fmt::Display for RejectedEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: '{}' rejected: {}",
+            self.line, self.text, self.error
+        )
+    }
+}
+
 /// Load words from a file
 ///
 /// Returns a vector of valid Word instances, skipping any invalid entries.
+/// Use `load_from_file_checked` to find out which entries were skipped.
 ///
 /// # Errors
 ///
@@ -23,21 +48,106 @@ use std::path::Path;
 /// println!("Loaded {} words", words.len());
 /// ```
 pub fn load_from_file<P: AsRef<Path>>(path: P) -> io::Result<Vec<Word>> {
+    load_from_file_checked(path).map(|(words, _)| words)
+}
+
+/// Load words from a file, also returning the entries that were rejected
+///
+/// # Errors
+///
+/// Returns an I/O error if the file cannot be read or opened.
+pub fn load_from_file_checked<P: AsRef<Path>>(
+    path: P,
+) -> io::Result<(Vec<Word>, Vec<RejectedEntry>)> {
+    let content = fs::read_to_string(path)?;
+    Ok(parse_word_list(&content))
+}
+
+/// Load words from a file, failing if any entry is invalid
+///
+/// Unlike [`load_from_file`], nothing is skipped silently: a list that
+/// mixes in other word lengths is refused rather than truncated.
+///
+/// # Errors
+///
+/// Returns an I/O error if the file cannot be read, or an
+/// [`io::ErrorKind::InvalidData`] error describing the rejected entries.
+pub fn load_from_file_strict<P: AsRef<Path>>(path: P) -> io::Result<Vec<Word>> {
     let content = fs::read_to_string(path)?;
+    parse_word_list_strict(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Parse one word per line like [`parse_word_list`], failing on any invalid entry
+///
+/// # Errors
+///
+/// Returns a message with the number of rejected entries and the first few
+/// of them.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::wordlists::loader::parse_word_list_strict;
+///
+/// assert_eq!(parse_word_list_strict("crane\nslate\n").unwrap().len(), 2);
+/// assert!(parse_word_list_strict("crane\ncranes\n").is_err());
+/// ```
+pub fn parse_word_list_strict(content: &str) -> Result<Vec<Word>, String> {
+    let (words, rejected) = parse_word_list(content);
+    if rejected.is_empty() {
+        return Ok(words);
+    }
+
+    let mut lines = vec![format!(
+        "{} of {} entries are not valid words:",
+        rejected.len(),
+        words.len() + rejected.len()
+    )];
+    lines.extend(
+        rejected
+            .iter()
+            .take(STRICT_SHOWN)
+            .map(|entry| format!("  {entry}")),
+    );
+    if rejected.len() > STRICT_SHOWN {
+        lines.push(format!("  ... and {} more", rejected.len() - STRICT_SHOWN));
+    }
+    Err(lines.join("\n"))
+}
+
+/// Parse one word per line, skipping blank lines
+///
+/// Returns the valid words and a record of each invalid entry. Each word keeps
+/// its original casing for [`Word::display_text`].
+///
+/// # Examples
+/// ```
+/// use wordle_solver::wordlists::loader::parse_word_list;
+///
+/// let (words, rejected) = parse_word_list("crane\nco-op\n\nslate\n");
+/// assert_eq!(words.len(), 2);
+/// assert_eq!(rejected[0].to_string(), "line 2: 'co-op' rejected: Word contains hyphen at index 2");
+/// ```
+#[must_use]
+pub fn parse_word_list(content: &str) -> (Vec<Word>, Vec<RejectedEntry>) {
+    let mut words = Vec::new();
+    let mut rejected = Vec::new();
 
-    let words = content
-        .lines()
-        .filter_map(|line| {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                None
-            } else {
-                Word::new(trimmed).ok()
-            }
-        })
-        .collect();
-
-    Ok(words)
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        match Word::new_preserving_case(trimmed) {
+            Ok(word) => words.push(word),
+            Err(error) => rejected.push(RejectedEntry {
+                line: i + 1,
+                text: trimmed.to_string(),
+                error,
+            }),
+        }
+    }
+
+    (words, rejected)
 }
 
 /// Convert embedded string slice to Word vector
@@ -55,6 +165,77 @@ pub fn words_from_slice(slice: &[&str]) -> Vec<Word> {
     slice.iter().filter_map(|&s| Word::new(s).ok()).collect()
 }
 
+/// Add the answers missing from a guess pool to it, so every answer can be guessed
+///
+/// A solver narrows candidates down from the answer list but only guesses
+/// from the pool, so an answer outside the pool could be found yet never
+/// played. Returns the words added, in answer list order; the pool is
+/// untouched when it already holds every answer.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::wordlists::loader::{add_missing_answers, words_from_slice};
+///
+/// let mut pool = words_from_slice(&["salet", "crane"]);
+/// let answers = words_from_slice(&["crane", "zebra"]);
+///
+/// let added = add_missing_answers(&mut pool, &answers);
+/// assert_eq!(added, words_from_slice(&["zebra"]));
+/// assert_eq!(pool.len(), 3);
+/// ```
+pub fn add_missing_answers(pool: &mut Vec<Word>, answers: &[Word]) -> Vec<Word> {
+    let index = WordIndex::new(pool);
+    let mut added: Vec<Word> = Vec::new();
+    for answer in answers {
+        if !index.contains(answer) && !added.contains(answer) {
+            added.push(answer.clone());
+        }
+    }
+    pool.extend(added.iter().cloned());
+    added
+}
+
+/// Parse answer weights, one `word weight` pair per line, skipping blank lines
+///
+/// Weights are relative likelihoods of each answer, e.g. how common the
+/// word is; they need not sum to anything. Words are keyed by their
+/// lowercase text.
+///
+/// # Errors
+///
+/// Returns a message naming the first line that isn't a valid word
+/// followed by a finite, non-negative weight.
+///
+/// # Examples
+/// ```
+/// use wordle_solver::wordlists::loader::parse_answer_weights;
+///
+/// let weights = parse_answer_weights("CRANE 3\nslate 0.5\n").unwrap();
+/// assert_eq!(weights["crane"], 3.0);
+/// assert!(parse_answer_weights("crane\n").is_err());
+/// ```
+pub fn parse_answer_weights(content: &str) -> Result<FxHashMap<String, f64>, String> {
+    let mut weights = FxHashMap::default();
+    for (i, line) in content.lines().enumerate() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let entry = match fields[..] {
+            [] => continue,
+            [word, weight] => Word::new(word).map_err(|e| e.to_string()).and_then(|word| {
+                weight
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|w| w.is_finite() && *w >= 0.0)
+                    .map(|weight| (word.text().to_string(), weight))
+                    .ok_or_else(|| format!("'{weight}' is not a non-negative number"))
+            }),
+            _ => Err("expected a word and a weight".to_string()),
+        };
+        let (word, weight) = entry.map_err(|e| format!("line {}: {e}", i + 1))?;
+        weights.insert(word, weight);
+    }
+    Ok(weights)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +276,59 @@ mod tests {
         let words = words_from_slice(ANSWERS);
         assert_eq!(words.len(), ANSWERS.len());
     }
+
+    #[test]
+    fn parse_word_list_reports_rejected_entries() {
+        let (words, rejected) = parse_word_list("crane\n  it's \n\nco-op\nSLATE\n");
+
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[1].text(), "slate");
+        assert_eq!(words[1].display_text(), "SLATE");
+        assert_eq!(rejected.len(), 2);
+        assert_eq!(rejected[0].line, 2);
+        assert_eq!(rejected[0].text, "it's");
+        assert_eq!(
+            rejected[0].error,
+            WordError::InvalidCharacters { ch: '\'', index: 2 }
+        );
+        assert_eq!(
+            rejected[1].error,
+            WordError::InvalidCharacters { ch: '-', index: 2 }
+        );
+    }
+
+    #[test]
+    fn strict_parse_reports_offenders() {
+        let words = parse_word_list_strict("crane\n\nSLATE\n").unwrap();
+        assert_eq!(words.len(), 2);
+
+        let content = "crane\ncranes\nab\nslate\nx\ny\nz\nzz\nzzz\n";
+        let error = parse_word_list_strict(content).unwrap_err();
+        let lines: Vec<&str> = error.lines().collect();
+        assert_eq!(lines[0], "7 of 9 entries are not valid words:");
+        assert!(lines[1].starts_with("  line 2: 'cranes' rejected"));
+        assert_eq!(lines.len(), 1 + STRICT_SHOWN + 1);
+        assert_eq!(lines[STRICT_SHOWN + 1], "  ... and 2 more");
+
+        // The first bad character is reported even when the length is off too
+        let error = parse_word_list_strict("crane\nit's\n").unwrap_err();
+        assert!(error.contains("apostrophe at index 2"), "{error}");
+    }
+
+    #[test]
+    fn missing_answers_join_the_pool_once() {
+        let mut pool = words_from_slice(&["salet", "crane"]);
+        let answers = words_from_slice(&["zebra", "crane", "zebra", "koala"]);
+
+        let added = add_missing_answers(&mut pool, &answers);
+        assert_eq!(added, words_from_slice(&["zebra", "koala"]));
+        assert_eq!(
+            pool,
+            words_from_slice(&["salet", "crane", "zebra", "koala"])
+        );
+
+        // Reconciled lists are left alone
+        assert!(add_missing_answers(&mut pool, &answers).is_empty());
+        assert_eq!(pool.len(), 4);
+    }
 }
@@ -0,0 +1,326 @@
+//! Word list manifests
+//!
+//! A custom word list can carry a sidecar `.meta.toml` file next to it
+//! (`words.txt` → `words.meta.toml`) recording settings precomputed for that
+//! list, so they are not recomputed every run. Only a small TOML subset is
+//! read: `key = value` lines with quoted strings or integers, and `#`
+//! comments.
+//!
+//! ```toml
+//! # Precomputed for words.txt
+//! opener = "salet"
+//! words = 12972
+//! answers = "3f9a0c2e71d4b856"
+//! ```
+
+use crate::artifact::list_hash;
+use crate::core::{Word, WordError};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Settings precomputed for one word list
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    /// Best first guess for this list
+    pub opener: Option<String>,
+    /// Number of words in the list the manifest was written for, used to
+    /// spot a manifest left over from an earlier version of the list
+    pub words: Option<usize>,
+    /// [`answers_hash`] of the answer list the opener was computed for
+    pub answers: Option<u64>,
+}
+
+/// Hash of an answer list, in order, for [`Manifest::answers`]
+#[must_use]
+pub fn answers_hash(answers: &[Word]) -> u64 {
+    list_hash(&[], answers)
+}
+
+/// Error reading or validating a manifest
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(io::Error),
+    /// A line that is not a known `key = value` pair
+    Syntax {
+        line: usize,
+        message: String,
+    },
+    /// The opener is not a valid word
+    InvalidOpener(WordError),
+    /// The opener is not in the loaded word list
+    OpenerNotInList(String),
+    /// The manifest was written for a list with a different word count
+    WordCountMismatch {
+        manifest: usize,
+        list: usize,
+    },
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            Self::InvalidOpener(e) => write!(f, "invalid opener: {e}"),
+            Self::OpenerNotInList(word) => {
+                write!(f, "opener '{word}' is not in the word list")
+            }
+            Self::WordCountMismatch { manifest, list } => write!(
+                f,
+                "written for a list of {manifest} words, but the list has {list}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ManifestError {}
+
+impl From<io::Error> for ManifestError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Path of the manifest for the word list at `list_path`
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use wordle_solver::wordlists::manifest::manifest_path;
+///
+/// assert_eq!(
+///     manifest_path(Path::new("lists/words.txt")),
+///     Path::new("lists/words.meta.toml")
+/// );
+/// ```
+#[must_use]
+pub fn manifest_path(list_path: &Path) -> PathBuf {
+    list_path.with_extension("meta.toml")
+}
+
+impl Manifest {
+    /// Load the manifest next to `list_path`, or `None` if there is none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest exists but cannot be read or parsed.
+    pub fn load(list_path: &Path) -> Result<Option<Self>, ManifestError> {
+        match fs::read_to_string(manifest_path(list_path)) {
+            Ok(content) => Self::parse(&content).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Parse manifest contents
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ManifestError::Syntax`] for malformed lines, unknown keys or
+    /// values of the wrong type.
+    pub fn parse(content: &str) -> Result<Self, ManifestError> {
+        let mut manifest = Self::default();
+
+        for (i, line) in content.lines().enumerate() {
+            let syntax = |message: String| ManifestError::Syntax {
+                line: i + 1,
+                message,
+            };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| syntax(format!("expected 'key = value', got '{line}'")))?;
+
+            match key.trim() {
+                "opener" => {
+                    let text = value
+                        .trim()
+                        .strip_prefix('"')
+                        .and_then(|v| v.strip_suffix('"'))
+                        .ok_or_else(|| syntax("opener must be a quoted string".to_string()))?;
+                    manifest.opener = Some(text.to_string());
+                }
+                "words" => {
+                    let count = value
+                        .trim()
+                        .parse()
+                        .map_err(|_| syntax("words must be a whole number".to_string()))?;
+                    manifest.words = Some(count);
+                }
+                "answers" => {
+                    let hash = value
+                        .trim()
+                        .strip_prefix('"')
+                        .and_then(|v| v.strip_suffix('"'))
+                        .and_then(|v| u64::from_str_radix(v, 16).ok())
+                        .ok_or_else(|| {
+                            syntax("answers must be a quoted hexadecimal hash".to_string())
+                        })?;
+                    manifest.answers = Some(hash);
+                }
+                other => return Err(syntax(format!("unknown key '{other}'"))),
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    /// Check the manifest against the list it was loaded for
+    ///
+    /// Returns the opener as it appears in `words`, if one is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the word count does not match, or the opener is
+    /// not a valid word in `words`.
+    pub fn validate<'a>(&self, words: &'a [Word]) -> Result<Option<&'a Word>, ManifestError> {
+        if let Some(count) = self.words
+            && count != words.len()
+        {
+            return Err(ManifestError::WordCountMismatch {
+                manifest: count,
+                list: words.len(),
+            });
+        }
+
+        let Some(opener) = &self.opener else {
+            return Ok(None);
+        };
+        let opener = Word::new(opener).map_err(ManifestError::InvalidOpener)?;
+        words
+            .iter()
+            .find(|w| **w == opener)
+            .map(Some)
+            .ok_or_else(|| ManifestError::OpenerNotInList(opener.text().to_string()))
+    }
+
+    /// Whether the opener was computed for `answers`
+    ///
+    /// Manifests written before the hash was recorded fit any answer list.
+    #[must_use]
+    pub fn fits_answers(&self, answers: &[Word]) -> bool {
+        self.answers
+            .is_none_or(|hash| hash == answers_hash(answers))
+    }
+
+    /// Render the manifest as TOML
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        let mut lines =
+            vec!["# Precomputed settings for the word list next to this file".to_string()];
+        if let Some(opener) = &self.opener {
+            lines.push(format!("opener = \"{opener}\""));
+        }
+        if let Some(words) = self.words {
+            lines.push(format!("words = {words}"));
+        }
+        if let Some(answers) = self.answers {
+            lines.push(format!("answers = \"{answers:016x}\""));
+        }
+        lines.join("\n") + "\n"
+    }
+
+    /// Write the manifest next to `list_path`, returning where it was written
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the file cannot be written.
+    pub fn save(&self, list_path: &Path) -> io::Result<PathBuf> {
+        let path = manifest_path(list_path);
+        fs::write(&path, self.to_toml())?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordlists::loader::words_from_slice;
+
+    #[test]
+    fn parse_round_trips() {
+        let manifest = Manifest {
+            opener: Some("crane".to_string()),
+            words: Some(3),
+            answers: Some(0x00ab_cdef_0123_4567),
+        };
+
+        assert_eq!(Manifest::parse(&manifest.to_toml()).unwrap(), manifest);
+        assert_eq!(
+            Manifest::parse("# nothing yet\n\n").unwrap(),
+            Manifest::default()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_bad_lines() {
+        for (content, line) in [
+            ("opener = crane", 1),
+            ("# ok\nwords = many", 2),
+            ("\n\nfirst = \"crane\"", 3),
+            ("opener \"crane\"", 1),
+            ("answers = \"not hex\"", 1),
+        ] {
+            match Manifest::parse(content) {
+                Err(ManifestError::Syntax { line: got, .. }) => assert_eq!(got, line, "{content}"),
+                other => panic!("{content}: expected syntax error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn validate_checks_opener_and_count() {
+        let words = words_from_slice(&["crane", "slate", "irate"]);
+        let manifest = |opener: &str, count: Option<usize>| Manifest {
+            opener: Some(opener.to_string()),
+            words: count,
+            answers: None,
+        };
+
+        let opener = manifest("SLATE", Some(3)).validate(&words).unwrap();
+        assert_eq!(opener.map(Word::text), Some("slate"));
+        assert!(Manifest::default().validate(&words).unwrap().is_none());
+
+        assert!(matches!(
+            manifest("salet", None).validate(&words),
+            Err(ManifestError::OpenerNotInList(w)) if w == "salet"
+        ));
+        assert!(matches!(
+            manifest("sla", None).validate(&words),
+            Err(ManifestError::InvalidOpener(_))
+        ));
+        assert!(matches!(
+            manifest("crane", Some(4)).validate(&words),
+            Err(ManifestError::WordCountMismatch {
+                manifest: 4,
+                list: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn opener_fits_only_its_answer_list() {
+        let answers = words_from_slice(&["crane", "slate"]);
+        let manifest = Manifest {
+            opener: Some("crane".to_string()),
+            words: Some(3),
+            answers: Some(answers_hash(&answers)),
+        };
+        assert!(manifest.fits_answers(&answers));
+        assert!(!manifest.fits_answers(&answers[..1]));
+        assert!(!manifest.fits_answers(&words_from_slice(&["slate", "crane"])));
+
+        // Older manifests don't record the answers
+        let older = Manifest {
+            answers: None,
+            ..manifest
+        };
+        assert!(older.fits_answers(&answers[..1]));
+    }
+}